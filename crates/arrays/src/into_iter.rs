@@ -139,6 +139,19 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
     }
 }
 
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.init.next_back().map(|i| {
+            // SAFETY: We know that the elements `init` are initialized and
+            // within the bounds of the array. We can safely assume that it is
+            // initialized and read it. Since we have consumed this index it
+            // will now be considered uninitialized and won't be touched again.
+            unsafe { self.arr.get_unchecked(i).assume_init_read() }
+        })
+    }
+}
+
 impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
     #[inline]
     fn len(&self) -> usize {
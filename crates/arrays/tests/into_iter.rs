@@ -104,3 +104,17 @@ fn into_iter_len() {
     assert_eq!(iter.next(), None);
     assert_eq!(iter.len(), 0);
 }
+
+#[test]
+fn into_iter_next_back() {
+    let arr: [i32; 4] = [1, 2, 3, 4];
+    let mut iter = arrays::IntoIter::new(arr);
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.as_slice(), &[1, 2, 3]);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.as_slice(), &[2]);
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -1,3 +1,5 @@
+use core::fmt;
+use core::iter;
 use core::iter::FusedIterator;
 
 use arrays::IntoIter;
@@ -50,6 +52,208 @@ pub trait IterArrayChunks: Iterator {
         ArrayChunks::new(self)
     }
 
+    /// Returns an iterator over `N` elements of the iterator at a time, where
+    /// the last chunk is filled out with clones of `pad`.
+    ///
+    /// The chunks do not overlap. If `N` does not divide the length of the
+    /// iterator, then the last up to `N-1` elements are yielded in a final
+    /// chunk padded to length `N`.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let mut iter = "lorem".chars().array_chunks_padded('_');
+    /// assert_eq!(iter.next(), Some(['l', 'o']));
+    /// assert_eq!(iter.next(), Some(['r', 'e']));
+    /// assert_eq!(iter.next(), Some(['m', '_']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn array_chunks_padded<const N: usize>(self, pad: Self::Item) -> ArrayChunksPadded<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        ArrayChunksPadded::new(self, pad)
+    }
+
+    /// Returns an iterator over `N` elements of the iterator at a time along
+    /// with the index of each chunk.
+    ///
+    /// This is the same as calling [`enumerate`] on [`array_chunks`] except
+    /// that the returned iterator still provides access to the remainder using
+    /// [`into_remainder`][EnumerateArrayChunks::into_remainder].
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let mut iter = "lorem".chars().enumerate_array_chunks();
+    /// assert_eq!(iter.next(), Some((0, ['l', 'o'])));
+    /// assert_eq!(iter.next(), Some((1, ['r', 'e'])));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder().unwrap().as_slice(), ['m']);
+    /// ```
+    ///
+    /// [`enumerate`]: Iterator::enumerate
+    /// [`array_chunks`]: IterArrayChunks::array_chunks
+    #[inline]
+    fn enumerate_array_chunks<const N: usize>(self) -> EnumerateArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        EnumerateArrayChunks {
+            iter: ArrayChunks::new(self),
+            count: 0,
+        }
+    }
+
+    /// Returns a short first chunk of `first_chunk` elements and an iterator
+    /// over `N` elements of the rest of the iterator at a time.
+    ///
+    /// This aligns the chunks to a stride of `N` when the iterator starts
+    /// partway through a frame. The first chunk is taken from the iterator
+    /// immediately and may be shorter than `first_chunk` if the iterator is
+    /// exhausted. The rest of the iterator is chunked in the same way as
+    /// [`array_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0` or if `first_chunk` is greater than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let (first, mut iter) = (0..6).array_chunks_aligned::<2>(1);
+    /// assert_eq!(first.as_slice(), [0]);
+    /// assert_eq!(iter.next(), Some([1, 2]));
+    /// assert_eq!(iter.next(), Some([3, 4]));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder().unwrap().as_slice(), [5]);
+    /// ```
+    ///
+    /// [`array_chunks`]: IterArrayChunks::array_chunks
+    #[inline]
+    #[track_caller]
+    fn array_chunks_aligned<const N: usize>(
+        mut self,
+        first_chunk: usize,
+    ) -> (IntoIter<Self::Item, N>, ArrayChunks<Self, N>)
+    where
+        Self: Sized,
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        assert!(
+            first_chunk <= N,
+            "first chunk size must be at most {}, but got {}",
+            N,
+            first_chunk
+        );
+        let first = match arrays::from_iter(self.by_ref().take(first_chunk)) {
+            Ok(chunk) => IntoIter::new(chunk),
+            Err(rem) => rem,
+        };
+        (first, ArrayChunks::new(self))
+    }
+
+    /// Returns an iterator over `N` consecutive elements of the iterator at a
+    /// time that share the same key.
+    ///
+    /// The key of each element is computed using the given function. Elements
+    /// are accumulated into a chunk while they have the same key and once `N`
+    /// elements have accumulated the chunk is yielded. If the key changes
+    /// before the chunk is full then the partial chunk is discarded and a new
+    /// chunk is started with the element that has the new key.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let data = [("a", 1), ("a", 2), ("a", 3), ("b", 4), ("c", 5), ("c", 6)];
+    /// let mut iter = data.into_iter().array_chunks_by_key::<2, _, _>(|&(k, _)| k);
+    /// assert_eq!(iter.next(), Some([("a", 1), ("a", 2)]));
+    /// assert_eq!(iter.next(), Some([("c", 5), ("c", 6)]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn array_chunks_by_key<const N: usize, K, F>(self, key: F) -> ArrayChunksByKey<Self, K, F, N>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        ArrayChunksByKey::new(self, key)
+    }
+
+    /// Collects `N` elements of the iterator at a time and maps each chunk in
+    /// parallel, returning the results along with the remainder.
+    ///
+    /// The chunks are collected into a [`Vec`] and mapped using rayon's
+    /// [`par_iter`], the results are in the same order as the chunks. If `N`
+    /// does not divide the length of the iterator, then the last up to `N-1`
+    /// elements are returned as the remainder.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let (sums, rem) = (1..=8).par_array_chunks_map(|[a, b, c]| a + b + c);
+    /// assert_eq!(sums, [6, 15]);
+    /// assert_eq!(rem.as_slice(), [7, 8]);
+    /// ```
+    ///
+    /// [`par_iter`]: rayon::iter::IntoParallelIterator::into_par_iter
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "array_chunks", feature = "rayon"))))]
+    #[track_caller]
+    fn par_array_chunks_map<const N: usize, R, F>(
+        mut self,
+        f: F,
+    ) -> (Vec<R>, IntoIter<Self::Item, N>)
+    where
+        Self: Sized,
+        Self::Item: Send,
+        R: Send,
+        F: Fn([Self::Item; N]) -> R + Sync + Send,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        assert!(N != 0, "chunk size must be non-zero");
+        let mut chunks = Vec::new();
+        let rem = loop {
+            match arrays::from_iter(self.by_ref()) {
+                Ok(chunk) => chunks.push(chunk),
+                Err(rem) => break rem,
+            }
+        };
+        (chunks.into_par_iter().map(f).collect(), rem)
+    }
+
     /// Identical to [`array_chunks`][IterArrayChunks::array_chunks] but doesn't
     /// collide with the standard library name.
     #[inline]
@@ -96,10 +300,52 @@ where
     /// Returns an iterator over the remaining elements of the original iterator
     /// that are not going to be yielded. The returned iterator will yield at
     /// most `N-1` elements. Returns `None` if the remainder is not yet known.
+    ///
+    /// The remainder can be iterated from either end since [`IntoIter`]
+    /// implements [`DoubleEndedIterator`]. Any elements that are not consumed
+    /// are dropped along with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let mut iter = (0..8).arrays::<3>();
+    /// for _ in iter.by_ref() {}
+    ///
+    /// let mut rem = iter.into_remainder().unwrap();
+    /// assert_eq!(rem.next_back(), Some(7));
+    /// assert_eq!(rem.next_back(), Some(6));
+    /// assert_eq!(rem.next_back(), None);
+    /// ```
     #[inline]
     pub fn into_remainder(self) -> Option<IntoIter<I::Item, N>> {
         self.remainder
     }
+
+    /// Returns the underlying iterator and the remainder.
+    ///
+    /// The underlying iterator is positioned after the last element that was
+    /// consumed, so if iteration stopped early it will yield the elements that
+    /// were not yet chunked. The remainder is the same as returned by
+    /// [`into_remainder`][ArrayChunks::into_remainder].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayChunks;
+    ///
+    /// let mut iter = (0..7).arrays::<2>();
+    /// assert_eq!(iter.next(), Some([0, 1]));
+    ///
+    /// let (inner, rem) = iter.into_parts();
+    /// assert!(rem.is_none());
+    /// assert_eq!(Vec::from_iter(inner), [2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn into_parts(self) -> (I, Option<IntoIter<I::Item, N>>) {
+        (self.iter, self.remainder)
+    }
 }
 
 impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N>
@@ -130,6 +376,23 @@ where
     fn count(self) -> usize {
         self.iter.count() / N
     }
+
+    /// Returns the `n`th chunk.
+    ///
+    /// This skips the elements of the first `n` chunks using the underlying
+    /// iterator's [`nth`][Iterator::nth] instead of collecting each chunk. If
+    /// the underlying iterator is exhausted while skipping then the skipped
+    /// elements are not kept as the remainder.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n > 0 {
+            // In the unlikely case that this overflows, no iterator could
+            // yield enough elements anyway.
+            let skip = n.saturating_mul(N);
+            self.iter.nth(skip - 1)?;
+        }
+        self.next()
+    }
 }
 
 impl<I, const N: usize> DoubleEndedIterator for ArrayChunks<I, N>
@@ -179,3 +442,315 @@ where
 }
 
 impl<I, const N: usize> FusedIterator for ArrayChunks<I, N> where I: FusedIterator {}
+
+/// An iterator over `N` elements of the iterator at a time, where the last
+/// chunk is padded to length `N`.
+///
+/// This struct is created by the [`array_chunks_padded`] method on iterators.
+/// See its documentation for more.
+///
+/// [`array_chunks_padded`]: IterArrayChunks::array_chunks_padded
+#[cfg_attr(docsrs, doc(cfg(feature = "array_chunks")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunksPadded<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    pad: I::Item,
+    done: bool,
+}
+
+impl<I, const N: usize> ArrayChunksPadded<I, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, pad: I::Item) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            pad,
+            done: false,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayChunksPadded<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match arrays::from_iter(&mut self.iter) {
+            Ok(chunk) => Some(chunk),
+            Err(rem) => {
+                self.done = true;
+                if rem.as_slice().is_empty() {
+                    return None;
+                }
+                let chunk = rem.chain(iter::repeat(self.pad.clone()));
+                // SAFETY: The chained iterator is infinite.
+                Some(unsafe { arrays::from_iter_unchecked(chunk) })
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let ceil = |n: usize| n / N + (n % N != 0) as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (ceil(lower), upper.map(ceil))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayChunksPadded<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for ArrayChunksPadded<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// An iterator over `N` elements of the iterator at a time and the index of
+/// each chunk.
+///
+/// This struct is created by the [`enumerate_array_chunks`] method on
+/// iterators. See its documentation for more.
+///
+/// [`enumerate_array_chunks`]: IterArrayChunks::enumerate_array_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "array_chunks")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EnumerateArrayChunks<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: ArrayChunks<I, N>,
+    count: usize,
+}
+
+impl<I, const N: usize> EnumerateArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    /// Returns an iterator over the remaining elements of the original iterator
+    /// that are not going to be yielded.
+    ///
+    /// See [`ArrayChunks::into_remainder`] for more details.
+    #[inline]
+    pub fn into_remainder(self) -> Option<IntoIter<I::Item, N>> {
+        self.iter.into_remainder()
+    }
+}
+
+impl<I, const N: usize> fmt::Debug for EnumerateArrayChunks<I, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnumerateArrayChunks")
+            .field("iter", &self.iter)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<I, const N: usize> Clone for EnumerateArrayChunks<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            count: self.count,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for EnumerateArrayChunks<I, N>
+where
+    I: Iterator,
+{
+    type Item = (usize, [I::Item; N]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.next()?;
+        let i = self.count;
+        self.count += 1;
+        Some((i, chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, const N: usize> DoubleEndedIterator for EnumerateArrayChunks<I, N>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.next_back()?;
+        Some((self.count + self.iter.len(), chunk))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for EnumerateArrayChunks<I, N>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, const N: usize> FusedIterator for EnumerateArrayChunks<I, N> where I: FusedIterator {}
+
+/// An iterator over `N` consecutive elements of the iterator at a time that
+/// share the same key.
+///
+/// This struct is created by the [`array_chunks_by_key`] method on iterators.
+/// See its documentation for more.
+///
+/// [`array_chunks_by_key`]: IterArrayChunks::array_chunks_by_key
+#[cfg_attr(docsrs, doc(cfg(feature = "array_chunks")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunksByKey<I, K, F, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    key: F,
+
+    /// The element that ended the previous chunk and its key, which starts
+    /// the next chunk.
+    peeked: Option<(K, I::Item)>,
+}
+
+impl<I, K, F, const N: usize> ArrayChunksByKey<I, K, F, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, key: F) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            key,
+            peeked: None,
+        }
+    }
+}
+
+impl<I, K, F, const N: usize> fmt::Debug for ArrayChunksByKey<I, K, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayChunksByKey")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+impl<I, K, F, const N: usize> Clone for ArrayChunksByKey<I, K, F, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    K: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            key: self.key.clone(),
+            peeked: self.peeked.clone(),
+        }
+    }
+}
+
+impl<I, K, F, const N: usize> Iterator for ArrayChunksByKey<I, K, F, N>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, key, peeked } = self;
+        loop {
+            let (k, first) = match peeked.take() {
+                Some(p) => p,
+                None => {
+                    let item = iter.next()?;
+                    (key(&item), item)
+                }
+            };
+            let mut next = None;
+            let run = iter::from_fn(|| {
+                let item = iter.next()?;
+                let item_k = key(&item);
+                if item_k == k {
+                    Some(item)
+                } else {
+                    next = Some((item_k, item));
+                    None
+                }
+            });
+            let chunk = arrays::from_iter(iter::once(first).chain(run));
+            *peeked = next;
+            match chunk {
+                Ok(chunk) => return Some(chunk),
+                // The partial chunk is discarded, if the underlying iterator
+                // is exhausted then the next loop will return `None`.
+                Err(_) => continue,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = self.peeked.is_some() as usize;
+        let (_, upper) = self.iter.size_hint();
+        (0, upper.and_then(|n| n.checked_add(peeked)).map(|n| n / N))
+    }
+}
+
+impl<I, K, F, const N: usize> FusedIterator for ArrayChunksByKey<I, K, F, N>
+where
+    I: FusedIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+}
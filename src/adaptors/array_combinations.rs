@@ -2,7 +2,7 @@ use core::fmt;
 use core::fmt::Debug;
 use core::iter::FusedIterator;
 
-use crate::adaptors::generic_combinations::GenericCombinations;
+use crate::adaptors::generic_combinations::{CombinationsState, Dropped, GenericCombinations};
 
 /// An extension trait that provides the [`array_combinations`] method for
 /// iterators.
@@ -42,6 +42,138 @@ pub trait IterArrayCombinations: Iterator {
     {
         ArrayCombinations::new(self)
     }
+
+    /// Returns an iterator adaptor that iterates over the positions of the
+    /// elements in `K` length combinations of the underlying iterator.
+    ///
+    /// The positions are yielded in the same order as [`array_combinations`]
+    /// but the elements themselves are dropped instead of being buffered, so
+    /// they do not need to implement [`Clone`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `K = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let mut iter = "abcd".chars().array_index_combinations();
+    /// assert_eq!(iter.next(), Some([0, 1, 2]));
+    /// assert_eq!(iter.next(), Some([0, 1, 3]));
+    /// assert_eq!(iter.next(), Some([0, 2, 3]));
+    /// assert_eq!(iter.next(), Some([1, 2, 3]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_combinations`]: IterArrayCombinations::array_combinations
+    #[inline]
+    fn array_index_combinations<const K: usize>(self) -> ArrayIndexCombinations<Self, K>
+    where
+        Self: Sized,
+    {
+        ArrayIndexCombinations::new(self)
+    }
+
+    /// Returns an iterator adaptor that iterates over `K` length combinations
+    /// of all the elements in the underlying iterator, buffering at most
+    /// `max_buffer` elements.
+    ///
+    /// The [`array_combinations`] adaptor keeps every element that it has
+    /// consumed in a buffer since later combinations need them. When the
+    /// underlying iterator is infinite the buffer grows without bound, with
+    /// one more element for every combination. This adaptor yields the same
+    /// combinations in the same order but stops as soon as the next
+    /// combination would need to buffer more than `max_buffer` elements. So
+    /// if the underlying iterator yields at most `max_buffer` elements then
+    /// this is the same as [`array_combinations`], otherwise the iterator
+    /// ends early.
+    ///
+    /// Note that this is not the same as limiting the underlying iterator to
+    /// `max_buffer` elements using [`take`], because the combinations are
+    /// yielded in lexicographic order of the positions and the iterator stops
+    /// at the first that needs the element at position `max_buffer`, instead
+    /// of yielding all the combinations of the first `max_buffer` elements.
+    /// If `K` is greater than `max_buffer` then no combinations are yielded.
+    ///
+    /// # Panics
+    ///
+    /// If called with `K = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let v = Vec::from_iter((0..).array_combinations_bounded::<2>(4));
+    /// assert_eq!(v, [[0, 1], [0, 2], [0, 3]]);
+    ///
+    /// let v = Vec::from_iter((0..4).array_combinations_bounded::<2>(4));
+    /// assert_eq!(v, [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+    /// ```
+    ///
+    /// [`array_combinations`]: IterArrayCombinations::array_combinations
+    /// [`take`]: Iterator::take
+    #[inline]
+    fn array_combinations_bounded<const K: usize>(
+        self,
+        max_buffer: usize,
+    ) -> ArrayCombinationsBounded<Self, K>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        ArrayCombinationsBounded::new(self, max_buffer)
+    }
+
+    /// Returns an iterator adaptor that iterates over `K` length combinations
+    /// of all the elements in the underlying iterator, pruning combinations
+    /// that start with a rejected prefix.
+    ///
+    /// The combinations are built up one element at a time and the predicate
+    /// is called with each partial combination, from length `1` up to and
+    /// including the full combination of length `K`. If the predicate returns
+    /// `false` then no combinations starting with that prefix are generated
+    /// or passed to the predicate, so whole branches of the search can be
+    /// skipped. The accepted combinations are yielded in the same order as
+    /// [`array_combinations`].
+    ///
+    /// Only the extensions of a rejected prefix are skipped, the following
+    /// elements are still tried at the same position. So like
+    /// [`array_combinations`] this does not terminate if the underlying
+    /// iterator is infinite.
+    ///
+    /// # Panics
+    ///
+    /// If called with `K = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Find all sets of three distinct numbers that sum to at most 8.
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let v = Vec::from_iter(
+    ///     (1..=10).array_combinations_filtered::<3, _>(|c: &[i32]| c.iter().sum::<i32>() <= 8),
+    /// );
+    /// assert_eq!(v, [[1, 2, 3], [1, 2, 4], [1, 2, 5], [1, 3, 4]]);
+    /// ```
+    ///
+    /// [`array_combinations`]: IterArrayCombinations::array_combinations
+    #[inline]
+    fn array_combinations_filtered<const K: usize, F>(
+        self,
+        accept: F,
+    ) -> ArrayCombinationsFiltered<Self, F, K>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&[Self::Item]) -> bool,
+    {
+        ArrayCombinationsFiltered::new(self, accept)
+    }
 }
 
 impl<I: ?Sized> IterArrayCombinations for I where I: Iterator {}
@@ -73,6 +205,133 @@ where
 
         Self(GenericCombinations::new(iter, comb))
     }
+
+    /// Creates an iterator that continues from a saved [`state`] over a fresh
+    /// instance of the original iterator.
+    ///
+    /// The elements that the original iterator had buffered are consumed from
+    /// the iterator immediately, the iterator must yield the same elements as
+    /// the original one for the combinations to be correct.
+    ///
+    /// # Panics
+    ///
+    /// If the state is not valid, if the length of the saved combination is
+    /// not `K`, or if the iterator is shorter than the saved combination
+    /// requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::{ArrayCombinations, IterArrayCombinations};
+    ///
+    /// let mut iter = "abcd".chars().array_combinations::<2>();
+    /// assert_eq!(iter.next(), Some(['a', 'b']));
+    /// let state = iter.state();
+    ///
+    /// let mut iter = ArrayCombinations::<_, 2>::resume_from("abcd".chars(), state);
+    /// assert_eq!(iter.next(), Some(['a', 'c']));
+    /// ```
+    ///
+    /// [`state`]: ArrayCombinations::state
+    #[track_caller]
+    pub fn resume_from(iter: I, state: CombinationsState) -> Self {
+        assert!(K != 0, "combination size must be non-zero");
+        let (comb, started) = state.into_parts();
+        assert!(
+            comb.len() == K,
+            "expected a combination state of length {}",
+            K
+        );
+        let comb = arrays::from_iter(comb.into_iter()).ok().unwrap();
+        Self(GenericCombinations::resume(iter, comb, started))
+    }
+
+    /// Returns the current position of the iterator, which can be used to
+    /// resume iteration later using
+    /// [`resume_from`][ArrayCombinations::resume_from].
+    #[inline]
+    pub fn state(&self) -> CombinationsState {
+        self.0.state()
+    }
+}
+
+impl<I, const K: usize> ArrayCombinations<I, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Writes the next combination into the given array instead of returning
+    /// a new one. Returns `false` if there are no more combinations.
+    ///
+    /// The elements are cloned into the existing values using
+    /// [`Clone::clone_from`], so allocations in the elements themselves can
+    /// be reused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let mut iter = ["a", "b", "c"].map(String::from).into_iter().array_combinations();
+    /// let mut buf = [String::new(), String::new()];
+    /// while iter.fill_next_into(&mut buf) {
+    ///     println!("{:?}", buf);
+    /// }
+    /// // Outputs
+    /// //    ["a", "b"]
+    /// //    ["a", "c"]
+    /// //    ["b", "c"]
+    /// ```
+    #[inline]
+    pub fn fill_next_into(&mut self, out: &mut [I::Item; K]) -> bool {
+        self.0.fill_next_into(out)
+    }
+
+    /// Returns the total number of combinations, including those that have
+    /// already been yielded.
+    ///
+    /// This consumes the rest of the underlying iterator into the buffer since
+    /// the total depends on its length, so it should not be called on an
+    /// infinite iterator.
+    ///
+    /// Returns `None` if the total overflows a [`u128`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let mut iter = "abcd".chars().array_combinations::<3>();
+    /// assert_eq!(iter.total(), Some(4));
+    /// ```
+    #[inline]
+    pub fn total(&mut self) -> Option<u128> {
+        self.0.total()
+    }
+
+    /// Returns the number of combinations that have been yielded so far.
+    ///
+    /// This is calculated from the current combination without consuming any
+    /// more of the underlying iterator and together with
+    /// [`total`][ArrayCombinations::total] can be used to report progress. Saturates at
+    /// [`u128::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinations;
+    ///
+    /// let mut iter = "abcd".chars().array_combinations::<2>();
+    /// assert_eq!(iter.produced(), 0);
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!(iter.produced(), 2);
+    /// assert_eq!(iter.total(), Some(6));
+    /// ```
+    #[inline]
+    pub fn produced(&self) -> u128 {
+        self.0.produced()
+    }
 }
 
 impl<I, const K: usize> Clone for ArrayCombinations<I, K>
@@ -117,3 +376,322 @@ where
     I::Item: Clone,
 {
 }
+
+/// An iterator that iterates over `K` length combinations of all the elements
+/// in the underlying iterator, buffering at most a maximum number of elements.
+///
+/// This struct is created by the [`array_combinations_bounded`] method on
+/// iterators. See its documentation for more.
+///
+/// [`array_combinations_bounded`]: IterArrayCombinations::array_combinations_bounded
+#[cfg_attr(docsrs, doc(cfg(feature = "array_combinations")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayCombinationsBounded<I, const K: usize>
+where
+    I: Iterator,
+{
+    inner: GenericCombinations<I, [usize; K]>,
+    max_buffer: usize,
+    done: bool,
+}
+
+impl<I, const K: usize> ArrayCombinationsBounded<I, K>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, max_buffer: usize) -> Self {
+        assert!(K != 0, "combination size must be non-zero");
+
+        // SAFETY: The range 0..K yields at least K elements.
+        let comb = unsafe { arrays::from_iter_unchecked(0..K) };
+
+        Self {
+            inner: GenericCombinations::new(iter, comb),
+            max_buffer,
+            done: false,
+        }
+    }
+}
+
+impl<I, const K: usize> Clone for ArrayCombinationsBounded<I, K>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            max_buffer: self.max_buffer,
+            done: self.done,
+        }
+    }
+}
+
+impl<I, const K: usize> Debug for ArrayCombinationsBounded<I, K>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt_with(f, "ArrayCombinationsBounded")
+    }
+}
+
+impl<I, const K: usize> Iterator for ArrayCombinationsBounded<I, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.inner.fill_next_bounded(self.max_buffer) {
+            // SAFETY: The iterator is guaranteed to yield K elements because
+            // it is derived from `self.inner.comb` which is an array of
+            // length K.
+            Some(it) => Some(unsafe { arrays::from_iter_unchecked(it) }),
+            None => {
+                // Don't consume any more elements from the underlying
+                // iterator once the buffer limit has been reached.
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<I, const K: usize> FusedIterator for ArrayCombinationsBounded<I, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// An iterator that iterates over `K` length combinations of all the elements
+/// in the underlying iterator, pruning combinations that start with a rejected
+/// prefix.
+///
+/// This struct is created by the [`array_combinations_filtered`] method on
+/// iterators. See its documentation for more.
+///
+/// [`array_combinations_filtered`]: IterArrayCombinations::array_combinations_filtered
+#[cfg_attr(docsrs, doc(cfg(feature = "array_combinations")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayCombinationsFiltered<I, F, const K: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    accept: F,
+
+    /// A buffer containing the elements that have been consumed so far.
+    buf: Vec<I::Item>,
+
+    /// Whether the underlying iterator has been exhausted, in which case the
+    /// buffer contains all the elements.
+    exhausted: bool,
+
+    /// The positions of the elements in the accepted prefix, followed by the
+    /// position of the next element to try after the prefix.
+    comb: [usize; K],
+
+    /// The elements of the accepted prefix.
+    prefix: Vec<I::Item>,
+
+    done: bool,
+}
+
+impl<I, F, const K: usize> ArrayCombinationsFiltered<I, F, K>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, accept: F) -> Self {
+        assert!(K != 0, "combination size must be non-zero");
+        Self {
+            iter,
+            accept,
+            buf: Vec::new(),
+            exhausted: false,
+            comb: [0; K],
+            prefix: Vec::with_capacity(K),
+            done: false,
+        }
+    }
+}
+
+impl<I, F, const K: usize> Clone for ArrayCombinationsFiltered<I, F, K>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            accept: self.accept.clone(),
+            buf: self.buf.clone(),
+            exhausted: self.exhausted,
+            comb: self.comb,
+            prefix: self.prefix.clone(),
+            done: self.done,
+        }
+    }
+}
+
+impl<I, F, const K: usize> Debug for ArrayCombinationsFiltered<I, F, K>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayCombinationsFiltered")
+            .field("iter", &self.iter)
+            .field("buf", &self.buf)
+            .field("exhausted", &self.exhausted)
+            .field("comb", &self.comb)
+            .field("prefix", &self.prefix)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, F, const K: usize> Iterator for ArrayCombinationsFiltered<I, F, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&[I::Item]) -> bool,
+{
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            // This is a depth first search where `depth` is the position in
+            // the combination that is being filled.
+            let depth = self.prefix.len();
+            let d = self.comb[depth];
+
+            // Make sure the candidate element is buffered.
+            while !self.exhausted && self.buf.len() <= d {
+                match self.iter.next() {
+                    Some(item) => self.buf.push(item),
+                    None => self.exhausted = true,
+                }
+            }
+
+            // Once all the elements are known we can tell whether there are
+            // enough elements left after the candidate to fill the rest of
+            // the combination. If not then the same is true for every later
+            // candidate so we backtrack to the previous position.
+            if self.exhausted && d + (K - depth) > self.buf.len() {
+                if depth == 0 {
+                    self.done = true;
+                    self.buf.clear();
+                    return None;
+                }
+                self.prefix.pop();
+                self.comb[depth - 1] += 1;
+                continue;
+            }
+
+            self.prefix.push(self.buf[d].clone());
+            if !(self.accept)(&self.prefix) {
+                self.prefix.pop();
+                self.comb[depth] += 1;
+                continue;
+            }
+
+            if depth + 1 < K {
+                self.comb[depth + 1] = d + 1;
+                continue;
+            }
+
+            // The combination is complete, the last element is removed
+            // from the prefix so that the next call tries the following
+            // element at the same position.
+            //
+            // SAFETY: The prefix had K elements so the chained iterator is
+            // guaranteed to yield K elements.
+            let last = self.prefix.pop();
+            self.comb[depth] += 1;
+            let comb = self.prefix.iter().cloned().chain(last);
+            return Some(unsafe { arrays::from_iter_unchecked(comb) });
+        }
+    }
+}
+
+impl<I, F, const K: usize> FusedIterator for ArrayCombinationsFiltered<I, F, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&[I::Item]) -> bool,
+{
+}
+
+/// An iterator that iterates over the positions of the elements in `K` length
+/// combinations of the underlying iterator.
+///
+/// This struct is created by the [`array_index_combinations`] method on
+/// iterators. See its documentation for more.
+///
+/// [`array_index_combinations`]: IterArrayCombinations::array_index_combinations
+#[cfg_attr(docsrs, doc(cfg(feature = "array_combinations")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayIndexCombinations<I, const K: usize>(GenericCombinations<Dropped<I>, [usize; K]>)
+where
+    I: Iterator;
+
+impl<I, const K: usize> ArrayIndexCombinations<I, K>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I) -> Self {
+        assert!(K != 0, "combination size must be non-zero");
+
+        // SAFETY: The range 0..K yields at least K elements.
+        let comb = unsafe { arrays::from_iter_unchecked(0..K) };
+
+        let iter = iter.map(drop as fn(I::Item));
+        Self(GenericCombinations::new(iter, comb))
+    }
+}
+
+impl<I, const K: usize> Clone for ArrayIndexCombinations<I, K>
+where
+    I: Iterator + Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I, const K: usize> Debug for ArrayIndexCombinations<I, K>
+where
+    I: Iterator + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_with(f, "ArrayIndexCombinations")
+    }
+}
+
+impl<I, const K: usize> Iterator for ArrayIndexCombinations<I, K>
+where
+    I: Iterator,
+{
+    type Item = [usize; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance()?;
+        Some(*self.0.indices())
+    }
+}
+
+impl<I, const K: usize> FusedIterator for ArrayIndexCombinations<I, K> where I: Iterator {}
@@ -69,6 +69,30 @@ where
         assert!(K != 0, "combination size must be non-zero");
         Self(GenericCombinations::new(iter, [0; K]))
     }
+
+    /// Returns the total number of combinations, including those that have
+    /// already been yielded.
+    ///
+    /// This consumes the rest of the underlying iterator into the buffer since
+    /// the total depends on its length, so it should not be called on an
+    /// infinite iterator.
+    ///
+    /// Returns `None` if the total overflows a [`u128`] or if the iterator has
+    /// already been exhausted, since the buffered elements are freed at that
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayCombinationsWithReps;
+    ///
+    /// let mut iter = "abcd".chars().array_combinations_with_reps::<3>();
+    /// assert_eq!(iter.total(), Some(64));
+    /// ```
+    #[inline]
+    pub fn total(&mut self) -> Option<u128> {
+        self.0.total_with_reps()
+    }
 }
 
 impl<I, const K: usize> Clone for ArrayCombinationsWithReps<I, K>
@@ -105,6 +129,28 @@ where
             unsafe { arrays::from_iter_unchecked(it) }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint_with_reps()
+    }
+}
+
+impl<I, const K: usize> DoubleEndedIterator for ArrayCombinationsWithReps<I, K>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Returns the previous combination from the back.
+    ///
+    /// This consumes the entire underlying iterator into the buffer on the
+    /// first call since the last combination depends on its length.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.fill_next_back_with_reps().map(|it| {
+            // SAFETY: The iterator is guaranteed to yield K elements because
+            // it is derived from `self.0.back` which is an array of length K.
+            unsafe { arrays::from_iter_unchecked(it) }
+        })
+    }
 }
 
 impl<I, const K: usize> FusedIterator for ArrayCombinationsWithReps<I, K>
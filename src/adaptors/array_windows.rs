@@ -1,6 +1,8 @@
+use core::fmt;
 use core::iter::FusedIterator;
 
-/// An extension trait that provides the [`array_windows`] method for iterators.
+/// An extension trait that provides the [`array_windows`] method and friends
+/// for iterators.
 ///
 /// [`array_windows`]: IterArrayWindows::array_windows
 #[cfg_attr(docsrs, doc(cfg(feature = "array_windows")))]
@@ -48,6 +50,136 @@ pub trait IterArrayWindows: Iterator {
     {
         ArrayWindows::new(self)
     }
+
+    /// Returns an iterator over all contiguous windows of length `N` along
+    /// with the start index of each window.
+    ///
+    /// The index is the position of the first element of the window in the
+    /// original iterator.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayWindows;
+    ///
+    /// let mut iter = "rust".chars().indexed_array_windows();
+    /// assert_eq!(iter.next(), Some((0, ['r', 'u'])));
+    /// assert_eq!(iter.next(), Some((1, ['u', 's'])));
+    /// assert_eq!(iter.next(), Some((2, ['s', 't'])));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn indexed_array_windows<const N: usize>(self) -> IndexedArrayWindows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        IndexedArrayWindows {
+            iter: ArrayWindows::new(self),
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator over the contiguous windows of length `N` where the
+    /// last element of the window satisfies the given predicate.
+    ///
+    /// The window slides over every element of the iterator in the same way
+    /// as [`array_windows`], but it is only yielded when the newest element
+    /// matches the predicate. The predicate is called exactly once for each
+    /// element from the `N`th onwards, and windows that are not yielded are
+    /// not cloned.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Get the two samples leading up to each marker.
+    ///
+    /// ```
+    /// use itermore::IterArrayWindows;
+    ///
+    /// let samples = [3, 4, -1, 5, 6, 7, -1, -1];
+    /// let v = Vec::from_iter(samples.into_iter().gated_array_windows(|&s| s < 0));
+    /// assert_eq!(v, [[3, 4, -1], [6, 7, -1], [7, -1, -1]]);
+    /// ```
+    ///
+    /// [`array_windows`]: IterArrayWindows::array_windows
+    #[inline]
+    fn gated_array_windows<const N: usize, F>(self, gate: F) -> GatedArrayWindows<Self, F, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        GatedArrayWindows::new(self, gate)
+    }
+
+    /// Calls a closure on all contiguous windows of length `N`, writing the
+    /// results into the given slice.
+    ///
+    /// Stops when either there are no more windows or the slice is full and
+    /// returns the number of results that were written. No more elements are
+    /// taken from the iterator than are needed for the written windows, so it
+    /// can be called on a mutable reference to continue using the iterator
+    /// afterwards.
+    ///
+    /// Unlike [`array_windows`] the window is passed to the closure by
+    /// reference and is updated in place, so the elements do not need to
+    /// implement [`Clone`] and nothing is allocated.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayWindows;
+    ///
+    /// let mut diffs = [0; 8];
+    /// let n = [1, 4, 9, 16, 25].into_iter().array_windows_for_each(&mut diffs, |[a, b]| b - a);
+    /// assert_eq!(n, 4);
+    /// assert_eq!(diffs[..n], [3, 5, 7, 9]);
+    /// ```
+    ///
+    /// [`array_windows`]: IterArrayWindows::array_windows
+    #[inline]
+    #[track_caller]
+    fn array_windows_for_each<const N: usize, R, F>(mut self, out: &mut [R], mut f: F) -> usize
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item; N]) -> R,
+    {
+        assert!(N != 0, "window size must be non-zero");
+        let mut slots = out.iter_mut();
+        let slot = match slots.next() {
+            Some(slot) => slot,
+            None => return 0,
+        };
+        let mut window: [Self::Item; N] = match arrays::from_iter(self.by_ref()) {
+            Ok(window) => window,
+            Err(_) => return 0,
+        };
+        *slot = f(&window);
+        let mut count = 1;
+        for slot in slots {
+            let item = match self.next() {
+                Some(item) => item,
+                None => break,
+            };
+            window.rotate_left(1);
+            window[N - 1] = item;
+            *slot = f(&window);
+            count += 1;
+        }
+        count
+    }
 }
 
 impl<I: ?Sized> IterArrayWindows for I where I: Iterator {}
@@ -67,6 +199,13 @@ where
 {
     iter: I,
     last: Option<[I::Item; N]>,
+
+    /// The last window yielded from the back and the number of windows that
+    /// remain between it and the front.
+    back: Option<([I::Item; N], usize)>,
+
+    /// Whether `last` was given as a prefix and has not been yielded yet.
+    pending: bool,
 }
 
 impl<I, const N: usize> ArrayWindows<I, N>
@@ -76,7 +215,74 @@ where
 {
     fn new(iter: I) -> Self {
         assert!(N != 0, "window size must be non-zero");
-        Self { iter, last: None }
+        Self {
+            iter,
+            last: None,
+            back: None,
+            pending: false,
+        }
+    }
+
+    /// Creates a new iterator over all contiguous windows of length `N` where
+    /// the first window is the given prefix.
+    ///
+    /// The prefix is treated exactly as if it was the first window read from
+    /// the underlying iterator. It is yielded first and each following window
+    /// only requires one new element from the underlying iterator. This is
+    /// useful to resume processing a stream where the last window is already
+    /// known.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::ArrayWindows;
+    ///
+    /// let mut iter = ArrayWindows::with_prefix(4..6, [1, 2, 3]);
+    /// assert_eq!(iter.next(), Some([1, 2, 3]));
+    /// assert_eq!(iter.next(), Some([2, 3, 4]));
+    /// assert_eq!(iter.next(), Some([3, 4, 5]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[track_caller]
+    pub fn with_prefix(iter: I, prefix: [I::Item; N]) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self {
+            iter,
+            last: Some(prefix),
+            back: None,
+            pending: true,
+        }
+    }
+
+    /// Returns the underlying iterator.
+    ///
+    /// The iterator is positioned after all the elements that have been
+    /// consumed. Yielding the first window consumes `N` elements and each
+    /// window after that consumes one more, so after `k > 0` windows have been
+    /// yielded from the front `N - 1 + k` elements have been consumed. If the
+    /// iterator was created using [`with_prefix`] then the prefix was never
+    /// taken from the underlying iterator, so only `k - 1` elements have been
+    /// consumed. Any windows yielded from the back consume elements from the
+    /// back of the iterator in the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayWindows;
+    ///
+    /// let mut iter = (0..6).array_windows::<3>();
+    /// assert_eq!(iter.next(), Some([0, 1, 2]));
+    /// assert_eq!(iter.into_inner().next(), Some(3));
+    /// ```
+    ///
+    /// [`with_prefix`]: ArrayWindows::with_prefix
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
     }
 }
 
@@ -89,7 +295,34 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let Self { iter, last } = self;
+        let Self {
+            iter,
+            last,
+            back,
+            pending,
+        } = self;
+
+        if *pending {
+            *pending = false;
+            return last.clone();
+        }
+
+        // If windows have been yielded from the back then the windows stop
+        // when they meet. Since the underlying iterator is shared the front
+        // window continues with elements from the back window once the
+        // iterator is exhausted.
+        let rest = match back {
+            None => &[][..],
+            Some((_, 0)) => return None,
+            Some((b, r)) => {
+                *r -= 1;
+                match last {
+                    Some(_) => (N - 1).checked_sub(*r + 1).map_or(&[][..], |i| &b[i..]),
+                    None => &b[..],
+                }
+            }
+        };
+        let mut iter = iter.by_ref().chain(rest.iter().cloned());
 
         match last {
             Some(last) => {
@@ -110,16 +343,126 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending as usize;
+        if let Some((_, r)) = self.back {
+            return (r + pending, Some(r + pending));
+        }
         let (lower, upper) = self.iter.size_hint();
-        (
-            lower.saturating_sub(N - 1),
-            upper.map(|n| n.saturating_sub(N - 1)),
-        )
+        match self.last {
+            Some(_) => (
+                lower.saturating_add(pending),
+                upper.and_then(|n| n.checked_add(pending)),
+            ),
+            None => (
+                lower.saturating_sub(N - 1),
+                upper.map(|n| n.saturating_sub(N - 1)),
+            ),
+        }
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.iter.count().saturating_sub(N - 1)
+        let pending = self.pending as usize;
+        match (self.back, self.last) {
+            (Some((_, r)), _) => r + pending,
+            (None, Some(_)) => self.iter.count() + pending,
+            (None, None) => self.iter.count().saturating_sub(N - 1),
+        }
+    }
+}
+
+impl<I, const N: usize> DoubleEndedIterator for ArrayWindows<I, N>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.advance_back().cloned()
+    }
+
+    /// Searches for a window from the back of the iterator that satisfies
+    /// the predicate.
+    ///
+    /// The predicate is called with a reference into the internal buffer so
+    /// only the matching window is cloned.
+    #[inline]
+    fn rfind<P>(&mut self, mut predicate: P) -> Option<Self::Item>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        while let Some(window) = self.advance_back() {
+            if predicate(window) {
+                return Some(window.clone());
+            }
+        }
+        None
+    }
+}
+
+impl<I, const N: usize> ArrayWindows<I, N>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    I::Item: Clone,
+{
+    /// Advances the back window and returns a reference to it.
+    fn advance_back(&mut self) -> Option<&[I::Item; N]> {
+        let Self {
+            iter,
+            last,
+            back,
+            pending,
+        } = self;
+
+        match back {
+            // Only the prefix is left.
+            Some((_, 0)) if *pending => {
+                *pending = false;
+                last.as_ref()
+            }
+            Some((_, 0)) => None,
+            Some((b, r)) => {
+                // The element before the back window, if the iterator is
+                // exhausted then it is the one in the front window.
+                let i = *r;
+                *r -= 1;
+                let item = match iter.next_back() {
+                    Some(item) => item,
+                    None => last.as_ref()?.get(i)?.clone(),
+                };
+                b.rotate_right(1);
+                b[0] = item;
+                Some(b)
+            }
+            None => {
+                let m = iter.len();
+                let r = match last {
+                    Some(_) => m,
+                    None => m.saturating_sub(N - 1),
+                };
+                if r == 0 {
+                    if *pending {
+                        *pending = false;
+                        return last.as_ref();
+                    }
+                    return None;
+                }
+                let b = match last {
+                    // Not enough elements left in the iterator so the start of
+                    // the window overlaps with the front window.
+                    Some(f) if m < N => {
+                        arrays::from_iter(f[m..].iter().cloned().chain(iter)).ok()?
+                    }
+                    _ => {
+                        let mut b = arrays::from_iter(iter.by_ref().rev()).ok()?;
+                        b.reverse();
+                        b
+                    }
+                };
+                let (b, _) = back.insert((b, r - 1));
+                Some(b)
+            }
+        }
     }
 }
 
@@ -130,7 +473,12 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        self.iter.len().saturating_sub(N - 1)
+        let pending = self.pending as usize;
+        match (&self.back, &self.last) {
+            (Some((_, r)), _) => *r + pending,
+            (None, Some(_)) => self.iter.len() + pending,
+            (None, None) => self.iter.len().saturating_sub(N - 1),
+        }
     }
 }
 
@@ -140,3 +488,213 @@ where
     I::Item: Clone,
 {
 }
+
+/// An iterator over the contiguous windows of length `N` where the last
+/// element of the window satisfies a predicate.
+///
+/// This struct is created by the [`gated_array_windows`] method on iterators.
+/// See its documentation for more.
+///
+/// [`gated_array_windows`]: IterArrayWindows::gated_array_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GatedArrayWindows<I, F, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    gate: F,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I, F, const N: usize> GatedArrayWindows<I, F, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, gate: F) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self {
+            iter,
+            gate,
+            window: None,
+        }
+    }
+}
+
+impl<I, F, const N: usize> fmt::Debug for GatedArrayWindows<I, F, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GatedArrayWindows")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+impl<I, F, const N: usize> Clone for GatedArrayWindows<I, F, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            gate: self.gate.clone(),
+            window: self.window.clone(),
+        }
+    }
+}
+
+impl<I, F, const N: usize> Iterator for GatedArrayWindows<I, F, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, gate, window } = self;
+        let window = match window {
+            Some(window) => window,
+            None => {
+                let first = arrays::from_iter(iter.by_ref()).ok()?;
+                let window = window.insert(first);
+                if gate(&window[N - 1]) {
+                    return Some(window.clone());
+                }
+                window
+            }
+        };
+        for item in iter {
+            window.rotate_left(1);
+            window[N - 1] = item;
+            if gate(&window[N - 1]) {
+                return Some(window.clone());
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        let upper = match self.window {
+            Some(_) => upper,
+            None => upper.map(|n| n.saturating_sub(N - 1)),
+        };
+        (0, upper)
+    }
+}
+
+impl<I, F, const N: usize> FusedIterator for GatedArrayWindows<I, F, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item) -> bool,
+{
+}
+
+/// An iterator over all contiguous windows of length `N` and their start
+/// indexes.
+///
+/// This struct is created by the [`indexed_array_windows`] method on
+/// iterators. See its documentation for more.
+///
+/// [`indexed_array_windows`]: IterArrayWindows::indexed_array_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexedArrayWindows<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: ArrayWindows<I, N>,
+    index: usize,
+}
+
+impl<I, const N: usize> fmt::Debug for IndexedArrayWindows<I, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedArrayWindows")
+            .field("iter", &self.iter)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I, const N: usize> Clone for IndexedArrayWindows<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            index: self.index,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for IndexedArrayWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (usize, [I::Item; N]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some((index, window))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, const N: usize> DoubleEndedIterator for IndexedArrayWindows<I, N>
+where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let window = self.iter.next_back()?;
+        Some((self.index + self.iter.len(), window))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for IndexedArrayWindows<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, const N: usize> FusedIterator for IndexedArrayWindows<I, N>
+where
+    I: FusedIterator + Clone,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,164 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::{FusedIterator, Sum};
+use core::ops::Mul;
+
+use crate::{ArrayWindows, IterArrayWindows};
+
+/// An extension trait that provides the [`array_windows_dot`] method for
+/// iterators.
+///
+/// [`array_windows_dot`]: IterArrayWindowsDot::array_windows_dot
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows_dot")))]
+pub trait IterArrayWindowsDot: Iterator {
+    /// Returns an iterator over the dot product of all contiguous windows of
+    /// length `N` with the given kernel.
+    ///
+    /// Each element of the window is multiplied with the element of the
+    /// kernel at the same position and the products are summed. This is a
+    /// sliding dot product, the core of a finite impulse response filter. If
+    /// the iterator is shorter than `N`, the iterator returns no values.
+    ///
+    /// The windows are produced by [`array_windows`], see its documentation for
+    /// more.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// A moving sum of differences.
+    ///
+    /// ```
+    /// use itermore::IterArrayWindowsDot;
+    ///
+    /// let mut iter = [1, 4, 9, 16].into_iter().array_windows_dot([-1, 1]);
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(5));
+    /// assert_eq!(iter.next(), Some(7));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows`]: IterArrayWindows::array_windows
+    #[inline]
+    fn array_windows_dot<K, const N: usize>(self, kernel: [K; N]) -> ArrayWindowsDot<Self, K, N>
+    where
+        Self: Sized,
+        Self::Item: Clone + Mul<K>,
+        K: Clone,
+        <Self::Item as Mul<K>>::Output: Sum,
+    {
+        ArrayWindowsDot::new(self, kernel)
+    }
+}
+
+impl<I: ?Sized> IterArrayWindowsDot for I where I: Iterator {}
+
+/// An iterator over the dot product of all contiguous windows of length `N`
+/// with a kernel.
+///
+/// This struct is created by the [`array_windows_dot`] method on iterators.
+/// See its documentation for more.
+///
+/// [`array_windows_dot`]: IterArrayWindowsDot::array_windows_dot
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows_dot")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindowsDot<I, K, const N: usize>
+where
+    I: Iterator,
+{
+    iter: ArrayWindows<I, N>,
+    kernel: [K; N],
+}
+
+impl<I, K, const N: usize> ArrayWindowsDot<I, K, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn new(iter: I, kernel: [K; N]) -> Self {
+        Self {
+            iter: iter.array_windows(),
+            kernel,
+        }
+    }
+}
+
+impl<I, K, const N: usize> Debug for ArrayWindowsDot<I, K, N>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+    K: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayWindowsDot")
+            .field("iter", &self.iter)
+            .field("kernel", &self.kernel)
+            .finish()
+    }
+}
+
+impl<I, K, const N: usize> Clone for ArrayWindowsDot<I, K, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    K: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            kernel: self.kernel.clone(),
+        }
+    }
+}
+
+impl<I, K, const N: usize> Iterator for ArrayWindowsDot<I, K, N>
+where
+    I: Iterator,
+    I::Item: Clone + Mul<K>,
+    K: Clone,
+    <I::Item as Mul<K>>::Output: Sum,
+{
+    type Item = <I::Item as Mul<K>>::Output;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.iter.next()?;
+        Some(
+            window
+                .into_iter()
+                .zip(self.kernel.iter().cloned())
+                .map(|(x, k)| x * k)
+                .sum(),
+        )
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, K, const N: usize> ExactSizeIterator for ArrayWindowsDot<I, K, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone + Mul<K>,
+    K: Clone,
+    <I::Item as Mul<K>>::Output: Sum,
+{
+}
+
+impl<I, K, const N: usize> FusedIterator for ArrayWindowsDot<I, K, N>
+where
+    I: FusedIterator + Clone,
+    I::Item: Clone + Mul<K>,
+    K: Clone,
+    <I::Item as Mul<K>>::Output: Sum,
+{
+}
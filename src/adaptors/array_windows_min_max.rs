@@ -0,0 +1,234 @@
+use core::iter::FusedIterator;
+
+use alloc::collections::VecDeque;
+
+/// An extension trait that provides the [`array_windows_min`] and
+/// [`array_windows_max`] methods for iterators.
+///
+/// [`array_windows_min`]: IterArrayWindowsMinMax::array_windows_min
+/// [`array_windows_max`]: IterArrayWindowsMinMax::array_windows_max
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows_min_max")))]
+pub trait IterArrayWindowsMinMax: Iterator {
+    /// Returns an iterator over the minimum element of all contiguous windows
+    /// of length `N`.
+    ///
+    /// This is equivalent to taking the minimum of each window returned by
+    /// [`array_windows`] but a monotonic queue of candidate elements is kept
+    /// so that each window is computed in amortized constant time instead of
+    /// `O(N)`. If the iterator is shorter than `N`, the iterator returns no
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayWindowsMinMax;
+    ///
+    /// let mut iter = [4, 2, 12, 3, 8, 7].into_iter().array_windows_min::<3>();
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows`]: crate::IterArrayWindows::array_windows
+    #[inline]
+    fn array_windows_min<const N: usize>(self) -> ArrayWindowsMin<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        ArrayWindowsMin(Monotonic::new(self))
+    }
+
+    /// Returns an iterator over the maximum element of all contiguous windows
+    /// of length `N`.
+    ///
+    /// See [`array_windows_min`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterArrayWindowsMinMax;
+    ///
+    /// let mut iter = [4, 2, 12, 3, 8, 7].into_iter().array_windows_max::<3>();
+    /// assert_eq!(iter.next(), Some(12));
+    /// assert_eq!(iter.next(), Some(12));
+    /// assert_eq!(iter.next(), Some(12));
+    /// assert_eq!(iter.next(), Some(8));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows_min`]: IterArrayWindowsMinMax::array_windows_min
+    #[inline]
+    fn array_windows_max<const N: usize>(self) -> ArrayWindowsMax<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        ArrayWindowsMax(Monotonic::new(self))
+    }
+}
+
+impl<I: ?Sized> IterArrayWindowsMinMax for I where I: Iterator {}
+
+/// An iterator over the minimum element of all contiguous windows of length
+/// `N`.
+///
+/// This struct is created by the [`array_windows_min`] method on iterators.
+/// See its documentation for more.
+///
+/// [`array_windows_min`]: IterArrayWindowsMinMax::array_windows_min
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows_min_max")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindowsMin<I, const N: usize>(Monotonic<I, I::Item, N>)
+where
+    I: Iterator;
+
+/// An iterator over the maximum element of all contiguous windows of length
+/// `N`.
+///
+/// This struct is created by the [`array_windows_max`] method on iterators.
+/// See its documentation for more.
+///
+/// [`array_windows_max`]: IterArrayWindowsMinMax::array_windows_max
+#[cfg_attr(docsrs, doc(cfg(feature = "array_windows_min_max")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindowsMax<I, const N: usize>(Monotonic<I, I::Item, N>)
+where
+    I: Iterator;
+
+/// Logic that is common to both the min and max adaptors.
+#[derive(Debug, Clone)]
+struct Monotonic<I, T, const N: usize> {
+    iter: I,
+
+    /// The candidate elements for the current and following windows along
+    /// with their position in the iterator.
+    ///
+    /// The elements are kept in order such that the front is always the
+    /// extreme of the current window.
+    queue: VecDeque<(usize, T)>,
+
+    /// The number of elements consumed from the iterator.
+    index: usize,
+}
+
+impl<I, T, const N: usize> Monotonic<I, T, N>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    fn new(iter: I) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self {
+            iter,
+            queue: VecDeque::with_capacity(N),
+            index: 0,
+        }
+    }
+
+    /// Returns the extreme of the next window.
+    ///
+    /// The function `is_worse(a, b)` should return true if `a` can never be
+    /// the extreme of a window containing `b`.
+    fn next_by<F>(&mut self, mut is_worse: F) -> Option<T>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        loop {
+            let item = self.iter.next()?;
+            while matches!(self.queue.back(), Some((_, b)) if is_worse(b, &item)) {
+                self.queue.pop_back();
+            }
+            self.queue.push_back((self.index, item));
+            self.index += 1;
+            while matches!(self.queue.front(), Some(&(i, _)) if i + N < self.index) {
+                self.queue.pop_front();
+            }
+            if self.index >= N {
+                return self.queue.front().map(|(_, item)| item.clone());
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (N - 1).saturating_sub(self.index);
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_sub(n), upper.map(|u| u.saturating_sub(n)))
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayWindowsMin<I, N>
+where
+    I: Iterator,
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_by(|a, b| a > b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayWindowsMax<I, N>
+where
+    I: Iterator,
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_by(|a, b| a < b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayWindowsMin<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Ord + Clone,
+{
+}
+
+impl<I, const N: usize> ExactSizeIterator for ArrayWindowsMax<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Ord + Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for ArrayWindowsMin<I, N>
+where
+    I: FusedIterator,
+    I::Item: Ord + Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for ArrayWindowsMax<I, N>
+where
+    I: FusedIterator,
+    I::Item: Ord + Clone,
+{
+}
@@ -0,0 +1,147 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+use core::ops::Add;
+
+/// An extension trait that provides the [`chunk_by_weight`] method for
+/// iterators.
+///
+/// [`chunk_by_weight`]: IterChunkByWeight::chunk_by_weight
+#[cfg_attr(docsrs, doc(cfg(feature = "chunk_by_weight")))]
+pub trait IterChunkByWeight: Iterator {
+    /// Returns an iterator over chunks of the iterator whose total weight does
+    /// not exceed `max`.
+    ///
+    /// Elements are added to the current chunk until adding the next element
+    /// would make the total weight of the chunk exceed `max`, then the chunk
+    /// is yielded and the next element starts a new chunk. An element whose
+    /// weight exceeds `max` by itself is yielded in a chunk on its own.
+    ///
+    /// The weight function is called exactly once for each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterChunkByWeight;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().chunk_by_weight(5, |x| *x);
+    /// assert_eq!(iter.next(), Some(vec![1, 2]));
+    /// assert_eq!(iter.next(), Some(vec![3]));
+    /// assert_eq!(iter.next(), Some(vec![4]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn chunk_by_weight<W, F>(self, max: W, weight: F) -> ChunkByWeight<Self, W, F>
+    where
+        Self: Sized,
+        W: Copy + PartialOrd + Add<Output = W>,
+        F: FnMut(&Self::Item) -> W,
+    {
+        ChunkByWeight::new(self, max, weight)
+    }
+}
+
+impl<I: ?Sized> IterChunkByWeight for I where I: Iterator {}
+
+/// An iterator over chunks of an iterator whose total weight does not exceed
+/// a maximum.
+///
+/// This struct is created by the [`chunk_by_weight`] method on iterators. See
+/// its documentation for more.
+///
+/// [`chunk_by_weight`]: IterChunkByWeight::chunk_by_weight
+#[cfg_attr(docsrs, doc(cfg(feature = "chunk_by_weight")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkByWeight<I, W, F>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The first element of the next chunk and its weight.
+    next: Option<(I::Item, W)>,
+
+    max: W,
+    weight: F,
+}
+
+impl<I, W, F> ChunkByWeight<I, W, F>
+where
+    I: Iterator,
+{
+    fn new(iter: I, max: W, weight: F) -> Self {
+        Self {
+            iter,
+            next: None,
+            max,
+            weight,
+        }
+    }
+}
+
+impl<I, W, F> Debug for ChunkByWeight<I, W, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+    W: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkByWeight")
+            .field("iter", &self.iter)
+            .field("next", &self.next)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<I, W, F> Iterator for ChunkByWeight<I, W, F>
+where
+    I: Iterator,
+    W: Copy + PartialOrd + Add<Output = W>,
+    F: FnMut(&I::Item) -> W,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, mut total) = match self.next.take() {
+            Some(next) => next,
+            None => {
+                let item = self.iter.next()?;
+                let w = (self.weight)(&item);
+                (item, w)
+            }
+        };
+        let mut chunk = vec![first];
+        for item in self.iter.by_ref() {
+            let w = (self.weight)(&item);
+            if total + w > self.max {
+                self.next = Some((item, w));
+                break;
+            }
+            total = total + w;
+            chunk.push(item);
+        }
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.next.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        let lower = match lower.saturating_add(extra) {
+            0 => 0,
+            _ => 1,
+        };
+        (lower, upper.and_then(|upper| upper.checked_add(extra)))
+    }
+}
+
+impl<I, W, F> FusedIterator for ChunkByWeight<I, W, F>
+where
+    I: FusedIterator,
+    W: Copy + PartialOrd + Add<Output = W>,
+    F: FnMut(&I::Item) -> W,
+{
+}
@@ -0,0 +1,180 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+use core::ops::Add;
+
+/// An extension trait that provides the [`chunk_sums`] method for iterators.
+///
+/// [`chunk_sums`]: IterChunkSums::chunk_sums
+#[cfg_attr(docsrs, doc(cfg(feature = "chunk_sums")))]
+pub trait IterChunkSums: Iterator {
+    /// Returns an iterator over the sum of every `N` elements of the iterator.
+    ///
+    /// The chunks do not overlap. If `N` does not divide the length of the
+    /// iterator, then the last up to `N-1` elements will be omitted but their
+    /// sum can be retrieved using [`into_remainder`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterChunkSums;
+    ///
+    /// let mut iter = [1, 2, 3, 4, 5].into_iter().chunk_sums::<2>();
+    /// assert_eq!(iter.next(), Some(3));
+    /// assert_eq!(iter.next(), Some(7));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder(), Some(5));
+    /// ```
+    ///
+    /// [`into_remainder`]: ChunkSums::into_remainder
+    #[inline]
+    fn chunk_sums<const N: usize>(self) -> ChunkSums<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Add<Output = Self::Item>,
+    {
+        ChunkSums::new(self)
+    }
+}
+
+impl<I: ?Sized> IterChunkSums for I where I: Iterator {}
+
+/// An iterator over the sum of every `N` elements of the iterator.
+///
+/// This struct is created by the [`chunk_sums`] method on iterators. See its
+/// documentation for more.
+///
+/// [`chunk_sums`]: IterChunkSums::chunk_sums
+#[cfg_attr(docsrs, doc(cfg(feature = "chunk_sums")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkSums<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    remainder: Option<I::Item>,
+    done: bool,
+}
+
+impl<I, const N: usize> ChunkSums<I, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            remainder: None,
+            done: false,
+        }
+    }
+
+    /// Returns the sum of the remaining elements of the original iterator
+    /// that did not fill a chunk. There are at most `N-1` of these elements.
+    /// Returns `None` if there are no remaining elements or if the remainder
+    /// is not yet known.
+    #[inline]
+    pub fn into_remainder(self) -> Option<I::Item> {
+        self.remainder
+    }
+}
+
+impl<I, const N: usize> Debug for ChunkSums<I, N>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkSums")
+            .field("iter", &self.iter)
+            .field("remainder", &self.remainder)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, const N: usize> Clone for ChunkSums<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            remainder: self.remainder.clone(),
+            done: self.done,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ChunkSums<I, N>
+where
+    I: Iterator,
+    I::Item: Add<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut chunk = self.iter.by_ref().take(N);
+        let first = match chunk.next() {
+            Some(first) => first,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let mut count = 1;
+        let sum = chunk.fold(first, |acc, x| {
+            count += 1;
+            acc + x
+        });
+        if count < N {
+            self.done = true;
+            self.remainder = Some(sum);
+            return None;
+        }
+        Some(sum)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        if self.done {
+            return 0;
+        }
+        self.iter.count() / N
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ChunkSums<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Add<Output = I::Item>,
+{
+}
+
+impl<I, const N: usize> FusedIterator for ChunkSums<I, N>
+where
+    I: Iterator,
+    I::Item: Add<Output = I::Item>,
+{
+}
@@ -0,0 +1,130 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`chunks`] method for iterators.
+///
+/// [`chunks`]: IterChunks::chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "chunks")))]
+pub trait IterChunks: Iterator {
+    /// Returns an iterator over `n` elements of the iterator at a time.
+    ///
+    /// This is the same as [`array_chunks`] except that the chunk size is
+    /// given at runtime and each chunk is collected into a [`Vec`].
+    ///
+    /// The chunks do not overlap. If `n` does not divide the length of the
+    /// iterator, then the last up to `n-1` elements will be omitted but can be
+    /// retrieved using [`into_remainder`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `n = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterChunks;
+    ///
+    /// let mut iter = "lorem".chars().chunks(2);
+    /// assert_eq!(iter.next(), Some(vec!['l', 'o']));
+    /// assert_eq!(iter.next(), Some(vec!['r', 'e']));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder(), Some(vec!['m']));
+    /// ```
+    ///
+    /// [`array_chunks`]: crate::IterArrayChunks::array_chunks
+    /// [`into_remainder`]: Chunks::into_remainder
+    #[inline]
+    fn chunks(self, n: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterChunks for I where I: Iterator {}
+
+/// An iterator over `n` elements of the iterator at a time.
+///
+/// This struct is created by the [`chunks`] method on iterators. See its
+/// documentation for more.
+///
+/// [`chunks`]: IterChunks::chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "chunks")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    n: usize,
+    remainder: Option<Vec<I::Item>>,
+}
+
+impl<I> Chunks<I>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            n,
+            remainder: None,
+        }
+    }
+
+    /// Returns the remaining elements of the original iterator that are not
+    /// going to be yielded. The returned vector will contain at most `n-1`
+    /// elements. Returns `None` if the remainder is not yet known.
+    #[inline]
+    pub fn into_remainder(self) -> Option<Vec<I::Item>> {
+        self.remainder
+    }
+}
+
+impl<I> Iterator for Chunks<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (lower, _) = self.iter.size_hint();
+        let mut chunk = Vec::with_capacity(self.n.min(lower));
+        chunk.extend(self.iter.by_ref().take(self.n));
+        if chunk.len() == self.n {
+            Some(chunk)
+        } else {
+            self.remainder.get_or_insert(chunk);
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / self.n, upper.map(|n| n / self.n))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count() / self.n
+    }
+}
+
+impl<I> ExactSizeIterator for Chunks<I>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len() / self.n
+    }
+}
+
+impl<I> FusedIterator for Chunks<I> where I: FusedIterator {}
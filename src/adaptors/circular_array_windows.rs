@@ -50,6 +50,34 @@ pub trait IterCircularArrayWindows: Iterator {
     {
         CircularArrayWindows::new(self)
     }
+
+    /// Returns an iterator over all adjacent pairs of elements including the
+    /// pair of the last and the first element.
+    ///
+    /// This is the same as [`circular_array_windows::<2>`] except that the
+    /// pairs are yielded as tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCircularArrayWindows;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().circular_pairs();
+    /// assert_eq!(iter.next(), Some((1, 2)));
+    /// assert_eq!(iter.next(), Some((2, 3)));
+    /// assert_eq!(iter.next(), Some((3, 1)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`circular_array_windows::<2>`]: IterCircularArrayWindows::circular_array_windows
+    #[inline]
+    fn circular_pairs(self) -> CircularPairs<Self>
+    where
+        Self: Sized + Clone + ExactSizeIterator,
+        Self::Item: Clone,
+    {
+        CircularPairs(CircularArrayWindows::new(self))
+    }
 }
 
 impl<I: ?Sized> IterCircularArrayWindows for I where I: Iterator {}
@@ -151,3 +179,174 @@ where
     I::Item: Clone,
 {
 }
+
+/// An iterator over all adjacent pairs of elements including the pair of the
+/// last and the first element.
+///
+/// This struct is created by the [`circular_pairs`] method on iterators. See
+/// its documentation for more.
+///
+/// [`circular_pairs`]: IterCircularArrayWindows::circular_pairs
+#[cfg_attr(docsrs, doc(cfg(feature = "circular_array_windows")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CircularPairs<I>(CircularArrayWindows<I, 2>)
+where
+    I: Iterator + Clone;
+
+impl<I> Debug for CircularPairs<I>
+where
+    I: Iterator + Clone + Debug,
+    I::Item: Clone + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CircularPairs").field(&self.0).finish()
+    }
+}
+
+impl<I> Clone for CircularPairs<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I> Iterator for CircularPairs<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|[a, b]| (a, b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.0.count()
+    }
+}
+
+impl<I> ExactSizeIterator for CircularPairs<I>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for CircularPairs<I>
+where
+    I: ExactSizeIterator + Clone,
+    I::Item: Clone,
+{
+}
+
+/// Returns an iterator over all contiguous windows of length `N` of a slice,
+/// starting at the element at `start` and wrapping back to the first elements
+/// when the window would otherwise exceed the length of the slice.
+///
+/// One window is yielded for every element in the slice. Unlike
+/// [`circular_array_windows`] this does not require a `Clone` and
+/// [`ExactSizeIterator`] iterator, since the elements are already collected.
+/// If `start` is past the end of the slice it wraps around as well.
+///
+/// # Panics
+///
+/// If called with `N = 0`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use itermore::slice_circular_array_windows;
+///
+/// let mut iter = slice_circular_array_windows(&[1, 2, 3, 4], 2);
+/// assert_eq!(iter.next(), Some([3, 4]));
+/// assert_eq!(iter.next(), Some([4, 1]));
+/// assert_eq!(iter.next(), Some([1, 2]));
+/// assert_eq!(iter.next(), Some([2, 3]));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// [`circular_array_windows`]: IterCircularArrayWindows::circular_array_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "circular_array_windows")))]
+#[track_caller]
+pub fn slice_circular_array_windows<T, const N: usize>(
+    slice: &[T],
+    start: usize,
+) -> RingWindows<'_, T, N>
+where
+    T: Clone,
+{
+    RingWindows::new(slice, start)
+}
+
+/// An iterator over all contiguous windows of length `N` of a slice, wrapping
+/// back to the first elements when the window would otherwise exceed the
+/// length of the slice.
+///
+/// This struct is created by the [`slice_circular_array_windows`] function.
+/// See its documentation for more.
+#[cfg_attr(docsrs, doc(cfg(feature = "circular_array_windows")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RingWindows<'a, T, const N: usize> {
+    slice: &'a [T],
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, T, const N: usize> RingWindows<'a, T, N> {
+    #[track_caller]
+    fn new(slice: &'a [T], start: usize) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        let len = slice.len();
+        let pos = if len == 0 { 0 } else { start % len };
+        Self { slice, pos, len }
+    }
+}
+
+impl<T, const N: usize> Iterator for RingWindows<'_, T, N>
+where
+    T: Clone,
+{
+    type Item = [T; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let n = self.slice.len();
+        let pos = self.pos;
+        self.pos = (pos + 1) % n;
+        let window = (0..N).map(|i| self.slice[(pos + i) % n].clone());
+        // SAFETY: The range 0..N yields exactly N elements.
+        Some(unsafe { arrays::from_iter_unchecked(window) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for RingWindows<'_, T, N> where T: Clone {}
+
+impl<T, const N: usize> FusedIterator for RingWindows<'_, T, N> where T: Clone {}
@@ -2,7 +2,9 @@ use core::fmt;
 use core::fmt::Debug;
 use core::iter::FusedIterator;
 
-use crate::adaptors::generic_combinations::GenericCombinations;
+use crate::adaptors::generic_combinations::{
+    CombinationsState, Dropped, GenericCombinations, GenericCombinationsRef,
+};
 
 /// An extension trait that provides the [`combinations`] method for iterators.
 ///
@@ -41,6 +43,81 @@ pub trait IterCombinations: Iterator {
     {
         Combinations::new(self, k)
     }
+
+    /// Returns an iterator adaptor that iterates over the positions of the
+    /// elements in `k` length combinations of the underlying iterator.
+    ///
+    /// The positions are yielded in the same order as [`combinations`] but
+    /// the elements themselves are dropped instead of being buffered, so they
+    /// do not need to implement [`Clone`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `k = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abcd".chars().index_combinations(3);
+    /// assert_eq!(iter.next(), Some(vec![0, 1, 2]));
+    /// assert_eq!(iter.next(), Some(vec![0, 1, 3]));
+    /// assert_eq!(iter.next(), Some(vec![0, 2, 3]));
+    /// assert_eq!(iter.next(), Some(vec![1, 2, 3]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`combinations`]: IterCombinations::combinations
+    #[inline]
+    fn index_combinations(self, k: usize) -> IndexCombinations<Self>
+    where
+        Self: Sized,
+    {
+        IndexCombinations::new(self, k)
+    }
+
+    /// Returns an iterator over `k` length combinations of the positions of
+    /// the elements in the underlying iterator, represented as bitmasks.
+    ///
+    /// Each combination is a [`u64`] where bit `i` is set if the element at
+    /// position `i` is selected, so exactly `k` bits are set. The masks are
+    /// yielded in increasing numeric order, which is a valid order for the
+    /// combinations although not the same as [`index_combinations`].
+    ///
+    /// The underlying iterator is consumed immediately to count the elements,
+    /// the elements themselves are dropped. At most 65 elements are consumed.
+    ///
+    /// # Panics
+    ///
+    /// If called with `k = 0` or if the iterator has more than 64 elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abcd".chars().combinations_mask(2);
+    /// assert_eq!(iter.next(), Some(0b0011));
+    /// assert_eq!(iter.next(), Some(0b0101));
+    /// assert_eq!(iter.next(), Some(0b0110));
+    /// assert_eq!(iter.next(), Some(0b1001));
+    /// assert_eq!(iter.next(), Some(0b1010));
+    /// assert_eq!(iter.next(), Some(0b1100));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`index_combinations`]: IterCombinations::index_combinations
+    #[inline]
+    #[track_caller]
+    fn combinations_mask(self, k: usize) -> CombinationsMask
+    where
+        Self: Sized,
+    {
+        // No more than 65 elements are counted so that an infinite iterator
+        // still panics.
+        CombinationsMask::new(self.take(65).count(), k)
+    }
 }
 
 impl<I: ?Sized> IterCombinations for I where I: Iterator {}
@@ -68,6 +145,173 @@ where
         assert!(k != 0, "combination size must be non-zero");
         Self(GenericCombinations::new(iter, Vec::from_iter(0..k)))
     }
+
+    /// Creates an iterator that continues from a saved [`state`] over a fresh
+    /// instance of the original iterator.
+    ///
+    /// The elements that the original iterator had buffered are consumed from
+    /// the iterator immediately, the iterator must yield the same elements as
+    /// the original one for the combinations to be correct.
+    ///
+    /// # Panics
+    ///
+    /// If the state is not valid or if the iterator is shorter than the saved
+    /// combination requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::{Combinations, IterCombinations};
+    ///
+    /// let mut iter = "abcd".chars().combinations(2);
+    /// assert_eq!(iter.next(), Some(vec!['a', 'b']));
+    /// assert_eq!(iter.next(), Some(vec!['a', 'c']));
+    /// let state = iter.state();
+    ///
+    /// let mut iter = Combinations::resume_from("abcd".chars(), state);
+    /// assert_eq!(iter.next(), Some(vec!['a', 'd']));
+    /// assert_eq!(iter.next(), Some(vec!['b', 'c']));
+    /// ```
+    ///
+    /// [`state`]: Combinations::state
+    #[track_caller]
+    pub fn resume_from(iter: I, state: CombinationsState) -> Self {
+        let (comb, started) = state.into_parts();
+        assert!(!comb.is_empty(), "combination size must be non-zero");
+        Self(GenericCombinations::resume(iter, comb, started))
+    }
+}
+
+impl<I> Combinations<I>
+where
+    I: Iterator,
+{
+    /// Returns an iterator over the remaining combinations that yields
+    /// references to the elements instead of clones.
+    ///
+    /// This consumes the rest of the underlying iterator immediately so that
+    /// all the elements are buffered. Advancing the returned iterator also
+    /// advances this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abcd".chars().combinations(3);
+    /// assert_eq!(iter.next(), Some(vec!['a', 'b', 'c']));
+    ///
+    /// let mut refs = iter.combinations_ref();
+    /// assert_eq!(refs.next(), Some(vec![&'a', &'b', &'d']));
+    /// assert_eq!(refs.next(), Some(vec![&'a', &'c', &'d']));
+    ///
+    /// assert_eq!(iter.next(), Some(vec!['b', 'c', 'd']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn combinations_ref(&mut self) -> CombinationsRef<'_, I::Item> {
+        CombinationsRef(self.0.fill_buf())
+    }
+
+    /// Returns the current position of the iterator, which can be used to
+    /// resume iteration later using [`resume_from`][Combinations::resume_from].
+    #[inline]
+    pub fn state(&self) -> CombinationsState {
+        self.0.state()
+    }
+
+    /// Returns the total number of combinations, including those that have
+    /// already been yielded.
+    ///
+    /// This consumes the rest of the underlying iterator into the buffer since
+    /// the total depends on its length, so it should not be called on an
+    /// infinite iterator.
+    ///
+    /// Returns `None` if the total overflows a [`u128`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abcd".chars().combinations(2);
+    /// assert_eq!(iter.next(), Some(vec!['a', 'b']));
+    /// assert_eq!(iter.total(), Some(6));
+    /// assert_eq!(iter.count(), 5);
+    /// ```
+    #[inline]
+    pub fn total(&mut self) -> Option<u128> {
+        self.0.total()
+    }
+
+    /// Returns the number of combinations that have been yielded so far.
+    ///
+    /// This is calculated from the current combination without consuming any
+    /// more of the underlying iterator and together with
+    /// [`total`][Combinations::total] can be used to report progress. Saturates at
+    /// [`u128::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abcd".chars().combinations(2);
+    /// assert_eq!(iter.produced(), 0);
+    /// iter.next();
+    /// iter.next();
+    /// assert_eq!(iter.produced(), 2);
+    /// assert_eq!(iter.total(), Some(6));
+    /// ```
+    #[inline]
+    pub fn produced(&self) -> u128 {
+        self.0.produced()
+    }
+}
+
+impl<I> Combinations<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Writes the next combination into the given slice instead of allocating
+    /// a new [`Vec`]. Returns `false` if there are no more combinations.
+    ///
+    /// The elements are cloned into the existing values using
+    /// [`Clone::clone_from`], so allocations in the elements themselves can
+    /// also be reused.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the slice is not equal to `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinations;
+    ///
+    /// let mut iter = "abc".chars().combinations(2);
+    /// let mut buf = [' '; 2];
+    /// while iter.fill_next_into(&mut buf) {
+    ///     println!("{:?}", buf);
+    /// }
+    /// // Outputs
+    /// //    ['a', 'b']
+    /// //    ['a', 'c']
+    /// //    ['b', 'c']
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn fill_next_into(&mut self, out: &mut [I::Item]) -> bool {
+        let k = self.0.k();
+        assert!(
+            out.len() == k,
+            "expected slice of length {}, but got {}",
+            k,
+            out.len()
+        );
+        self.0.fill_next_into(out)
+    }
 }
 
 impl<I> Clone for Combinations<I>
@@ -100,6 +344,22 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.0.fill_next().map(Vec::from_iter)
     }
+
+    /// Returns the `n`th combination from the current position.
+    ///
+    /// If the length of the underlying iterator can be determined from its
+    /// size hint then the rest of it is buffered and the combination is
+    /// computed directly without enumerating the combinations in between.
+    /// Otherwise this advances one combination at a time.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if let Some(comb) = self.0.fill_nth(n) {
+            return comb.map(Vec::from_iter);
+        }
+        for _ in 0..n {
+            self.0.advance()?;
+        }
+        self.next()
+    }
 }
 
 impl<I> FusedIterator for Combinations<I>
@@ -108,3 +368,133 @@ where
     I::Item: Clone,
 {
 }
+
+/// An iterator that iterates over `k` length combinations of the buffered
+/// elements of a [`Combinations`] by reference.
+///
+/// This struct is created by the [`combinations_ref`] method on
+/// [`Combinations`]. See its documentation for more.
+///
+/// [`combinations_ref`]: Combinations::combinations_ref
+#[cfg_attr(docsrs, doc(cfg(feature = "combinations")))]
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CombinationsRef<'a, T>(GenericCombinationsRef<'a, T, Vec<usize>>);
+
+impl<'a, T> Iterator for CombinationsRef<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.fill_next().map(Vec::from_iter)
+    }
+}
+
+impl<T> FusedIterator for CombinationsRef<'_, T> {}
+
+/// An iterator that iterates over the positions of the elements in `k` length
+/// combinations of the underlying iterator.
+///
+/// This struct is created by the [`index_combinations`] method on iterators.
+/// See its documentation for more.
+///
+/// [`index_combinations`]: IterCombinations::index_combinations
+#[cfg_attr(docsrs, doc(cfg(feature = "combinations")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexCombinations<I>(GenericCombinations<Dropped<I>, Vec<usize>>)
+where
+    I: Iterator;
+
+impl<I> IndexCombinations<I>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, k: usize) -> Self {
+        assert!(k != 0, "combination size must be non-zero");
+        let iter = iter.map(drop as fn(I::Item));
+        Self(GenericCombinations::new(iter, Vec::from_iter(0..k)))
+    }
+}
+
+impl<I> Clone for IndexCombinations<I>
+where
+    I: Iterator + Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<I> Debug for IndexCombinations<I>
+where
+    I: Iterator + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_with(f, "IndexCombinations")
+    }
+}
+
+impl<I> Iterator for IndexCombinations<I>
+where
+    I: Iterator,
+{
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance()?;
+        Some(self.0.indices().clone())
+    }
+}
+
+impl<I> FusedIterator for IndexCombinations<I> where I: Iterator {}
+
+/// An iterator over `k` length combinations of the positions of the elements
+/// in an iterator, represented as bitmasks.
+///
+/// This struct is created by the [`combinations_mask`] method on iterators.
+/// See its documentation for more.
+///
+/// [`combinations_mask`]: IterCombinations::combinations_mask
+#[cfg_attr(docsrs, doc(cfg(feature = "combinations")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CombinationsMask {
+    next: Option<u64>,
+    n: usize,
+}
+
+impl CombinationsMask {
+    #[track_caller]
+    fn new(n: usize, k: usize) -> Self {
+        assert!(k != 0, "combination size must be non-zero");
+        assert!(n <= 64, "iterator must have at most 64 elements");
+        let next = match k {
+            k if k > n => None,
+            64 => Some(u64::MAX),
+            k => Some((1 << k) - 1),
+        };
+        Self { next, n }
+    }
+}
+
+impl Iterator for CombinationsMask {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = self.next?;
+        // Find the next larger number with the same number of bits set, this
+        // is known as Gosper's hack. The lowest run of set bits is moved up by
+        // one and the rest of the run is moved to the bottom.
+        let low = mask & mask.wrapping_neg();
+        self.next = mask.checked_add(low).and_then(|ripple| {
+            let next = (((ripple ^ mask) >> 2) / low) | ripple;
+            match self.n {
+                64 => Some(next),
+                n => (next >> n == 0).then(|| next),
+            }
+        });
+        Some(mask)
+    }
+}
+
+impl FusedIterator for CombinationsMask {}
@@ -0,0 +1,222 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::{Fuse, FusedIterator};
+
+/// An extension trait that provides the [`combinations_gray`] method for
+/// iterators.
+///
+/// [`combinations_gray`]: IterCombinationsGray::combinations_gray
+#[cfg_attr(docsrs, doc(cfg(feature = "combinations_gray")))]
+pub trait IterCombinationsGray: Iterator {
+    /// Returns an iterator adaptor that iterates over `k` length combinations
+    /// of all the elements in the underlying iterator in revolving door order.
+    ///
+    /// Unlike [`combinations`] which yields the combinations in lexicographic
+    /// order, each combination differs from the previous one by exactly one
+    /// element, that is one element is removed and another is added. This is
+    /// a Gray code for combinations and is generated using Knuth's
+    /// Algorithm R.
+    ///
+    /// The elements in each combination are in the same order as in the
+    /// underlying iterator. Since the order depends on the total number of
+    /// elements, the entire underlying iterator is consumed in the first
+    /// iteration.
+    ///
+    /// # Panics
+    ///
+    /// If called with `k = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinationsGray;
+    ///
+    /// let mut iter = "abcd".chars().combinations_gray(2);
+    /// assert_eq!(iter.next(), Some(vec!['a', 'b']));
+    /// assert_eq!(iter.next(), Some(vec!['b', 'c']));
+    /// assert_eq!(iter.next(), Some(vec!['a', 'c']));
+    /// assert_eq!(iter.next(), Some(vec!['c', 'd']));
+    /// assert_eq!(iter.next(), Some(vec!['b', 'd']));
+    /// assert_eq!(iter.next(), Some(vec!['a', 'd']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`combinations`]: crate::IterCombinations::combinations
+    #[inline]
+    fn combinations_gray(self, k: usize) -> CombinationsGray<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        CombinationsGray::new(self, k)
+    }
+}
+
+impl<I: ?Sized> IterCombinationsGray for I where I: Iterator {}
+
+/// An iterator that iterates over `k` length combinations of all the elements
+/// in the underlying iterator in revolving door order.
+///
+/// This struct is created by the [`combinations_gray`] method on iterators.
+/// See its documentation for more.
+///
+/// [`combinations_gray`]: IterCombinationsGray::combinations_gray
+#[cfg_attr(docsrs, doc(cfg(feature = "combinations_gray")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CombinationsGray<I>
+where
+    I: Iterator,
+{
+    /// The underlying iterator.
+    iter: Fuse<I>,
+
+    /// All the elements of the underlying iterator.
+    buf: Vec<I::Item>,
+
+    /// The positions of the elements in the current combination in increasing
+    /// order. Once started this contains an extra trailing sentinel equal to
+    /// the number of elements.
+    comb: Vec<usize>,
+
+    /// The state of the iterator.
+    state: State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    First,
+    Normal,
+    Done,
+}
+
+impl<I> CombinationsGray<I>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, k: usize) -> Self {
+        assert!(k != 0, "combination size must be non-zero");
+        Self {
+            iter: iter.fuse(),
+            buf: Vec::new(),
+            comb: Vec::from_iter(0..k),
+            state: State::First,
+        }
+    }
+}
+
+impl<I> Clone for CombinationsGray<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            buf: self.buf.clone(),
+            comb: self.comb.clone(),
+            state: self.state,
+        }
+    }
+}
+
+impl<I> Debug for CombinationsGray<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombinationsGray")
+            .field("iter", &self.iter)
+            .field("buf", &self.buf)
+            .field("comb", &self.comb)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<I> Iterator for CombinationsGray<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.state {
+            State::First => {
+                self.buf.extend(self.iter.by_ref());
+                let n = self.buf.len();
+                if self.comb.len() > n {
+                    self.state = State::Done;
+                    return None;
+                }
+                self.comb.push(n);
+                self.state = State::Normal;
+            }
+            State::Normal => {
+                if revolve(&mut self.comb).is_none() {
+                    self.state = State::Done;
+                    self.buf = Vec::new();
+                    return None;
+                }
+            }
+            State::Done => return None,
+        }
+        let k = self.comb.len() - 1;
+        Some(Vec::from_iter(
+            self.comb[..k].iter().map(|&d| self.buf[d].clone()),
+        ))
+    }
+}
+
+impl<I> FusedIterator for CombinationsGray<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// Moves the combination to the next one in revolving door order, returns
+/// `None` if there are no more combinations.
+///
+/// The combination must be in increasing order and followed by a sentinel
+/// equal to the number of elements. This is Algorithm R from The Art of
+/// Computer Programming, Volume 4A, Section 7.2.1.3 with zero-based indices.
+fn revolve(comb: &mut [usize]) -> Option<()> {
+    let k = comb.len() - 1;
+
+    // The easy case where only the smallest position changes.
+    let mut increase = if k % 2 == 1 {
+        if comb[0] + 1 < comb[1] {
+            comb[0] += 1;
+            return Some(());
+        }
+        false
+    } else {
+        if comb[0] > 0 {
+            comb[0] -= 1;
+            return Some(());
+        }
+        true
+    };
+
+    // Otherwise alternate between trying to decrease and increase the next
+    // position, moving the previous one to make room.
+    for j in 1..k {
+        if increase {
+            if comb[j] + 1 < comb[j + 1] {
+                comb[j - 1] = comb[j];
+                comb[j] += 1;
+                return Some(());
+            }
+        } else if comb[j] > j {
+            comb[j] = comb[j - 1];
+            comb[j - 1] = j - 1;
+            return Some(());
+        }
+        increase = !increase;
+    }
+
+    None
+}
@@ -69,6 +69,30 @@ where
         assert!(k != 0, "combination size must be non-zero");
         Self(GenericCombinations::new(iter, vec![0; k]))
     }
+
+    /// Returns the total number of combinations, including those that have
+    /// already been yielded.
+    ///
+    /// This consumes the rest of the underlying iterator into the buffer since
+    /// the total depends on its length, so it should not be called on an
+    /// infinite iterator.
+    ///
+    /// Returns `None` if the total overflows a [`u128`] or if the iterator has
+    /// already been exhausted, since the buffered elements are freed at that
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCombinationsWithReps;
+    ///
+    /// let mut iter = "abcd".chars().combinations_with_reps(2);
+    /// assert_eq!(iter.total(), Some(16));
+    /// ```
+    #[inline]
+    pub fn total(&mut self) -> Option<u128> {
+        self.0.total_with_reps()
+    }
 }
 
 impl<I> Debug for CombinationsWithReps<I>
@@ -101,6 +125,24 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.0.fill_next_with_reps().map(Vec::from_iter)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint_with_reps()
+    }
+}
+
+impl<I> DoubleEndedIterator for CombinationsWithReps<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    /// Returns the previous combination from the back.
+    ///
+    /// This consumes the entire underlying iterator into the buffer on the
+    /// first call since the last combination depends on its length.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.fill_next_back_with_reps().map(Vec::from_iter)
+    }
 }
 
 impl<I> FusedIterator for CombinationsWithReps<I>
@@ -0,0 +1,112 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`cycle_n`] method for iterators.
+///
+/// [`cycle_n`]: IterCycleN::cycle_n
+#[cfg_attr(docsrs, doc(cfg(feature = "cycle_n")))]
+pub trait IterCycleN: Iterator {
+    /// Returns an iterator that repeats the entire iterator `n` times.
+    ///
+    /// This is like [`cycle`] except that it stops after `n` passes. The
+    /// iterator is cloned at the start of every pass so it is most suited to
+    /// iterators that are cheap to clone like ranges and slice iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterCycleN;
+    ///
+    /// let v: Vec<_> = (1..4).cycle_n(2).collect();
+    /// assert_eq!(v, [1, 2, 3, 1, 2, 3]);
+    /// ```
+    ///
+    /// [`cycle`]: Iterator::cycle
+    #[inline]
+    fn cycle_n(self, n: usize) -> CycleN<Self>
+    where
+        Self: Sized + Clone,
+    {
+        CycleN::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterCycleN for I where I: Iterator {}
+
+/// An iterator that repeats an iterator a number of times.
+///
+/// This struct is created by the [`cycle_n`] method on iterators. See its
+/// documentation for more.
+///
+/// [`cycle_n`]: IterCycleN::cycle_n
+#[cfg_attr(docsrs, doc(cfg(feature = "cycle_n")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CycleN<I> {
+    orig: I,
+    iter: I,
+
+    /// The number of passes left including the current one.
+    n: usize,
+}
+
+impl<I> CycleN<I>
+where
+    I: Clone,
+{
+    fn new(iter: I, n: usize) -> Self {
+        Self {
+            orig: iter.clone(),
+            iter,
+            n,
+        }
+    }
+}
+
+impl<I> Iterator for CycleN<I>
+where
+    I: Iterator + Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+        if let Some(item) = self.iter.next() {
+            return Some(item);
+        }
+        self.n -= 1;
+        if self.n == 0 {
+            return None;
+        }
+        self.iter = self.orig.clone();
+        let item = self.iter.next();
+        if item.is_none() {
+            // The iterator is empty so there is no point in doing the
+            // remaining passes.
+            self.n = 0;
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let passes = self.n - 1;
+        let (lower, upper) = self.iter.size_hint();
+        let (orig_lower, orig_upper) = self.orig.size_hint();
+        let lower = orig_lower.saturating_mul(passes).saturating_add(lower);
+        let upper = match (upper, orig_upper) {
+            (Some(upper), Some(orig_upper)) => orig_upper
+                .checked_mul(passes)
+                .and_then(|n| n.checked_add(upper)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<I> FusedIterator for CycleN<I> where I: Iterator + Clone {}
@@ -0,0 +1,199 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`dedup_with_count`] and
+/// [`run_length_decode`] methods for iterators.
+///
+/// [`dedup_with_count`]: IterDedup::dedup_with_count
+/// [`run_length_decode`]: IterDedup::run_length_decode
+#[cfg_attr(docsrs, doc(cfg(feature = "dedup")))]
+pub trait IterDedup: Iterator {
+    /// Returns an iterator that collapses runs of consecutive equal elements
+    /// into a single element along with the length of the run.
+    ///
+    /// This is a run-length encoding of the iterator, see
+    /// [`run_length_decode`] for the inverse. The first element of each run is
+    /// the one that is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterDedup;
+    ///
+    /// let mut iter = "aaba".chars().dedup_with_count();
+    /// assert_eq!(iter.next(), Some((2, 'a')));
+    /// assert_eq!(iter.next(), Some((1, 'b')));
+    /// assert_eq!(iter.next(), Some((1, 'a')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`run_length_decode`]: IterDedup::run_length_decode
+    #[inline]
+    fn dedup_with_count(self) -> DedupWithCount<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupWithCount::new(self)
+    }
+
+    /// Returns an iterator that expands each `(count, element)` pair into
+    /// `count` copies of the element.
+    ///
+    /// This is the inverse of [`dedup_with_count`]. Pairs with a count of zero
+    /// are skipped. The last copy of each element is moved out instead of
+    /// cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterDedup;
+    ///
+    /// let s: String = [(2, 'a'), (1, 'b'), (3, 'c')].into_iter().run_length_decode().collect();
+    /// assert_eq!(s, "aabccc");
+    /// ```
+    ///
+    /// [`dedup_with_count`]: IterDedup::dedup_with_count
+    #[inline]
+    fn run_length_decode<T>(self) -> RunLengthDecode<Self, T>
+    where
+        Self: Sized + Iterator<Item = (usize, T)>,
+        T: Clone,
+    {
+        RunLengthDecode::new(self)
+    }
+}
+
+impl<I: ?Sized> IterDedup for I where I: Iterator {}
+
+/// An iterator that collapses runs of consecutive equal elements into a single
+/// element along with the length of the run.
+///
+/// This struct is created by the [`dedup_with_count`] method on iterators. See
+/// its documentation for more.
+///
+/// [`dedup_with_count`]: IterDedup::dedup_with_count
+#[cfg_attr(docsrs, doc(cfg(feature = "dedup")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupWithCount<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The first element of the next run.
+    next: Option<I::Item>,
+}
+
+impl<I> DedupWithCount<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> Self {
+        Self { iter, next: None }
+    }
+}
+
+impl<I> Iterator for DedupWithCount<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.next.take() {
+            Some(first) => first,
+            None => self.iter.next()?,
+        };
+        let mut count = 1;
+        for item in self.iter.by_ref() {
+            if item != first {
+                self.next = Some(item);
+                break;
+            }
+            count += 1;
+        }
+        Some((count, first))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.next.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        let lower = match lower.saturating_add(extra) {
+            0 => 0,
+            _ => 1,
+        };
+        (lower, upper.and_then(|upper| upper.checked_add(extra)))
+    }
+}
+
+impl<I> FusedIterator for DedupWithCount<I>
+where
+    I: FusedIterator,
+    I::Item: PartialEq,
+{
+}
+
+/// An iterator that expands each `(count, element)` pair into `count` copies
+/// of the element.
+///
+/// This struct is created by the [`run_length_decode`] method on iterators.
+/// See its documentation for more.
+///
+/// [`run_length_decode`]: IterDedup::run_length_decode
+#[cfg_attr(docsrs, doc(cfg(feature = "dedup")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunLengthDecode<I, T> {
+    iter: I,
+
+    /// The element currently being repeated and the number of copies left.
+    curr: Option<(usize, T)>,
+}
+
+impl<I, T> RunLengthDecode<I, T> {
+    fn new(iter: I) -> Self {
+        Self { iter, curr: None }
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecode<I, T>
+where
+    I: Iterator<Item = (usize, T)>,
+    T: Clone,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match &mut self.curr {
+                Some((1, _)) => return self.curr.take().map(|(_, item)| item),
+                Some((n, item)) if *n > 1 => {
+                    *n -= 1;
+                    return Some(item.clone());
+                }
+                _ => self.curr = Some(self.iter.next()?),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.curr.as_ref().map_or(0, |(n, _)| *n);
+        match self.iter.size_hint() {
+            (_, Some(0)) => (n, Some(n)),
+            _ => (n, None),
+        }
+    }
+}
+
+impl<I, T> FusedIterator for RunLengthDecode<I, T>
+where
+    I: FusedIterator<Item = (usize, T)>,
+    T: Clone,
+{
+}
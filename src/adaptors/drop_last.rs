@@ -0,0 +1,104 @@
+use core::iter::FusedIterator;
+
+use alloc::collections::VecDeque;
+
+/// An extension trait that provides the [`drop_last`] method for iterators.
+///
+/// [`drop_last`]: IterDropLast::drop_last
+#[cfg_attr(docsrs, doc(cfg(feature = "drop_last")))]
+pub trait IterDropLast: Iterator {
+    /// Returns an iterator that yields all but the last `n` elements of the
+    /// iterator.
+    ///
+    /// This works by buffering `n` elements ahead of the one being yielded, so
+    /// it does not need to know the length of the iterator up front. If the
+    /// iterator has `n` elements or less then no elements are yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterDropLast;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().drop_last(2);
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn drop_last(self, n: usize) -> DropLast<Self>
+    where
+        Self: Sized,
+    {
+        DropLast::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterDropLast for I where I: Iterator {}
+
+/// An iterator that yields all but the last `n` elements of an iterator.
+///
+/// This struct is created by the [`drop_last`] method on iterators. See its
+/// documentation for more.
+///
+/// [`drop_last`]: IterDropLast::drop_last
+#[cfg_attr(docsrs, doc(cfg(feature = "drop_last")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DropLast<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The elements taken from the iterator but not yet yielded, oldest first.
+    buf: VecDeque<I::Item>,
+
+    /// The number of elements to drop.
+    n: usize,
+}
+
+impl<I> DropLast<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I, n: usize) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            n,
+        }
+    }
+}
+
+impl<I> Iterator for DropLast<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buf.len() < self.n {
+            self.buf.push_back(self.iter.next()?);
+        }
+        let item = self.iter.next()?;
+        self.buf.push_back(item);
+        self.buf.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.buf.len();
+        (
+            lower.saturating_add(buffered).saturating_sub(self.n),
+            upper
+                .and_then(|upper| upper.checked_add(buffered))
+                .map(|upper| upper.saturating_sub(self.n)),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for DropLast<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for DropLast<I> where I: FusedIterator {}
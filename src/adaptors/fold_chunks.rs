@@ -0,0 +1,168 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+use arrays::IntoIter;
+
+use crate::{ArrayChunks, IterArrayChunks};
+
+/// An extension trait that provides the [`fold_chunks`] method for iterators.
+///
+/// [`fold_chunks`]: IterFoldChunks::fold_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "fold_chunks")))]
+pub trait IterFoldChunks: Iterator {
+    /// Returns an iterator that folds every `N` elements of the iterator into
+    /// a single value.
+    ///
+    /// Each chunk is folded starting from the value returned by `init` using
+    /// `f`, just like [`Iterator::fold`]. The chunks do not overlap. If `N`
+    /// does not divide the length of the iterator, then the last up to `N-1`
+    /// elements will be omitted but can be retrieved using
+    /// [`into_remainder`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterFoldChunks;
+    ///
+    /// let mut iter = ["a", "b", "c", "d", "e"]
+    ///     .into_iter()
+    ///     .fold_chunks::<2, _, _, _>(String::new, |acc, s| acc + s);
+    /// assert_eq!(iter.next().as_deref(), Some("ab"));
+    /// assert_eq!(iter.next().as_deref(), Some("cd"));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.into_remainder().unwrap().as_slice(), ["e"]);
+    /// ```
+    ///
+    /// [`into_remainder`]: FoldChunks::into_remainder
+    #[inline]
+    fn fold_chunks<const N: usize, B, F, G>(self, init: F, f: G) -> FoldChunks<Self, F, G, N>
+    where
+        Self: Sized,
+        F: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        FoldChunks::new(self, init, f)
+    }
+}
+
+impl<I: ?Sized> IterFoldChunks for I where I: Iterator {}
+
+/// An iterator that folds every `N` elements of the iterator into a single
+/// value.
+///
+/// This struct is created by the [`fold_chunks`] method on iterators. See its
+/// documentation for more.
+///
+/// [`fold_chunks`]: IterFoldChunks::fold_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "fold_chunks")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FoldChunks<I, F, G, const N: usize>
+where
+    I: Iterator,
+{
+    iter: ArrayChunks<I, N>,
+    init: F,
+    f: G,
+}
+
+impl<I, F, G, const N: usize> FoldChunks<I, F, G, N>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, init: F, f: G) -> Self {
+        Self {
+            iter: iter.arrays(),
+            init,
+            f,
+        }
+    }
+
+    /// Returns an iterator over the remaining elements of the original iterator
+    /// that are not going to be folded. The returned iterator will yield at
+    /// most `N-1` elements. Returns `None` if the remainder is not yet known.
+    #[inline]
+    pub fn into_remainder(self) -> Option<IntoIter<I::Item, N>> {
+        self.iter.into_remainder()
+    }
+}
+
+impl<I, F, G, const N: usize> Debug for FoldChunks<I, F, G, N>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FoldChunks")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, G, const N: usize> Clone for FoldChunks<I, F, G, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+    G: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            init: self.init.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, F, G, B, const N: usize> Iterator for FoldChunks<I, F, G, N>
+where
+    I: Iterator,
+    F: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.next()?;
+        Some(chunk.into_iter().fold((self.init)(), &mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, F, G, B, const N: usize> ExactSizeIterator for FoldChunks<I, F, G, N>
+where
+    I: ExactSizeIterator,
+    F: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F, G, B, const N: usize> FusedIterator for FoldChunks<I, F, G, N>
+where
+    I: FusedIterator,
+    F: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
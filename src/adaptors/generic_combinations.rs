@@ -4,6 +4,13 @@
 use core::fmt;
 use core::fmt::Debug;
 use core::iter::Fuse;
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+use core::iter::Map;
+
+/// The underlying iterator of the index combinations adaptors, which drops
+/// the elements so that only the positions are kept.
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+pub type Dropped<I> = Map<I, fn(<I as Iterator>::Item)>;
 
 #[derive(Clone)]
 pub struct GenericCombinations<I, C>
@@ -29,6 +36,13 @@ where
 
     /// The state of the iterator.
     state: State,
+
+    /// The last combination yielded from the back, if any.
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    back: Option<C>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -52,21 +66,198 @@ where
             comb,
             buf: Vec::new(),
             state: State::First,
+            #[cfg(any(
+                feature = "array_combinations_with_reps",
+                feature = "combinations_with_reps",
+            ))]
+            back: None,
         }
     }
 
+    /// Creates a new instance that continues from a previously saved state,
+    /// re-reading the elements that the saved combination refers to.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    #[track_caller]
+    pub fn resume(iter: I, comb: C, started: bool) -> Self
+    where
+        C: AsRef<[usize]>,
+    {
+        let digits = comb.as_ref();
+        let valid = if started {
+            digits.windows(2).all(|w| w[0] < w[1])
+        } else {
+            digits.iter().enumerate().all(|(i, &d)| i == d)
+        };
+        assert!(valid, "invalid combinations state");
+        let n = match digits.last() {
+            Some(&d) if started => d + 1,
+            _ => 0,
+        };
+        // If any digit other than the last has moved then the original
+        // iterator must have been exhausted, so to keep that invariant the
+        // entire iterator is buffered.
+        let exhausted = digits
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(1)
+            .any(|(i, &d)| i != d);
+        let mut this = Self::new(iter, comb);
+        this.reserve(n, usize::MAX);
+        this.buf.extend(this.iter.by_ref().take(n));
+        assert!(
+            this.buf.len() == n,
+            "iterator is shorter than the saved combination requires"
+        );
+        if exhausted {
+            this.buf.extend(this.iter.by_ref());
+        }
+        if started {
+            this.state = State::Normal;
+        }
+        this
+    }
+
+    /// Returns the combination digits and whether iteration has started.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn state(&self) -> CombinationsState
+    where
+        C: AsRef<[usize]>,
+    {
+        CombinationsState {
+            comb: self.comb.as_ref().to_vec(),
+            started: matches!(self.state, State::Normal),
+        }
+    }
+
+    /// Returns the length of each combination.
+    #[cfg(feature = "combinations")]
+    pub fn k(&self) -> usize
+    where
+        C: AsRef<[usize]>,
+    {
+        self.comb.as_ref().len()
+    }
+
     pub fn fmt_with(&self, f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result
     where
         I: Debug,
         I::Item: Debug,
         C: Debug,
     {
-        f.debug_struct(name)
-            .field("iter", &self.iter)
+        let mut d = f.debug_struct(name);
+        d.field("iter", &self.iter)
             .field("comb", &self.comb)
             .field("buf", &self.buf)
-            .field("state", &self.state)
-            .finish()
+            .field("state", &self.state);
+        #[cfg(any(
+            feature = "array_combinations_with_reps",
+            feature = "combinations_with_reps",
+        ))]
+        d.field("back", &self.back);
+        d.finish()
+    }
+
+    /// Reserves capacity in the buffer for the rest of the iterator if its
+    /// length is known, otherwise for at least `additional` more elements.
+    ///
+    /// The buffer will eventually hold every element of the iterator, or at
+    /// most `max_buf` elements, so this avoids reallocating as it is filled.
+    /// Since the iterator may be very large and might never be fully consumed
+    /// the reservation is allowed to fail.
+    fn reserve(&mut self, additional: usize, max_buf: usize) {
+        match self.iter.size_hint() {
+            (lower, Some(upper)) if lower == upper && lower > additional => {
+                let len = lower.min(max_buf.saturating_sub(self.buf.len()));
+                if self.buf.try_reserve_exact(len).is_err() {
+                    self.buf.reserve(additional);
+                }
+            }
+            _ => self.buf.reserve(additional),
+        }
+    }
+
+    /// Consumes the rest of the underlying iterator into the buffer and
+    /// returns a view that iterates the remaining combinations by reference.
+    #[cfg(feature = "combinations")]
+    pub fn fill_buf(&mut self) -> GenericCombinationsRef<'_, I::Item, C> {
+        self.buf.extend(self.iter.by_ref());
+        GenericCombinationsRef {
+            buf: &self.buf,
+            comb: &mut self.comb,
+            state: &mut self.state,
+        }
+    }
+
+    /// Consumes the rest of the underlying iterator into the buffer and
+    /// returns the total number of elements.
+    fn fill_len(&mut self) -> usize {
+        self.buf.extend(self.iter.by_ref());
+        self.buf.len()
+    }
+
+    /// Returns the total number of combinations without replacement, or `None`
+    /// if it overflows.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn total(&mut self) -> Option<u128>
+    where
+        C: AsRef<[usize]>,
+    {
+        let n = self.fill_len();
+        binomial(n, self.comb.as_ref().len())
+    }
+
+    /// Returns the number of combinations without replacement that have been
+    /// yielded so far, saturating at `u128::MAX`.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn produced(&self) -> u128
+    where
+        C: AsRef<[usize]>,
+    {
+        if let State::First = self.state {
+            return 0;
+        }
+
+        // The combinations are yielded in lexicographic order so every value
+        // smaller than a digit, that is greater than the previous digit, is
+        // the start of a block of combinations that have already been
+        // yielded. The block contains all the combinations of the remaining
+        // digits with the elements after that value.
+        //
+        // N is not necessarily known yet but if the underlying iterator has
+        // not been exhausted then the combination must be of the form
+        // `0 1 .. K-2 d` in which case the count does not depend on N.
+        let comb = self.comb.as_ref();
+        let k = comb.len();
+        let n = self.buf.len();
+        let mut count = 1u128;
+        let mut start = 0;
+        for (i, &d) in comb.iter().enumerate() {
+            for j in start..d {
+                let block = binomial(n - 1 - j, k - 1 - i).unwrap_or(u128::MAX);
+                count = count.saturating_add(block);
+            }
+            start = d + 1;
+        }
+        count
+    }
+
+    /// Returns the total number of combinations with replacement, or `None` if
+    /// it overflows or the buffer has already been freed.
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    pub fn total_with_reps(&mut self) -> Option<u128>
+    where
+        C: AsRef<[usize]>,
+    {
+        if let State::Done = self.state {
+            return None;
+        }
+        let n = self.fill_len() as u128;
+        let k = u32::try_from(self.comb.as_ref().len()).ok()?;
+        n.checked_pow(k)
     }
 
     #[cfg(any(feature = "array_combinations", feature = "combinations"))]
@@ -74,6 +265,139 @@ where
     where
         I::Item: Clone,
         C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        self.advance()?;
+        Some(self.current().cloned())
+    }
+
+    /// Returns the next combination the same as [`fill_next`][Self::fill_next]
+    /// except that the buffer never holds more than `max_buf` elements, see
+    /// [`advance_bounded`][Self::advance_bounded].
+    #[cfg(feature = "array_combinations")]
+    pub fn fill_next_bounded(
+        &mut self,
+        max_buf: usize,
+    ) -> Option<impl Iterator<Item = I::Item> + '_>
+    where
+        I::Item: Clone,
+        C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        self.advance_bounded(max_buf)?;
+        Some(self.current().cloned())
+    }
+
+    /// Skips `n` combinations and returns the next one by unranking it
+    /// directly instead of enumerating the combinations in between.
+    ///
+    /// This is only possible if N can be determined, that is if the
+    /// underlying iterator reports an exact size hint, in which case the rest
+    /// of it is consumed into the buffer. Otherwise the outer `None` is
+    /// returned and the caller should fall back to advancing one combination
+    /// at a time.
+    #[cfg(feature = "combinations")]
+    pub fn fill_nth(&mut self, n: usize) -> Option<Option<impl Iterator<Item = I::Item> + '_>>
+    where
+        I::Item: Clone,
+        C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        if n == 0 {
+            return None;
+        }
+        match self.iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => {}
+            _ => return None,
+        }
+        let n_total = self.fill_len();
+        let k = self.comb.as_ref().len();
+        let total = binomial(n_total, k)?;
+        let target = self.produced() + n as u128;
+
+        if target >= total {
+            // Move to the final combination so that the iterator is
+            // exhausted. If K > N there are no combinations at all and the
+            // buffer can never be filled, so the state does not matter.
+            if k <= n_total {
+                for (i, d) in self.comb.as_mut().iter_mut().enumerate() {
+                    *d = n_total - k + i;
+                }
+                self.state = State::Normal;
+            }
+            return Some(None);
+        }
+
+        // Each digit is the smallest value such that the number of
+        // combinations starting with the smaller values, given the previous
+        // digits, does not exceed the remaining rank.
+        let mut rank = target;
+        let mut x = 0;
+        for (i, d) in self.comb.as_mut().iter_mut().enumerate() {
+            loop {
+                // Doesn't overflow because it is at most the total.
+                let block = binomial(n_total - 1 - x, k - 1 - i).unwrap();
+                if rank < block {
+                    break;
+                }
+                rank -= block;
+                x += 1;
+            }
+            *d = x;
+            x += 1;
+        }
+        self.state = State::Normal;
+        Some(Some(self.current().cloned()))
+    }
+
+    /// Writes the next combination into the given slice, cloning from the
+    /// buffer in place so that existing allocations can be reused.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn fill_next_into(&mut self, out: &mut [I::Item]) -> bool
+    where
+        I::Item: Clone,
+        C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        if self.advance().is_none() {
+            return false;
+        }
+        for (dst, src) in out.iter_mut().zip(self.current()) {
+            dst.clone_from(src);
+        }
+        true
+    }
+
+    /// Returns the elements of the current combination.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    fn current(&self) -> impl Iterator<Item = &I::Item> + '_
+    where
+        C: AsRef<[usize]>,
+    {
+        self.comb.as_ref().iter().map(|&d| &self.buf[d])
+    }
+
+    /// Returns the positions of the elements in the current combination.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn indices(&self) -> &C {
+        &self.comb
+    }
+
+    /// Advances the combination to the next one, consuming elements from the
+    /// underlying iterator as required. Returns `None` if there are no more
+    /// combinations.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn advance(&mut self) -> Option<()>
+    where
+        C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        self.advance_bounded(usize::MAX)
+    }
+
+    /// Advances the combination the same as [`advance`][Self::advance] except
+    /// that `None` is returned if the buffer would need to hold more than
+    /// `max_buf` elements. In that case the element that would have been
+    /// buffered is dropped.
+    #[cfg(any(feature = "array_combinations", feature = "combinations"))]
+    pub fn advance_bounded(&mut self, max_buf: usize) -> Option<()>
+    where
+        C: AsRef<[usize]> + AsMut<[usize]>,
     {
         let k = self.comb.as_ref().len();
 
@@ -85,9 +409,14 @@ where
             State::Done => unreachable!(),
 
             State::First => {
-                // Fill the buffer with k elements from the iterator.
-                self.buf.reserve(k);
-                for _ in 0..k {
+                // Fill the buffer with k elements from the iterator. The
+                // buffer might already contain some or all elements if it was
+                // filled by `fill_buf`.
+                if k > max_buf {
+                    return None;
+                }
+                self.reserve(k.saturating_sub(self.buf.len()), max_buf);
+                while self.buf.len() < k {
                     self.buf.push(self.iter.next()?);
                 }
                 self.state = State::Normal;
@@ -101,54 +430,39 @@ where
                 let d = unsafe { self.comb.as_mut().last_mut().unwrap_unchecked() };
                 if *d == self.buf.len() - 1 {
                     if let Some(item) = self.iter.next() {
+                        if self.buf.len() >= max_buf {
+                            return None;
+                        }
                         self.buf.push(item);
                     }
                 }
 
-                // Now we find the digit that needs to be incremented. Looking
-                // from the back we find the first digit that is not the final
-                // expected combination for that digit.
-                //
-                // For example given K = 3 and a total N = 5
-                //
-                // 0 1 3 ^--- finds this because at this point we think N = 4
-                //
-                // 0 1 4 ^----- finds this because we know N = 5
-                //
-                // 0 2 3 ^--- finds this again since it is not 4 yet
-                //
-                // The base case in the above example would be the following
-                // which returns `None` and is propagated using `?`.
-                //
-                // 2 3 4
-                //
-                let n = self.buf.len();
-                let i = self
-                    .comb
-                    .as_ref()
-                    .iter()
-                    .enumerate()
-                    .rposition(|(i, &d)| d != i + n - k)?;
-
-                // Increment the digit, and reset the ones to its right
-                //
-                // For example given K = 3 and N = 5 and the following combination.
-                //
-                // 0 1 4 ^----- i
-                //
-                // We would increment digit i and then reset all digits to the
-                // right.
-                //
-                // 0 2 3 ^---- was reset to 3 ^------ was incremented
-                //
-                self.comb.as_mut()[i] += 1;
-                for j in (i + 1)..k {
-                    self.comb.as_mut()[j] = self.comb.as_ref()[j - 1] + 1;
-                }
+                increment(self.comb.as_mut(), self.buf.len())?;
             }
         }
 
-        Some(self.comb.as_ref().iter().map(|&d| self.buf[d].clone()))
+        Some(())
+    }
+
+    /// Restarts the iterator with combinations of one more element, reusing
+    /// the elements that are already buffered.
+    ///
+    /// This must only be called once the combinations of the current length
+    /// are exhausted, at which point the entire underlying iterator has been
+    /// buffered. Returns `false` if there are not enough elements for a longer
+    /// combination.
+    #[cfg(feature = "power_set")]
+    pub fn grow(&mut self) -> bool
+    where
+        C: AsRef<[usize]> + FromIterator<usize>,
+    {
+        let k = self.comb.as_ref().len();
+        if self.buf.len() <= k {
+            return false;
+        }
+        self.comb = C::from_iter(0..=k);
+        self.state = State::First;
+        true
     }
 
     #[cfg(any(
@@ -163,15 +477,18 @@ where
         match self.state {
             State::Done => return None,
 
-            State::First => match self.iter.next() {
-                Some(item) => {
-                    self.buf.push(item);
-                    self.state = State::Normal;
-                }
-                None => {
-                    self.state = State::Done;
+            State::First => {
+                // The buffer might already contain all the elements if it was
+                // filled by `fill_next_back_with_reps`.
+                if self.buf.is_empty() {
+                    self.reserve(1, usize::MAX);
+                    match self.iter.next() {
+                        Some(item) => self.buf.push(item),
+                        None => return self.finish(),
+                    }
                 }
-            },
+                self.state = State::Normal;
+            }
 
             State::Normal => {
                 if let Some(item) = self.iter.next() {
@@ -186,14 +503,295 @@ where
                     }
                     *d = 0;
                     if i == 0 {
-                        self.buf.clear();
-                        self.state = State::Done;
-                        return None;
+                        return self.finish();
                     }
                 }
             }
         }
 
+        // Stop if we have met the combinations yielded from the back.
+        if let Some(back) = &self.back {
+            if self.comb.as_ref() >= back.as_ref() {
+                return self.finish();
+            }
+        }
+
         Some(self.comb.as_ref().iter().map(|&d| self.buf[d].clone()))
     }
+
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    pub fn fill_next_back_with_reps(&mut self) -> Option<impl Iterator<Item = I::Item> + '_>
+    where
+        I::Item: Clone,
+        C: Clone + AsRef<[usize]> + AsMut<[usize]>,
+    {
+        if let State::Done = self.state {
+            return None;
+        }
+
+        // The last combination depends on N so the entire iterator needs to be
+        // buffered.
+        self.buf.extend(self.iter.by_ref());
+        let n = self.buf.len();
+        if n == 0 {
+            return self.finish();
+        }
+
+        let back = match self.back.take() {
+            Some(mut back) => {
+                if decrement(back.as_mut(), n).is_none() {
+                    return self.finish();
+                }
+                back
+            }
+            None => {
+                let mut back = self.comb.clone();
+                back.as_mut().fill(n - 1);
+                back
+            }
+        };
+
+        // Stop if we have met the combinations yielded from the front.
+        if let State::Normal = self.state {
+            if back.as_ref() <= self.comb.as_ref() {
+                return self.finish();
+            }
+        }
+
+        let buf = &self.buf;
+        let back = &*self.back.insert(back);
+        Some(back.as_ref().iter().map(move |&d| buf[d].clone()))
+    }
+
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    pub fn size_hint_with_reps(&self) -> (usize, Option<usize>)
+    where
+        C: AsRef<[usize]>,
+    {
+        // N is not fully known until the underlying iterator is drained so
+        // the bounds are calculated using the bounds on N.
+        let (lower, upper) = self.iter.size_hint();
+        let n = self.buf.len();
+        let lower = n
+            .checked_add(lower)
+            .and_then(|n| self.remaining_with_reps(n))
+            .unwrap_or(usize::MAX);
+        let upper = upper
+            .and_then(|upper| n.checked_add(upper))
+            .and_then(|n| self.remaining_with_reps(n));
+        (lower, upper)
+    }
+
+    /// Returns the number of remaining combinations with replacement given
+    /// that there are `n` elements in total, or `None` if it overflows.
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    fn remaining_with_reps(&self, n: usize) -> Option<usize>
+    where
+        C: AsRef<[usize]>,
+    {
+        // Interpreting the combination as a K digit number in base N gives
+        // its position in the sequence of all N^K combinations.
+        let position = |comb: &[usize]| {
+            comb.iter()
+                .try_fold(0usize, |acc, &d| acc.checked_mul(n)?.checked_add(d))
+        };
+        let end = match &self.back {
+            Some(back) => position(back.as_ref())?,
+            None => self
+                .comb
+                .as_ref()
+                .iter()
+                .try_fold(1usize, |acc, _| acc.checked_mul(n))?,
+        };
+        match self.state {
+            State::First => Some(end),
+            State::Normal => Some(end - position(self.comb.as_ref())? - 1),
+            State::Done => Some(0),
+        }
+    }
+
+    /// Marks the iterator as done and frees the buffer.
+    #[cfg(any(
+        feature = "array_combinations_with_reps",
+        feature = "combinations_with_reps",
+    ))]
+    fn finish<T>(&mut self) -> Option<T> {
+        self.buf.clear();
+        self.state = State::Done;
+        None
+    }
+}
+
+/// The position of a combinations iterator that can be saved and later used
+/// to resume iteration over a fresh iterator.
+///
+/// This only contains the positions of the elements in the current
+/// combination, not the elements themselves. It is returned by
+/// [`Combinations::state`] and [`ArrayCombinations::state`].
+///
+/// [`Combinations::state`]: crate::Combinations::state
+/// [`ArrayCombinations::state`]: crate::ArrayCombinations::state
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "array_combinations", feature = "combinations")))
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinationsState {
+    comb: Vec<usize>,
+    started: bool,
+}
+
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+impl CombinationsState {
+    /// Returns the length of the combinations.
+    #[inline]
+    pub fn k(&self) -> usize {
+        self.comb.len()
+    }
+
+    pub(crate) fn into_parts(self) -> (Vec<usize>, bool) {
+        (self.comb, self.started)
+    }
+}
+
+/// A view of a [`GenericCombinations`] whose underlying iterator has been fully
+/// consumed into the buffer, allowing combinations to borrow from it.
+#[cfg(feature = "combinations")]
+#[derive(Debug)]
+pub struct GenericCombinationsRef<'a, T, C> {
+    buf: &'a [T],
+    comb: &'a mut C,
+    state: &'a mut State,
+}
+
+#[cfg(feature = "combinations")]
+impl<'a, T, C> GenericCombinationsRef<'a, T, C> {
+    pub fn fill_next(&mut self) -> Option<impl Iterator<Item = &'a T> + '_>
+    where
+        C: AsRef<[usize]> + AsMut<[usize]>,
+    {
+        let k = self.comb.as_ref().len();
+
+        match self.state {
+            #[cfg(any(
+                feature = "array_combinations_with_reps",
+                feature = "combinations_with_reps",
+            ))]
+            State::Done => unreachable!(),
+
+            State::First => {
+                if self.buf.len() < k {
+                    return None;
+                }
+                *self.state = State::Normal;
+            }
+
+            State::Normal => {
+                // All elements are already buffered so we know N exactly.
+                increment(self.comb.as_mut(), self.buf.len())?;
+            }
+        }
+
+        let buf = self.buf;
+        Some(self.comb.as_ref().iter().map(move |&d| &buf[d]))
+    }
+}
+
+/// Returns the binomial coefficient `n` choose `k`, or `None` if it overflows.
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+fn binomial(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let (n, k) = (n as u128, k as u128);
+    // Each intermediate value is itself a binomial coefficient so the division
+    // is always exact. Dividing out the common factor first means it only
+    // overflows if the next coefficient does.
+    (0..k.min(n - k)).try_fold(1u128, |acc, i| {
+        let g = gcd(acc, i + 1);
+        (acc / g).checked_mul((n - i) / ((i + 1) / g))
+    })
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Increments the combination to the next one without replacement, given that
+/// there are currently `n` elements known. Returns `None` if there are no more
+/// combinations.
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+fn increment(comb: &mut [usize], n: usize) -> Option<()> {
+    // Find the digit that needs to be incremented. Looking from the back we
+    // find the first digit that is not the final expected combination for
+    // that digit.
+    //
+    // For example given K = 3 and a total N = 5
+    //
+    // 0 1 3 ^--- finds this because at this point we think N = 4
+    //
+    // 0 1 4 ^----- finds this because we know N = 5
+    //
+    // 0 2 3 ^--- finds this again since it is not 4 yet
+    //
+    // The base case in the above example would be the following which returns
+    // `None`.
+    //
+    // 2 3 4
+    //
+    let k = comb.len();
+    let i = comb
+        .iter()
+        .enumerate()
+        .rposition(|(i, &d)| d != i + n - k)?;
+
+    // Increment the digit, and reset the ones to its right
+    //
+    // For example given K = 3 and N = 5 and the following combination.
+    //
+    // 0 1 4 ^----- i
+    //
+    // We would increment digit i and then reset all digits to the right.
+    //
+    // 0 2 3 ^---- was reset to 3 ^------ was incremented
+    //
+    comb[i] += 1;
+    for j in (i + 1)..k {
+        comb[j] = comb[j - 1] + 1;
+    }
+    Some(())
+}
+
+/// Decrements the combination with replacement to the previous one, given that
+/// there are `n` elements in total. Returns `None` if there are no more
+/// combinations.
+#[cfg(any(
+    feature = "array_combinations_with_reps",
+    feature = "combinations_with_reps",
+))]
+fn decrement(comb: &mut [usize], n: usize) -> Option<()> {
+    for d in comb.iter_mut().rev() {
+        if *d > 0 {
+            *d -= 1;
+            return Some(());
+        }
+        *d = n - 1;
+    }
+    None
 }
@@ -0,0 +1,91 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::adaptors::mixed_radix;
+
+/// Returns an iterator over all coordinates of a `D`-dimensional grid spanned
+/// by the given ranges.
+///
+/// The coordinates are yielded as arrays in row-major order, that is the last
+/// coordinate changes the fastest. This is equivalent to the cartesian product
+/// of the ranges but the ranges do not need to be cloned for every row and the
+/// coordinates are yielded as arrays instead of nested tuples.
+///
+/// If any range is empty then there are no coordinates and if `D = 0` then a
+/// single empty coordinate is yielded.
+///
+/// # Examples
+///
+/// ```
+/// use itermore::grid;
+///
+/// let mut iter = grid([0..2, 5..8]);
+/// assert_eq!(iter.next(), Some([0, 5]));
+/// assert_eq!(iter.next(), Some([0, 6]));
+/// assert_eq!(iter.next(), Some([0, 7]));
+/// assert_eq!(iter.next(), Some([1, 5]));
+/// assert_eq!(iter.next(), Some([1, 6]));
+/// assert_eq!(iter.next(), Some([1, 7]));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "grid")))]
+#[inline]
+pub fn grid<const D: usize>(ranges: [Range<usize>; D]) -> Grid<D> {
+    Grid::new(ranges)
+}
+
+/// An iterator over all coordinates of a `D`-dimensional grid.
+///
+/// This struct is created by the [`grid`] function. See its documentation for
+/// more.
+#[cfg_attr(docsrs, doc(cfg(feature = "grid")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Grid<const D: usize> {
+    ranges: [Range<usize>; D],
+
+    /// The next coordinate to yield or `None` if the iterator is exhausted.
+    next: Option<[usize; D]>,
+}
+
+impl<const D: usize> Grid<D> {
+    fn new(ranges: [Range<usize>; D]) -> Self {
+        let next = match ranges.iter().any(|r| r.is_empty()) {
+            true => None,
+            false => Some(ranges.clone().map(|r| r.start)),
+        };
+        Self { ranges, next }
+    }
+
+    /// Returns the number of remaining coordinates, or `None` if it overflows.
+    fn remaining(&self) -> Option<usize> {
+        match &self.next {
+            Some(next) => mixed_radix::remaining(self.ranges.iter().cloned(), next),
+            None => Some(0),
+        }
+    }
+}
+
+impl<const D: usize> Iterator for Grid<D> {
+    type Item = [usize; D];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.as_mut()?;
+        let item = *next;
+        if mixed_radix::increment(self.ranges.iter().cloned(), next).is_none() {
+            self.next = None;
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<const D: usize> FusedIterator for Grid<D> {}
@@ -0,0 +1,93 @@
+use core::iter::FusedIterator;
+
+use crate::adaptors::mixed_radix;
+
+/// Returns an iterator over all coordinates of an N-dimensional grid with the
+/// given dimensions.
+///
+/// The coordinates are yielded in row-major order, that is the last
+/// coordinate changes the fastest. This is equivalent to the cartesian product
+/// of the ranges `0..dims[0]`, `0..dims[1]`, etc but does not require the
+/// ranges to be cloned.
+///
+/// If any dimension is zero then there are no coordinates and if there are no
+/// dimensions then a single empty coordinate is yielded.
+///
+/// # Examples
+///
+/// ```
+/// use itermore::index_product;
+///
+/// let mut iter = index_product(&[2, 3]);
+/// assert_eq!(iter.next(), Some(vec![0, 0]));
+/// assert_eq!(iter.next(), Some(vec![0, 1]));
+/// assert_eq!(iter.next(), Some(vec![0, 2]));
+/// assert_eq!(iter.next(), Some(vec![1, 0]));
+/// assert_eq!(iter.next(), Some(vec![1, 1]));
+/// assert_eq!(iter.next(), Some(vec![1, 2]));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "index_product")))]
+#[inline]
+pub fn index_product(dims: &[usize]) -> IndexProduct {
+    IndexProduct::new(dims)
+}
+
+/// An iterator over all coordinates of an N-dimensional grid.
+///
+/// This struct is created by the [`index_product`] function. See its
+/// documentation for more.
+#[cfg_attr(docsrs, doc(cfg(feature = "index_product")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IndexProduct {
+    dims: Vec<usize>,
+
+    /// The next coordinate to yield or `None` if the iterator is exhausted.
+    next: Option<Vec<usize>>,
+}
+
+impl IndexProduct {
+    fn new(dims: &[usize]) -> Self {
+        let next = match dims.contains(&0) {
+            true => None,
+            false => Some(vec![0; dims.len()]),
+        };
+        Self {
+            dims: dims.to_vec(),
+            next,
+        }
+    }
+
+    /// Returns the number of remaining coordinates, or `None` if it overflows.
+    fn remaining(&self) -> Option<usize> {
+        match &self.next {
+            Some(next) => mixed_radix::remaining(self.dims.iter().map(|&d| 0..d), next),
+            None => Some(0),
+        }
+    }
+}
+
+impl Iterator for IndexProduct {
+    type Item = Vec<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.as_mut()?;
+        let item = next.clone();
+        if mixed_radix::increment(self.dims.iter().map(|&d| 0..d), next).is_none() {
+            self.next = None;
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for IndexProduct {}
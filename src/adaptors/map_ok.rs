@@ -0,0 +1,174 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`map_ok`] and [`filter_ok`] methods
+/// for iterators.
+///
+/// [`map_ok`]: IterMapOk::map_ok
+/// [`filter_ok`]: IterMapOk::filter_ok
+#[cfg_attr(docsrs, doc(cfg(feature = "map_ok")))]
+pub trait IterMapOk: Iterator {
+    /// Returns an iterator adaptor that applies a function to every `Ok`
+    /// value of an iterator of [`Result`]s.
+    ///
+    /// `Err` values are yielded unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMapOk;
+    ///
+    /// let iter = ["1", "x", "3"].into_iter().map(str::parse::<i32>);
+    /// let mut iter = iter.map_ok(|n| n * 10);
+    /// assert_eq!(iter.next(), Some(Ok(10)));
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next(), Some(Ok(30)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn map_ok<T, E, U, F>(self, f: F) -> MapOk<Self, F>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        F: FnMut(T) -> U,
+    {
+        MapOk { iter: self, f }
+    }
+
+    /// Returns an iterator adaptor that filters the `Ok` values of an
+    /// iterator of [`Result`]s using the given predicate.
+    ///
+    /// `Ok` values are only yielded if the predicate returns `true` and `Err`
+    /// values are always yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMapOk;
+    ///
+    /// let iter = [Ok(1), Err("bad"), Ok(2), Ok(3)].into_iter();
+    /// let v = Vec::from_iter(iter.filter_ok(|n| n % 2 == 1));
+    /// assert_eq!(v, [Ok(1), Err("bad"), Ok(3)]);
+    /// ```
+    #[inline]
+    fn filter_ok<T, E, P>(self, pred: P) -> FilterOk<Self, P>
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+        P: FnMut(&T) -> bool,
+    {
+        FilterOk { iter: self, pred }
+    }
+}
+
+impl<I: ?Sized> IterMapOk for I where I: Iterator {}
+
+/// An iterator that applies a function to every `Ok` value of an iterator of
+/// [`Result`]s.
+///
+/// This struct is created by the [`map_ok`] method on iterators. See its
+/// documentation for more.
+///
+/// [`map_ok`]: IterMapOk::map_ok
+#[cfg_attr(docsrs, doc(cfg(feature = "map_ok")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+/// An iterator that filters the `Ok` values of an iterator of [`Result`]s.
+///
+/// This struct is created by the [`filter_ok`] method on iterators. See its
+/// documentation for more.
+///
+/// [`filter_ok`]: IterMapOk::filter_ok
+#[cfg_attr(docsrs, doc(cfg(feature = "map_ok")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterOk<I, P> {
+    iter: I,
+    pred: P,
+}
+
+impl<I, F> Debug for MapOk<I, F>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapOk").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, P> Debug for FilterOk<I, P>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilterOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, T, E, U> Iterator for MapOk<I, F>
+where
+    I: Iterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, P, T, E> Iterator for FilterOk<I, P>
+where
+    I: Iterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let pred = &mut self.pred;
+        self.iter.find(|r| match r {
+            Ok(t) => pred(t),
+            Err(_) => true,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, F, T, E, U> ExactSizeIterator for MapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
+
+impl<I, F, T, E, U> FusedIterator for MapOk<I, F>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+}
+
+impl<I, P, T, E> FusedIterator for FilterOk<I, P>
+where
+    I: FusedIterator<Item = Result<T, E>>,
+    P: FnMut(&T) -> bool,
+{
+}
@@ -0,0 +1,165 @@
+use core::cell::RefCell;
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+use std::rc::Rc;
+
+/// An extension trait that provides the [`memoize`] method for iterators.
+///
+/// [`memoize`]: IterMemoize::memoize
+#[cfg_attr(docsrs, doc(cfg(feature = "memoize")))]
+pub trait IterMemoize: Iterator {
+    /// Returns an iterator that caches the elements it produces so that it can
+    /// be cheaply cloned and iterated multiple times.
+    ///
+    /// All clones share the same cache and the underlying iterator is only
+    /// advanced when a clone reaches the end of the elements cached so far, so
+    /// every element is produced by the underlying iterator only once.
+    /// Cloning the iterator yields an iterator that starts at the same
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMemoize;
+    ///
+    /// let mut calls = 0;
+    /// let iter = (1..4).inspect(|_| calls += 1).memoize();
+    ///
+    /// let sum: i32 = iter.clone().sum();
+    /// let max = iter.clone().max();
+    /// drop(iter);
+    ///
+    /// assert_eq!(sum, 6);
+    /// assert_eq!(max, Some(3));
+    /// assert_eq!(calls, 3);
+    /// ```
+    #[inline]
+    fn memoize(self) -> Memoized<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Memoized::new(self)
+    }
+}
+
+impl<I: ?Sized> IterMemoize for I where I: Iterator {}
+
+/// An iterator that caches the elements it produces so that it can be cheaply
+/// cloned and iterated multiple times.
+///
+/// This struct is created by the [`memoize`] method on iterators. See its
+/// documentation for more.
+///
+/// [`memoize`]: IterMemoize::memoize
+#[cfg_attr(docsrs, doc(cfg(feature = "memoize")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Memoized<I>
+where
+    I: Iterator,
+{
+    cache: Rc<RefCell<Cache<I>>>,
+
+    /// The index of the next element to yield.
+    pos: usize,
+}
+
+/// The state shared between all clones.
+#[derive(Debug)]
+struct Cache<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    buf: Vec<I::Item>,
+}
+
+impl<I> Memoized<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> Self {
+        Self {
+            cache: Rc::new(RefCell::new(Cache {
+                iter,
+                buf: Vec::new(),
+            })),
+            pos: 0,
+        }
+    }
+}
+
+impl<I> Debug for Memoized<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memoized")
+            .field("cache", &self.cache)
+            .field("pos", &self.pos)
+            .finish()
+    }
+}
+
+impl<I> Clone for Memoized<I>
+where
+    I: Iterator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            pos: self.pos,
+        }
+    }
+}
+
+impl<I> Iterator for Memoized<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cache = self.cache.borrow_mut();
+        let item = match cache.buf.get(self.pos) {
+            Some(item) => item.clone(),
+            None => {
+                let item = cache.iter.next()?;
+                cache.buf.push(item.clone());
+                item
+            }
+        };
+        self.pos += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let cache = self.cache.borrow();
+        let extra = cache.buf.len() - self.pos;
+        let (lower, upper) = cache.iter.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for Memoized<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for Memoized<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
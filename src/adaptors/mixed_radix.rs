@@ -0,0 +1,42 @@
+//! Implements logic that is common to both the grid and index product
+//! iterators.
+
+use core::ops::Range;
+
+/// Returns the number of coordinates from the given one to the end of the
+/// grid spanned by the ranges, or `None` if it overflows.
+pub fn remaining<R>(ranges: R, coord: &[usize]) -> Option<usize>
+where
+    R: Iterator<Item = Range<usize>>,
+{
+    // Interpreting the coordinate as a mixed radix number gives its position
+    // in the sequence of all coordinates.
+    let mut total = 1usize;
+    let mut position = 0usize;
+    for (r, &i) in ranges.zip(coord) {
+        let d = r.end - r.start;
+        total = total.checked_mul(d)?;
+        position = position.checked_mul(d)? + i - r.start;
+    }
+    Some(total - position)
+}
+
+/// Increments the coordinate to the next one in row-major order. Returns
+/// `None` if it was the last coordinate of the grid spanned by the ranges.
+pub fn increment<R>(ranges: R, coord: &mut [usize]) -> Option<()>
+where
+    R: DoubleEndedIterator<Item = Range<usize>> + ExactSizeIterator,
+{
+    let carry = coord.iter_mut().zip(ranges).rev().all(|(i, r)| {
+        *i += 1;
+        if *i < r.end {
+            return false;
+        }
+        *i = r.start;
+        true
+    });
+    match carry {
+        true => None,
+        false => Some(()),
+    }
+}
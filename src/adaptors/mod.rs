@@ -6,18 +6,80 @@ pub mod array_combinations;
 pub mod array_combinations_with_reps;
 #[cfg(feature = "array_windows")]
 pub mod array_windows;
+#[cfg(feature = "array_windows_dot")]
+pub mod array_windows_dot;
+#[cfg(feature = "array_windows_min_max")]
+pub mod array_windows_min_max;
 #[cfg(feature = "cartesian_product")]
 pub mod cartesian_product;
+#[cfg(feature = "chunk_by_weight")]
+pub mod chunk_by_weight;
+#[cfg(feature = "chunk_sums")]
+pub mod chunk_sums;
+#[cfg(feature = "chunks")]
+pub mod chunks;
 #[cfg(feature = "circular_array_windows")]
 pub mod circular_array_windows;
 #[cfg(feature = "combinations")]
 pub mod combinations;
+#[cfg(feature = "combinations_gray")]
+pub mod combinations_gray;
 #[cfg(feature = "combinations_with_reps")]
 pub mod combinations_with_reps;
+#[cfg(feature = "cycle_n")]
+pub mod cycle_n;
+#[cfg(feature = "dedup")]
+pub mod dedup;
+#[cfg(feature = "drop_last")]
+pub mod drop_last;
+#[cfg(feature = "fold_chunks")]
+pub mod fold_chunks;
 #[cfg(any(
     feature = "array_combinations",
     feature = "array_combinations_with_reps",
     feature = "combinations",
     feature = "combinations_with_reps"
 ))]
-mod generic_combinations;
+pub mod generic_combinations;
+#[cfg(feature = "grid")]
+pub mod grid;
+#[cfg(feature = "index_product")]
+pub mod index_product;
+#[cfg(feature = "map_ok")]
+pub mod map_ok;
+#[cfg(feature = "memoize")]
+pub mod memoize;
+#[cfg(any(feature = "grid", feature = "index_product"))]
+pub mod mixed_radix;
+#[cfg(feature = "overlapping_chunks")]
+pub mod overlapping_chunks;
+#[cfg(feature = "power_set")]
+pub mod power_set;
+#[cfg(feature = "repeat_each")]
+pub mod repeat_each;
+#[cfg(feature = "rolling_windows")]
+pub mod rolling_windows;
+#[cfg(feature = "min_max")]
+pub mod running_min_max;
+#[cfg(feature = "split_when")]
+pub mod split_when;
+#[cfg(feature = "step_by_offset")]
+pub mod step_by_offset;
+#[cfg(feature = "take_exactly")]
+pub mod take_exactly;
+#[cfg(feature = "tee")]
+pub mod tee;
+#[cfg(feature = "windows")]
+pub mod windows;
+#[cfg(feature = "windows_buffered")]
+pub mod windows_buffered;
+#[cfg(feature = "with_lag")]
+pub mod with_lag;
+#[cfg(feature = "with_next")]
+pub mod with_next;
+#[cfg(feature = "with_phase")]
+pub mod with_phase;
+#[cfg(feature = "with_prev")]
+pub mod with_prev;
+#[cfg(feature = "zip_longest")]
+pub mod zip_longest;
@@ -0,0 +1,151 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`overlapping_chunks`] method for
+/// iterators.
+///
+/// [`overlapping_chunks`]: IterOverlappingChunks::overlapping_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "overlapping_chunks")))]
+pub trait IterOverlappingChunks: Iterator {
+    /// Returns an iterator over `N` elements of the iterator at a time where
+    /// each chunk shares `overlap` elements with the previous one.
+    ///
+    /// Each chunk starts `N - overlap` elements after the start of the previous
+    /// chunk. An overlap of zero is the same as [`array_chunks`] and an overlap
+    /// of `N - 1` is the same as [`array_windows`]. If there are not enough
+    /// elements left to fill a chunk then the remaining elements are skipped.
+    ///
+    /// This adaptor clones the iterator elements so that they can be part of
+    /// successive chunks, this makes this it most suited for iterators of
+    /// references and other values that are cheap to clone or copy.
+    ///
+    /// # Panics
+    ///
+    /// If `overlap` is greater than or equal to `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterOverlappingChunks;
+    ///
+    /// let mut iter = (1..=8).overlapping_chunks::<3>(1);
+    /// assert_eq!(iter.next(), Some([1, 2, 3]));
+    /// assert_eq!(iter.next(), Some([3, 4, 5]));
+    /// assert_eq!(iter.next(), Some([5, 6, 7]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_chunks`]: crate::IterArrayChunks::array_chunks
+    /// [`array_windows`]: crate::IterArrayWindows::array_windows
+    #[inline]
+    #[track_caller]
+    fn overlapping_chunks<const N: usize>(self, overlap: usize) -> OverlappingChunks<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        OverlappingChunks::new(self, overlap)
+    }
+}
+
+impl<I: ?Sized> IterOverlappingChunks for I where I: Iterator {}
+
+/// An iterator over `N` elements of the iterator at a time where each chunk
+/// shares some elements with the previous one.
+///
+/// This struct is created by the [`overlapping_chunks`] method on iterators.
+/// See its documentation for more.
+///
+/// [`overlapping_chunks`]: IterOverlappingChunks::overlapping_chunks
+#[cfg_attr(docsrs, doc(cfg(feature = "overlapping_chunks")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OverlappingChunks<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+    last: Option<[I::Item; N]>,
+    step: usize,
+}
+
+impl<I, const N: usize> OverlappingChunks<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    #[track_caller]
+    fn new(iter: I, overlap: usize) -> Self {
+        assert!(overlap < N, "overlap must be less than the chunk size");
+        Self {
+            iter,
+            last: None,
+            step: N - overlap,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for OverlappingChunks<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, last, step } = self;
+        match last {
+            Some(last) => {
+                last.rotate_left(*step);
+                for slot in &mut last[N - *step..] {
+                    *slot = iter.next()?;
+                }
+                Some(last.clone())
+            }
+            None => {
+                let tmp = arrays::from_iter(iter).ok()?;
+                *last = Some(tmp.clone());
+                Some(tmp)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let started = self.last.is_some();
+        let (lower, upper) = self.iter.size_hint();
+        (
+            chunks::<N>(started, self.step, lower),
+            upper.map(|n| chunks::<N>(started, self.step, n)),
+        )
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        chunks::<N>(self.last.is_some(), self.step, self.iter.count())
+    }
+}
+
+/// Returns the number of chunks given the number of elements left in the
+/// underlying iterator.
+fn chunks<const N: usize>(started: bool, step: usize, n: usize) -> usize {
+    match started {
+        true => n / step,
+        false if n < N => 0,
+        false => (n - N) / step + 1,
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for OverlappingChunks<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for OverlappingChunks<I, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,181 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+use crate::adaptors::generic_combinations::GenericCombinations;
+
+/// An extension trait that provides the [`power_set`] and
+/// [`power_set_up_to`] methods for iterators.
+///
+/// [`power_set`]: IterPowerSet::power_set
+/// [`power_set_up_to`]: IterPowerSet::power_set_up_to
+#[cfg_attr(docsrs, doc(cfg(feature = "power_set")))]
+pub trait IterPowerSet: Iterator {
+    /// Returns an iterator adaptor that iterates over all the subsets of the
+    /// elements in the underlying iterator.
+    ///
+    /// The subsets are yielded in order of increasing size, starting with the
+    /// empty set, and subsets of the same size are yielded in the same order
+    /// as [`combinations`]. There are `2^n` subsets of `n` elements, see
+    /// [`power_set_up_to`] to limit the size of the subsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterPowerSet;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().power_set();
+    /// assert_eq!(iter.next(), Some(vec![]));
+    /// assert_eq!(iter.next(), Some(vec![1]));
+    /// assert_eq!(iter.next(), Some(vec![2]));
+    /// assert_eq!(iter.next(), Some(vec![3]));
+    /// assert_eq!(iter.next(), Some(vec![1, 2]));
+    /// assert_eq!(iter.next(), Some(vec![1, 3]));
+    /// assert_eq!(iter.next(), Some(vec![2, 3]));
+    /// assert_eq!(iter.next(), Some(vec![1, 2, 3]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`combinations`]: crate::IterCombinations::combinations
+    /// [`power_set_up_to`]: IterPowerSet::power_set_up_to
+    #[inline]
+    fn power_set(self) -> PowerSet<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        PowerSet::new(self, usize::MAX)
+    }
+
+    /// Returns an iterator adaptor that iterates over all the subsets of the
+    /// elements in the underlying iterator with at most `max_k` elements.
+    ///
+    /// This is the same as [`power_set`] except that it stops after the
+    /// subsets of length `max_k`, which is much smaller than the full power
+    /// set when `max_k` is small compared to the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterPowerSet;
+    ///
+    /// let mut iter = "abc".chars().power_set_up_to(1);
+    /// assert_eq!(iter.next(), Some(vec![]));
+    /// assert_eq!(iter.next(), Some(vec!['a']));
+    /// assert_eq!(iter.next(), Some(vec!['b']));
+    /// assert_eq!(iter.next(), Some(vec!['c']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`power_set`]: IterPowerSet::power_set
+    #[inline]
+    fn power_set_up_to(self, max_k: usize) -> PowerSet<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        PowerSet::new(self, max_k)
+    }
+}
+
+impl<I: ?Sized> IterPowerSet for I where I: Iterator {}
+
+/// An iterator that iterates over the subsets of all the elements in the
+/// underlying iterator.
+///
+/// This struct is created by the [`power_set`] and [`power_set_up_to`]
+/// methods on iterators. See their documentation for more.
+///
+/// [`power_set`]: IterPowerSet::power_set
+/// [`power_set_up_to`]: IterPowerSet::power_set_up_to
+#[cfg_attr(docsrs, doc(cfg(feature = "power_set")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PowerSet<I>
+where
+    I: Iterator,
+{
+    /// The combinations of the current length, starting at one.
+    combs: GenericCombinations<I, Vec<usize>>,
+
+    /// The maximum length of a subset.
+    max_k: usize,
+
+    /// Whether the empty set still needs to be yielded.
+    empty: bool,
+
+    /// Whether all the subsets have been yielded.
+    done: bool,
+}
+
+impl<I> PowerSet<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I, max_k: usize) -> Self {
+        Self {
+            combs: GenericCombinations::new(iter, vec![0]),
+            max_k,
+            empty: true,
+            done: max_k == 0,
+        }
+    }
+}
+
+impl<I> Clone for PowerSet<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            combs: self.combs.clone(),
+            max_k: self.max_k,
+            empty: self.empty,
+            done: self.done,
+        }
+    }
+}
+
+impl<I> Debug for PowerSet<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.combs.fmt_with(f, "PowerSet")
+    }
+}
+
+impl<I> Iterator for PowerSet<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.empty {
+            self.empty = false;
+            return Some(Vec::new());
+        }
+        if self.done {
+            return None;
+        }
+        loop {
+            if let Some(comb) = self.combs.fill_next() {
+                return Some(Vec::from_iter(comb));
+            }
+            if self.combs.k() >= self.max_k || !self.combs.grow() {
+                self.done = true;
+                return None;
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for PowerSet<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,142 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`repeat_each`] method and friends for
+/// iterators.
+///
+/// [`repeat_each`]: IterRepeatEach::repeat_each
+#[cfg_attr(docsrs, doc(cfg(feature = "repeat_each")))]
+pub trait IterRepeatEach: Iterator {
+    /// Returns an iterator that yields each element `N` times in a row.
+    ///
+    /// The last copy of each element is moved out instead of cloned. If
+    /// `N = 0` then nothing is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterRepeatEach;
+    ///
+    /// let v: Vec<_> = [1, 2].into_iter().repeat_each::<3>().collect();
+    /// assert_eq!(v, [1, 1, 1, 2, 2, 2]);
+    /// ```
+    #[inline]
+    fn repeat_each<const N: usize>(self) -> RepeatEach<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        RepeatEach::new(self, N)
+    }
+
+    /// Returns an iterator that yields each element `n` times in a row.
+    ///
+    /// This is the same as [`repeat_each`] but with the number of repetitions
+    /// given at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterRepeatEach;
+    ///
+    /// let s: String = "abc".chars().repeat_each_n(2).collect();
+    /// assert_eq!(s, "aabbcc");
+    /// ```
+    ///
+    /// [`repeat_each`]: IterRepeatEach::repeat_each
+    #[inline]
+    fn repeat_each_n(self, n: usize) -> RepeatEach<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        RepeatEach::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterRepeatEach for I where I: Iterator {}
+
+/// An iterator that yields each element a number of times in a row.
+///
+/// This struct is created by the [`repeat_each`] and [`repeat_each_n`]
+/// methods on iterators. See their documentation for more.
+///
+/// [`repeat_each`]: IterRepeatEach::repeat_each
+/// [`repeat_each_n`]: IterRepeatEach::repeat_each_n
+#[cfg_attr(docsrs, doc(cfg(feature = "repeat_each")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RepeatEach<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The element currently being repeated and the number of copies left.
+    curr: Option<(usize, I::Item)>,
+
+    n: usize,
+}
+
+impl<I> RepeatEach<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I, n: usize) -> Self {
+        Self {
+            iter,
+            curr: None,
+            n,
+        }
+    }
+}
+
+impl<I> Iterator for RepeatEach<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.curr {
+            Some((1, _)) => self.curr.take().map(|(_, item)| item),
+            Some((left, item)) => {
+                *left -= 1;
+                Some(item.clone())
+            }
+            None => {
+                if self.n == 0 {
+                    return None;
+                }
+                let item = self.iter.next()?;
+                if self.n > 1 {
+                    self.curr = Some((self.n - 1, item.clone()));
+                }
+                Some(item)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let left = self.curr.as_ref().map_or(0, |(left, _)| *left);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_mul(self.n).saturating_add(left),
+            upper
+                .and_then(|upper| upper.checked_mul(self.n))
+                .and_then(|upper| upper.checked_add(left)),
+        )
+    }
+}
+
+impl<I> FusedIterator for RepeatEach<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,174 @@
+use core::iter::FusedIterator;
+
+use crate::{ArrayWindows, IterArrayWindows};
+
+/// The base of the polynomial used by [`rolling_hash`].
+const BASE: u64 = 0x100_0000_01b3;
+
+/// An extension trait that provides the [`rolling_windows`] method for
+/// iterators.
+///
+/// [`rolling_windows`]: IterRollingWindows::rolling_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "rolling_windows")))]
+pub trait IterRollingWindows: Iterator {
+    /// Returns an iterator over all contiguous windows of length `N` along
+    /// with a rolling hash of each window.
+    ///
+    /// The hash is a polynomial hash of the elements of the window, see
+    /// [`rolling_hash`] for the exact definition. Instead of hashing
+    /// all `N` elements for every window, the hash of each window is updated
+    /// from the hash of the previous one in constant time. This makes it
+    /// suitable for algorithms like Rabin-Karp string search.
+    ///
+    /// The windows are produced by [`array_windows`], see its documentation for
+    /// more.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Find the position of a needle in a haystack.
+    ///
+    /// ```
+    /// use itermore::{rolling_hash, IterRollingWindows};
+    ///
+    /// let needle = *b"ipsum";
+    /// let hash = rolling_hash(&needle);
+    ///
+    /// let haystack = b"lorem ipsum dolor";
+    /// let pos = haystack
+    ///     .iter()
+    ///     .copied()
+    ///     .rolling_windows()
+    ///     .position(|(w, h)| h == hash && w == needle);
+    /// assert_eq!(pos, Some(6));
+    /// ```
+    ///
+    /// [`array_windows`]: IterArrayWindows::array_windows
+    #[inline]
+    fn rolling_windows<const N: usize>(self) -> RollingWindows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone + Into<u64>,
+    {
+        RollingWindows::new(self)
+    }
+}
+
+impl<I: ?Sized> IterRollingWindows for I where I: Iterator {}
+
+/// Returns the hash of the given window as calculated by [`rolling_windows`].
+///
+/// The hash is defined as the sum of `w[i] * B ^ (N - 1 - i)` for each element
+/// in the window, where `B` is a fixed odd base, and all arithmetic wraps
+/// around at `u64::MAX`. Windows with different hashes are always different but
+/// windows with the same hash are not necessarily equal.
+///
+/// # Examples
+///
+/// ```
+/// use itermore::{rolling_hash, IterRollingWindows};
+///
+/// let (_, hash) = "abc".chars().rolling_windows::<2>().last().unwrap();
+/// assert_eq!(hash, rolling_hash(&['b', 'c']));
+/// ```
+///
+/// [`rolling_windows`]: IterRollingWindows::rolling_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "rolling_windows")))]
+pub fn rolling_hash<T, const N: usize>(window: &[T; N]) -> u64
+where
+    T: Clone + Into<u64>,
+{
+    window.iter().fold(0u64, |acc, x| {
+        acc.wrapping_mul(BASE).wrapping_add(x.clone().into())
+    })
+}
+
+/// An iterator over all contiguous windows of length `N` and a rolling hash of
+/// each window.
+///
+/// This struct is created by the [`rolling_windows`] method on iterators. See
+/// its documentation for more.
+///
+/// [`rolling_windows`]: IterRollingWindows::rolling_windows
+#[cfg_attr(docsrs, doc(cfg(feature = "rolling_windows")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RollingWindows<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: ArrayWindows<I, N>,
+
+    /// The hash of the last window and its first element.
+    last: Option<(u64, I::Item)>,
+
+    /// The weight of the first element in a window, `BASE ^ (N - 1)`.
+    pow: u64,
+}
+
+impl<I, const N: usize> RollingWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone + Into<u64>,
+{
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.array_windows(),
+            last: None,
+            pow: (1..N).fold(1, |acc, _| acc.wrapping_mul(BASE)),
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for RollingWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone + Into<u64>,
+{
+    type Item = ([I::Item; N], u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.iter.next()?;
+        let hash = match self.last.take() {
+            Some((hash, first)) => {
+                // Remove the element that left the window and add the one
+                // that entered it.
+                let end: u64 = window[N - 1].clone().into();
+                hash.wrapping_sub(first.into().wrapping_mul(self.pow))
+                    .wrapping_mul(BASE)
+                    .wrapping_add(end)
+            }
+            None => rolling_hash(&window),
+        };
+        self.last = Some((hash, window[0].clone()));
+        Some((window, hash))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for RollingWindows<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone + Into<u64>,
+{
+}
+
+impl<I, const N: usize> FusedIterator for RollingWindows<I, N>
+where
+    I: FusedIterator + Clone,
+    I::Item: Clone + Into<u64>,
+{
+}
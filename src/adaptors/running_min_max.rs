@@ -0,0 +1,262 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+/// An iterator that yields the minimum element seen so far.
+///
+/// This struct is created by the [`running_min`] method on iterators. See its
+/// documentation for more.
+///
+/// [`running_min`]: crate::IterMinMax::running_min
+#[cfg_attr(docsrs, doc(cfg(feature = "min_max")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningMin<I, F>
+where
+    I: Iterator,
+{
+    iter: I,
+    min: Option<I::Item>,
+    compare: F,
+}
+
+/// An iterator that yields the maximum element seen so far.
+///
+/// This struct is created by the [`running_max`] method on iterators. See its
+/// documentation for more.
+///
+/// [`running_max`]: crate::IterMinMax::running_max
+#[cfg_attr(docsrs, doc(cfg(feature = "min_max")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningMax<I, F>
+where
+    I: Iterator,
+{
+    iter: I,
+    max: Option<I::Item>,
+    compare: F,
+}
+
+/// An iterator that yields the minimum and maximum element seen so far.
+///
+/// This struct is created by the [`running_min_max`] method on iterators. See
+/// its documentation for more.
+///
+/// [`running_min_max`]: crate::IterMinMax::running_min_max
+#[cfg_attr(docsrs, doc(cfg(feature = "min_max")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunningMinMax<I, F>
+where
+    I: Iterator,
+{
+    iter: I,
+    min_max: Option<(I::Item, I::Item)>,
+    compare: F,
+}
+
+impl<I, F> RunningMin<I, F>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, compare: F) -> Self {
+        Self {
+            iter,
+            min: None,
+            compare,
+        }
+    }
+}
+
+impl<I, F> RunningMax<I, F>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, compare: F) -> Self {
+        Self {
+            iter,
+            max: None,
+            compare,
+        }
+    }
+}
+
+impl<I, F> RunningMinMax<I, F>
+where
+    I: Iterator,
+{
+    pub(crate) fn new(iter: I, compare: F) -> Self {
+        Self {
+            iter,
+            min_max: None,
+            compare,
+        }
+    }
+}
+
+impl<I, F> Debug for RunningMin<I, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningMin")
+            .field("iter", &self.iter)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<I, F> Debug for RunningMax<I, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningMax")
+            .field("iter", &self.iter)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<I, F> Debug for RunningMinMax<I, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunningMinMax")
+            .field("iter", &self.iter)
+            .field("min_max", &self.min_max)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for RunningMin<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        match self.min {
+            Some(ref min) if (self.compare)(&item, min) != Ordering::Less => {}
+            _ => self.min = Some(item),
+        }
+        self.min.clone()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> Iterator for RunningMax<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        match self.max {
+            Some(ref max) if (self.compare)(&item, max) != Ordering::Greater => {}
+            _ => self.max = Some(item),
+        }
+        self.max.clone()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> Iterator for RunningMinMax<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        match self.min_max {
+            Some((ref mut min, ref mut max)) => {
+                if (self.compare)(&item, min) == Ordering::Less {
+                    *min = item;
+                } else if (self.compare)(&item, max) == Ordering::Greater {
+                    *max = item;
+                }
+            }
+            None => self.min_max = Some((item.clone(), item)),
+        }
+        self.min_max.clone()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for RunningMin<I, F>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+impl<I, F> ExactSizeIterator for RunningMax<I, F>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+impl<I, F> ExactSizeIterator for RunningMinMax<I, F>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+impl<I, F> FusedIterator for RunningMin<I, F>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+impl<I, F> FusedIterator for RunningMax<I, F>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+impl<I, F> FusedIterator for RunningMinMax<I, F>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
@@ -0,0 +1,139 @@
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`split_when`] method for iterators.
+///
+/// [`split_when`]: IterSplitWhen::split_when
+#[cfg_attr(docsrs, doc(cfg(feature = "split_when")))]
+pub trait IterSplitWhen: Iterator {
+    /// Returns an iterator over segments of the iterator, starting a new
+    /// segment between every pair of adjacent elements for which the given
+    /// predicate returns `true`.
+    ///
+    /// The predicate is called with the previous and the current element, in
+    /// the same way as a window returned by [`array_windows::<2>`]. Every
+    /// segment is non-empty and the segments concatenated together are equal
+    /// to the original iterator.
+    ///
+    /// # Examples
+    ///
+    /// Segment a time series at large jumps.
+    ///
+    /// ```
+    /// use itermore::IterSplitWhen;
+    ///
+    /// let mut iter = [1, 2, 10, 11, 3]
+    ///     .into_iter()
+    ///     .split_when(|a: &i32, b: &i32| (a - b).abs() > 5);
+    /// assert_eq!(iter.next(), Some(vec![1, 2]));
+    /// assert_eq!(iter.next(), Some(vec![10, 11]));
+    /// assert_eq!(iter.next(), Some(vec![3]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows::<2>`]: crate::IterArrayWindows::array_windows
+    #[inline]
+    fn split_when<F>(self, pred: F) -> SplitWhen<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        SplitWhen::new(self, pred)
+    }
+}
+
+impl<I: ?Sized> IterSplitWhen for I where I: Iterator {}
+
+/// An iterator over segments of an iterator split between adjacent elements
+/// matching a predicate.
+///
+/// This struct is created by the [`split_when`] method on iterators. See its
+/// documentation for more.
+///
+/// [`split_when`]: IterSplitWhen::split_when
+#[cfg_attr(docsrs, doc(cfg(feature = "split_when")))]
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitWhen<I, F>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The first element of the next segment.
+    next: Option<I::Item>,
+
+    pred: F,
+}
+
+impl<I, F> SplitWhen<I, F>
+where
+    I: Iterator,
+{
+    fn new(iter: I, pred: F) -> Self {
+        Self {
+            iter,
+            next: None,
+            pred,
+        }
+    }
+}
+
+impl<I, F> Debug for SplitWhen<I, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWhen")
+            .field("iter", &self.iter)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for SplitWhen<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.next.take() {
+            Some(first) => first,
+            None => self.iter.next()?,
+        };
+        let mut segment = vec![first];
+        for item in self.iter.by_ref() {
+            // The segment is never empty so there is always a last element.
+            let prev = segment.last().unwrap();
+            if (self.pred)(prev, &item) {
+                self.next = Some(item);
+                break;
+            }
+            segment.push(item);
+        }
+        Some(segment)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.next.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        let lower = match lower.saturating_add(extra) {
+            0 => 0,
+            _ => 1,
+        };
+        (lower, upper.and_then(|upper| upper.checked_add(extra)))
+    }
+}
+
+impl<I, F> FusedIterator for SplitWhen<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+}
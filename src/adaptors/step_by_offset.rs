@@ -0,0 +1,100 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`step_by_offset`] method for
+/// iterators.
+///
+/// [`step_by_offset`]: IterStepByOffset::step_by_offset
+#[cfg_attr(docsrs, doc(cfg(feature = "step_by_offset")))]
+pub trait IterStepByOffset: Iterator {
+    /// Returns an iterator that skips the first `offset` elements and then
+    /// yields every `step`-th element.
+    ///
+    /// This is equivalent to `.skip(offset).step_by(step)`.
+    ///
+    /// # Panics
+    ///
+    /// If called with `step = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterStepByOffset;
+    ///
+    /// let v: Vec<_> = (0..10).step_by_offset(3, 1).collect();
+    /// assert_eq!(v, [1, 4, 7]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn step_by_offset(self, step: usize, offset: usize) -> StepByOffset<Self>
+    where
+        Self: Sized,
+    {
+        StepByOffset::new(self, step, offset)
+    }
+}
+
+impl<I: ?Sized> IterStepByOffset for I where I: Iterator {}
+
+/// An iterator that skips a number of elements and then yields every `step`-th
+/// element.
+///
+/// This struct is created by the [`step_by_offset`] method on iterators. See
+/// its documentation for more.
+///
+/// [`step_by_offset`]: IterStepByOffset::step_by_offset
+#[cfg_attr(docsrs, doc(cfg(feature = "step_by_offset")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct StepByOffset<I> {
+    iter: I,
+    step: usize,
+
+    /// The number of elements to skip before the next element, this is the
+    /// offset at first and then `step - 1`.
+    skip: usize,
+}
+
+impl<I> StepByOffset<I> {
+    #[track_caller]
+    fn new(iter: I, step: usize, offset: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        Self {
+            iter,
+            step,
+            skip: offset,
+        }
+    }
+
+    /// Returns the number of elements yielded if the underlying iterator has
+    /// `n` elements left.
+    fn len_for(&self, n: usize) -> usize {
+        match n.checked_sub(self.skip) {
+            Some(0) | None => 0,
+            Some(n) => (n - 1) / self.step + 1,
+        }
+    }
+}
+
+impl<I> Iterator for StepByOffset<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let skip = self.skip;
+        self.skip = self.step - 1;
+        self.iter.nth(skip)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (self.len_for(lower), upper.map(|n| self.len_for(n)))
+    }
+}
+
+impl<I> ExactSizeIterator for StepByOffset<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for StepByOffset<I> where I: FusedIterator {}
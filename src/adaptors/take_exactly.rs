@@ -0,0 +1,237 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`take_exactly`] method and friends
+/// for iterators.
+///
+/// [`take_exactly`]: IterTakeExactly::take_exactly
+#[cfg_attr(docsrs, doc(cfg(feature = "take_exactly")))]
+pub trait IterTakeExactly: Iterator {
+    /// Returns an iterator that yields exactly `n` elements.
+    ///
+    /// Unlike [`take`] this adaptor does not silently yield fewer elements if
+    /// the underlying iterator is too short.
+    ///
+    /// # Panics
+    ///
+    /// The returned iterator panics when it is advanced and the underlying
+    /// iterator is exhausted before `n` elements have been yielded. See
+    /// [`try_take_exactly`] for a non-panicking version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterTakeExactly;
+    ///
+    /// let v: Vec<_> = (1..10).take_exactly(3).collect();
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use itermore::IterTakeExactly;
+    ///
+    /// let v: Vec<_> = (1..3).take_exactly(3).collect(); // panics!
+    /// ```
+    ///
+    /// [`take`]: Iterator::take
+    /// [`try_take_exactly`]: IterTakeExactly::try_take_exactly
+    #[inline]
+    fn take_exactly(self, n: usize) -> TakeExactly<Self>
+    where
+        Self: Sized,
+    {
+        TakeExactly::new(self, n)
+    }
+
+    /// Returns an iterator that yields exactly `n` elements or an error if the
+    /// underlying iterator is too short.
+    ///
+    /// Each element is wrapped in `Ok`. If the underlying iterator is
+    /// exhausted before `n` elements have been yielded then a single
+    /// [`ShortError`] is yielded and the iterator ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterTakeExactly;
+    ///
+    /// let r: Result<Vec<_>, _> = (1..10).try_take_exactly(3).collect();
+    /// assert_eq!(r.unwrap(), [1, 2, 3]);
+    ///
+    /// let r: Result<Vec<_>, _> = (1..3).try_take_exactly(3).collect();
+    /// let err = r.unwrap_err();
+    /// assert_eq!(err.expected(), 3);
+    /// assert_eq!(err.actual(), 2);
+    /// ```
+    #[inline]
+    fn try_take_exactly(self, n: usize) -> TryTakeExactly<Self>
+    where
+        Self: Sized,
+    {
+        TryTakeExactly::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterTakeExactly for I where I: Iterator {}
+
+/// An iterator that yields exactly `n` elements.
+///
+/// This struct is created by the [`take_exactly`] method on iterators. See its
+/// documentation for more.
+///
+/// [`take_exactly`]: IterTakeExactly::take_exactly
+#[cfg_attr(docsrs, doc(cfg(feature = "take_exactly")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeExactly<I> {
+    iter: I,
+    n: usize,
+    taken: usize,
+}
+
+/// An iterator that yields exactly `n` elements or an error.
+///
+/// This struct is created by the [`try_take_exactly`] method on iterators. See
+/// its documentation for more.
+///
+/// [`try_take_exactly`]: IterTakeExactly::try_take_exactly
+#[cfg_attr(docsrs, doc(cfg(feature = "take_exactly")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryTakeExactly<I> {
+    iter: I,
+    n: usize,
+    taken: usize,
+}
+
+/// The error yielded by [`try_take_exactly`] when the underlying iterator is
+/// too short.
+///
+/// [`try_take_exactly`]: IterTakeExactly::try_take_exactly
+#[cfg_attr(docsrs, doc(cfg(feature = "take_exactly")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShortError {
+    expected: usize,
+    actual: usize,
+}
+
+impl<I> TakeExactly<I> {
+    fn new(iter: I, n: usize) -> Self {
+        Self { iter, n, taken: 0 }
+    }
+}
+
+impl<I> Iterator for TakeExactly<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.taken == self.n {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.taken += 1;
+                Some(item)
+            }
+            None => panic!(
+                "expected {} elements but the iterator was exhausted after {}",
+                self.n, self.taken
+            ),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.n - self.taken;
+        (n, Some(n))
+    }
+}
+
+impl<I> ExactSizeIterator for TakeExactly<I> where I: Iterator {}
+
+impl<I> FusedIterator for TakeExactly<I> where I: Iterator {}
+
+impl<I> TryTakeExactly<I> {
+    fn new(iter: I, n: usize) -> Self {
+        Self { iter, n, taken: 0 }
+    }
+}
+
+impl<I> Iterator for TryTakeExactly<I>
+where
+    I: Iterator,
+{
+    type Item = Result<I::Item, ShortError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.taken == self.n {
+            return None;
+        }
+        match self.iter.next() {
+            Some(item) => {
+                self.taken += 1;
+                Some(Ok(item))
+            }
+            None => {
+                let err = ShortError {
+                    expected: self.n,
+                    actual: self.taken,
+                };
+                self.n = self.taken;
+                Some(Err(err))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.n - self.taken;
+        if n == 0 {
+            return (0, Some(0));
+        }
+        // If the underlying iterator is too short then one extra element, the
+        // error, is yielded after its elements.
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.saturating_add(1).min(n);
+        let upper = match upper {
+            Some(upper) => upper.saturating_add(1).min(n),
+            None => n,
+        };
+        (lower, Some(upper))
+    }
+}
+
+impl<I> FusedIterator for TryTakeExactly<I> where I: Iterator {}
+
+impl ShortError {
+    /// Returns the number of elements that were expected.
+    #[inline]
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// Returns the number of elements the iterator actually had.
+    #[inline]
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for ShortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} elements but the iterator was exhausted after {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "take_exactly", feature = "std"))))]
+impl std::error::Error for ShortError {}
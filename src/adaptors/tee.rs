@@ -0,0 +1,158 @@
+use core::cell::RefCell;
+use core::fmt;
+use core::fmt::Debug;
+use core::iter::FusedIterator;
+
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// An extension trait that provides the [`tee`] method for iterators.
+///
+/// [`tee`]: IterTee::tee
+#[cfg_attr(docsrs, doc(cfg(feature = "tee")))]
+pub trait IterTee: Iterator {
+    /// Splits the iterator into two iterators that both yield all the elements
+    /// of the original iterator.
+    ///
+    /// The two halves can be advanced independently. Only the elements that
+    /// one half has yielded but the other half has not yet yielded are
+    /// buffered, so if both halves are advanced at the same rate then the
+    /// buffer stays small.
+    ///
+    /// This adaptor clones the iterator elements so that they can be yielded by
+    /// both halves, this makes this it most suited for iterators of references
+    /// and other values that are cheap to clone or copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterTee;
+    ///
+    /// let (a, b) = (1..4).tee();
+    /// let v: Vec<_> = a.zip(b.skip(1)).collect();
+    /// assert_eq!(v, [(1, 2), (2, 3)]);
+    /// ```
+    #[inline]
+    fn tee(self) -> (Tee<Self>, Tee<Self>)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Tee::new(self)
+    }
+}
+
+impl<I: ?Sized> IterTee for I where I: Iterator {}
+
+/// One half of an iterator split into two.
+///
+/// This struct is created by the [`tee`] method on iterators. See its
+/// documentation for more.
+///
+/// [`tee`]: IterTee::tee
+#[cfg_attr(docsrs, doc(cfg(feature = "tee")))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Tee<I>
+where
+    I: Iterator,
+{
+    shared: Rc<RefCell<Shared<I>>>,
+    id: bool,
+}
+
+/// The state shared between both halves.
+#[derive(Debug)]
+struct Shared<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// Elements yielded by one half that the other half has not yet yielded.
+    buf: VecDeque<I::Item>,
+
+    /// The id of the half that the buffered elements are waiting for.
+    owner: bool,
+}
+
+impl<I> Tee<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> (Self, Self) {
+        let shared = Rc::new(RefCell::new(Shared {
+            iter,
+            buf: VecDeque::new(),
+            owner: false,
+        }));
+        let a = Self {
+            shared: shared.clone(),
+            id: false,
+        };
+        let b = Self { shared, id: true };
+        (a, b)
+    }
+}
+
+impl<I> Debug for Tee<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("shared", &self.shared)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<I> Iterator for Tee<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut shared = self.shared.borrow_mut();
+        if shared.owner == self.id {
+            if let Some(item) = shared.buf.pop_front() {
+                return Some(item);
+            }
+        }
+        let item = shared.iter.next()?;
+        shared.buf.push_back(item.clone());
+        shared.owner = !self.id;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let extra = match shared.owner == self.id {
+            true => shared.buf.len(),
+            false => 0,
+        };
+        let (lower, upper) = shared.iter.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for Tee<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for Tee<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
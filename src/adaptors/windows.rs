@@ -0,0 +1,138 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`windows`] method for iterators.
+///
+/// [`windows`]: IterWindows::windows
+#[cfg_attr(docsrs, doc(cfg(feature = "windows")))]
+pub trait IterWindows: Iterator {
+    /// Returns an iterator over all contiguous windows of length `n`.
+    ///
+    /// This is the same as [`array_windows`] except that the window size is
+    /// given at runtime and each window is collected into a [`Vec`].
+    ///
+    /// The windows overlap. If the iterator is shorter than `n`, the iterator
+    /// returns no values.
+    ///
+    /// # Panics
+    ///
+    /// If called with `n = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterWindows;
+    ///
+    /// let mut iter = "rust".chars().windows(2);
+    /// assert_eq!(iter.next(), Some(vec!['r', 'u']));
+    /// assert_eq!(iter.next(), Some(vec!['u', 's']));
+    /// assert_eq!(iter.next(), Some(vec!['s', 't']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows`]: crate::IterArrayWindows::array_windows
+    #[inline]
+    fn windows(self, n: usize) -> Windows<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Windows::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterWindows for I where I: Iterator {}
+
+/// An iterator over all contiguous windows of length `n`.
+///
+/// This struct is created by the [`windows`] method on iterators. See its
+/// documentation for more.
+///
+/// [`windows`]: IterWindows::windows
+#[cfg_attr(docsrs, doc(cfg(feature = "windows")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    n: usize,
+    last: Option<Vec<I::Item>>,
+}
+
+impl<I> Windows<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    #[track_caller]
+    fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "window size must be non-zero");
+        Self {
+            iter,
+            n,
+            last: None,
+        }
+    }
+}
+
+impl<I> Iterator for Windows<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter, n, last } = self;
+
+        match last {
+            Some(last) => {
+                let item = iter.next()?;
+                last.rotate_left(1);
+                if let Some(end) = last.last_mut() {
+                    *end = item;
+                }
+                Some(last.clone())
+            }
+            None => {
+                let mut tmp = Vec::with_capacity(*n);
+                tmp.extend(iter.by_ref().take(*n));
+                if tmp.len() < *n {
+                    return None;
+                }
+                *last = Some(tmp.clone());
+                Some(tmp)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.last {
+            Some(_) => (lower, upper),
+            None => (
+                lower.saturating_sub(self.n - 1),
+                upper.map(|n| n.saturating_sub(self.n - 1)),
+            ),
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for Windows<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for Windows<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
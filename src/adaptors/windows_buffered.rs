@@ -0,0 +1,105 @@
+use alloc::collections::VecDeque;
+
+/// An extension trait that provides the [`windows_buffered`] method for
+/// iterators.
+///
+/// [`windows_buffered`]: IterWindowsBuffered::windows_buffered
+#[cfg_attr(docsrs, doc(cfg(feature = "windows_buffered")))]
+pub trait IterWindowsBuffered: Iterator {
+    /// Returns an adaptor over all contiguous windows of length `n` that
+    /// yields slices into a single reused buffer.
+    ///
+    /// The windows overlap. If the iterator is shorter than `n`, the adaptor
+    /// returns no values.
+    ///
+    /// Unlike [`array_windows`] the elements are not cloned for each window,
+    /// instead a buffer of the last `n` elements is kept and advanced by one
+    /// element each step. Since each window borrows the buffer, the returned
+    /// adaptor can not implement [`Iterator`]. Instead windows are retrieved
+    /// using [`next_ref`].
+    ///
+    /// # Panics
+    ///
+    /// If called with `n = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterWindowsBuffered;
+    ///
+    /// let mut iter = "rust".chars().windows_buffered(2);
+    /// assert_eq!(iter.next_ref(), Some(&['r', 'u'][..]));
+    /// assert_eq!(iter.next_ref(), Some(&['u', 's'][..]));
+    /// assert_eq!(iter.next_ref(), Some(&['s', 't'][..]));
+    /// assert_eq!(iter.next_ref(), None);
+    /// ```
+    ///
+    /// [`array_windows`]: crate::IterArrayWindows::array_windows
+    /// [`next_ref`]: WindowsBuffered::next_ref
+    #[inline]
+    fn windows_buffered(self, n: usize) -> WindowsBuffered<Self>
+    where
+        Self: Sized,
+    {
+        WindowsBuffered::new(self, n)
+    }
+}
+
+impl<I: ?Sized> IterWindowsBuffered for I where I: Iterator {}
+
+/// An adaptor over all contiguous windows of length `n` that yields slices
+/// into a single reused buffer.
+///
+/// This struct is created by the [`windows_buffered`] method on iterators.
+/// See its documentation for more.
+///
+/// [`windows_buffered`]: IterWindowsBuffered::windows_buffered
+#[cfg_attr(docsrs, doc(cfg(feature = "windows_buffered")))]
+#[derive(Debug, Clone)]
+#[must_use = "adaptors are lazy and do nothing unless `next_ref` is called"]
+pub struct WindowsBuffered<I>
+where
+    I: Iterator,
+{
+    iter: I,
+    buf: VecDeque<I::Item>,
+    n: usize,
+}
+
+impl<I> WindowsBuffered<I>
+where
+    I: Iterator,
+{
+    #[track_caller]
+    fn new(iter: I, n: usize) -> Self {
+        assert!(n != 0, "window size must be non-zero");
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            n,
+        }
+    }
+
+    /// Advances the adaptor and returns the next window.
+    ///
+    /// The returned slice borrows the adaptor so it must be dropped before the
+    /// next window can be retrieved.
+    pub fn next_ref(&mut self) -> Option<&[I::Item]> {
+        if self.buf.len() < self.n {
+            // Leave space for the buffer to advance before it wraps around
+            // and needs to be made contiguous again.
+            self.buf
+                .reserve((2 * self.n).saturating_sub(self.buf.len()));
+            while self.buf.len() < self.n {
+                self.buf.push_back(self.iter.next()?);
+            }
+        } else {
+            let item = self.iter.next()?;
+            self.buf.pop_front();
+            self.buf.push_back(item);
+        }
+        Some(self.buf.make_contiguous())
+    }
+}
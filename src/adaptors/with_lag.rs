@@ -0,0 +1,122 @@
+use core::iter::FusedIterator;
+
+use alloc::collections::VecDeque;
+
+/// An extension trait that provides the [`with_lag`] method for iterators.
+///
+/// [`with_lag`]: IterWithLag::with_lag
+#[cfg_attr(docsrs, doc(cfg(feature = "with_lag")))]
+pub trait IterWithLag: Iterator {
+    /// Returns an iterator that pairs each element with the `N` elements that
+    /// came before it.
+    ///
+    /// The previous elements are yielded in the order they appeared in the
+    /// iterator. For the first `N` elements there are not enough previous
+    /// elements so `None` is yielded instead.
+    ///
+    /// This adaptor clones the iterator elements so that they can be part of
+    /// successive windows, this makes this it most suited for iterators of
+    /// references and other values that are cheap to clone or copy.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterWithLag;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().with_lag::<2>();
+    /// assert_eq!(iter.next(), Some((1, None)));
+    /// assert_eq!(iter.next(), Some((2, None)));
+    /// assert_eq!(iter.next(), Some((3, Some([1, 2]))));
+    /// assert_eq!(iter.next(), Some((4, Some([2, 3]))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn with_lag<const N: usize>(self) -> WithLag<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        WithLag::new(self)
+    }
+}
+
+impl<I: ?Sized> IterWithLag for I where I: Iterator {}
+
+/// An iterator that pairs each element with the `N` elements that came before
+/// it.
+///
+/// This struct is created by the [`with_lag`] method on iterators. See its
+/// documentation for more.
+///
+/// [`with_lag`]: IterWithLag::with_lag
+#[cfg_attr(docsrs, doc(cfg(feature = "with_lag")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithLag<I, const N: usize>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The last `N` elements yielded, oldest first.
+    buf: VecDeque<I::Item>,
+}
+
+impl<I, const N: usize> WithLag<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    fn new(iter: I) -> Self {
+        assert!(N != 0, "lag size must be non-zero");
+        Self {
+            iter,
+            buf: VecDeque::with_capacity(N),
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for WithLag<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, Option<[I::Item; N]>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let lag = if self.buf.len() == N {
+            let lag = arrays::from_iter(self.buf.iter().cloned()).ok();
+            self.buf.pop_front();
+            lag
+        } else {
+            None
+        };
+        self.buf.push_back(item.clone());
+        Some((item, lag))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for WithLag<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> FusedIterator for WithLag<I, N>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,113 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`with_next`] method for iterators.
+///
+/// [`with_next`]: IterWithNext::with_next
+#[cfg_attr(docsrs, doc(cfg(feature = "with_next")))]
+pub trait IterWithNext: Iterator {
+    /// Returns an iterator that pairs each element with the element that comes
+    /// after it.
+    ///
+    /// The last element is paired with `None`. Unlike
+    /// [`array_windows::<2>`] this yields one item for every element of the
+    /// iterator.
+    ///
+    /// This adaptor clones each element once so that it can be yielded as the
+    /// next element and then again as the current element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterWithNext;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().with_next();
+    /// assert_eq!(iter.next(), Some((1, Some(2))));
+    /// assert_eq!(iter.next(), Some((2, Some(3))));
+    /// assert_eq!(iter.next(), Some((3, None)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows::<2>`]: crate::IterArrayWindows::array_windows
+    #[inline]
+    fn with_next(self) -> WithNext<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        WithNext::new(self)
+    }
+}
+
+impl<I: ?Sized> IterWithNext for I where I: Iterator {}
+
+/// An iterator that pairs each element with the element that comes after it.
+///
+/// This struct is created by the [`with_next`] method on iterators. See its
+/// documentation for more.
+///
+/// [`with_next`]: IterWithNext::with_next
+#[cfg_attr(docsrs, doc(cfg(feature = "with_next")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithNext<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The element after the last yielded element, already taken from the
+    /// underlying iterator.
+    peeked: Option<I::Item>,
+}
+
+impl<I> WithNext<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> Self {
+        Self { iter, peeked: None }
+    }
+}
+
+impl<I> Iterator for WithNext<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, Option<I::Item>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = match self.peeked.take() {
+            Some(curr) => curr,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next();
+        self.peeked = next.clone();
+        Some((curr, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let extra = self.peeked.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(extra),
+            upper.and_then(|upper| upper.checked_add(extra)),
+        )
+    }
+}
+
+impl<I> ExactSizeIterator for WithNext<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for WithNext<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
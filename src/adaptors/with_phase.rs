@@ -0,0 +1,94 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`with_phase`] method for iterators.
+///
+/// [`with_phase`]: IterWithPhase::with_phase
+#[cfg_attr(docsrs, doc(cfg(feature = "with_phase")))]
+pub trait IterWithPhase: Iterator {
+    /// Returns an iterator that pairs each element with its index modulo the
+    /// given period.
+    ///
+    /// This is equivalent to `(0..period).cycle().zip(self)`.
+    ///
+    /// # Panics
+    ///
+    /// If called with `period = 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterWithPhase;
+    ///
+    /// let v: Vec<_> = "abcde".chars().with_phase(2).collect();
+    /// assert_eq!(v, [(0, 'a'), (1, 'b'), (0, 'c'), (1, 'd'), (0, 'e')]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn with_phase(self, period: usize) -> WithPhase<Self>
+    where
+        Self: Sized,
+    {
+        WithPhase::new(self, period)
+    }
+}
+
+impl<I: ?Sized> IterWithPhase for I where I: Iterator {}
+
+/// An iterator that pairs each element with its index modulo a period.
+///
+/// This struct is created by the [`with_phase`] method on iterators. See its
+/// documentation for more.
+///
+/// [`with_phase`]: IterWithPhase::with_phase
+#[cfg_attr(docsrs, doc(cfg(feature = "with_phase")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithPhase<I> {
+    iter: I,
+    period: usize,
+    phase: usize,
+}
+
+impl<I> WithPhase<I> {
+    #[track_caller]
+    fn new(iter: I, period: usize) -> Self {
+        assert!(period != 0, "period must be non-zero");
+        Self {
+            iter,
+            period,
+            phase: 0,
+        }
+    }
+}
+
+impl<I> Iterator for WithPhase<I>
+where
+    I: Iterator,
+{
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let phase = self.phase;
+        self.phase += 1;
+        if self.phase == self.period {
+            self.phase = 0;
+        }
+        Some((phase, item))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I> ExactSizeIterator for WithPhase<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for WithPhase<I> where I: FusedIterator {}
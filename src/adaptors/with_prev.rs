@@ -0,0 +1,109 @@
+use core::iter::FusedIterator;
+
+/// An extension trait that provides the [`with_prev`] method for iterators.
+///
+/// [`with_prev`]: IterWithPrev::with_prev
+#[cfg_attr(docsrs, doc(cfg(feature = "with_prev")))]
+pub trait IterWithPrev: Iterator {
+    /// Returns an iterator that pairs each element with the element that came
+    /// before it.
+    ///
+    /// The first element is paired with `None`. Unlike
+    /// [`array_windows::<2>`] this yields one item for every element of the
+    /// iterator. See also [`with_next`].
+    ///
+    /// This adaptor clones each element once so that it can be yielded as the
+    /// current element and then again as the previous element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterWithPrev;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().with_prev();
+    /// assert_eq!(iter.next(), Some((None, 1)));
+    /// assert_eq!(iter.next(), Some((Some(1), 2)));
+    /// assert_eq!(iter.next(), Some((Some(2), 3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`array_windows::<2>`]: crate::IterArrayWindows::array_windows
+    /// [`with_next`]: crate::IterWithNext::with_next
+    #[inline]
+    fn with_prev(self) -> WithPrev<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        WithPrev::new(self)
+    }
+}
+
+impl<I: ?Sized> IterWithPrev for I where I: Iterator {}
+
+/// An iterator that pairs each element with the element that came before it.
+///
+/// This struct is created by the [`with_prev`] method on iterators. See its
+/// documentation for more.
+///
+/// [`with_prev`]: IterWithPrev::with_prev
+#[cfg_attr(docsrs, doc(cfg(feature = "with_prev")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithPrev<I>
+where
+    I: Iterator,
+{
+    iter: I,
+
+    /// The last yielded element.
+    prev: Option<I::Item>,
+}
+
+impl<I> WithPrev<I>
+where
+    I: Iterator,
+{
+    fn new(iter: I) -> Self {
+        Self { iter, prev: None }
+    }
+}
+
+impl<I> Iterator for WithPrev<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (Option<I::Item>, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = self.iter.next()?;
+        let prev = self.prev.replace(curr.clone());
+        Some((prev, curr))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+}
+
+impl<I> ExactSizeIterator for WithPrev<I>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I> FusedIterator for WithPrev<I>
+where
+    I: FusedIterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,149 @@
+use core::cmp;
+use core::iter::{Fuse, FusedIterator};
+
+/// An extension trait that provides the [`zip_longest`] method for
+/// iterators.
+///
+/// [`zip_longest`]: IterZipLongest::zip_longest
+#[cfg_attr(docsrs, doc(cfg(feature = "zip_longest")))]
+pub trait IterZipLongest: Iterator {
+    /// Returns an iterator that zips two iterators together, continuing
+    /// until both are exhausted.
+    ///
+    /// Unlike [`zip`] which stops as soon as either iterator is exhausted,
+    /// this yields [`EitherOrBoth::Both`] while both iterators yield elements
+    /// and then [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`] for the rest
+    /// of the longer iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::{EitherOrBoth, IterZipLongest};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().zip_longest("ab".chars());
+    /// assert_eq!(iter.next(), Some(EitherOrBoth::Both(1, 'a')));
+    /// assert_eq!(iter.next(), Some(EitherOrBoth::Both(2, 'b')));
+    /// assert_eq!(iter.next(), Some(EitherOrBoth::Left(3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`zip`]: Iterator::zip
+    #[inline]
+    fn zip_longest<J>(self, other: J) -> ZipLongest<Self, J::IntoIter>
+    where
+        Self: Sized,
+        J: IntoIterator,
+    {
+        ZipLongest::new(self, other.into_iter())
+    }
+}
+
+impl<I: ?Sized> IterZipLongest for I where I: Iterator {}
+
+/// A value that is either a left value, a right value, or both.
+///
+/// This enum is yielded by the [`zip_longest`] adaptor.
+///
+/// [`zip_longest`]: IterZipLongest::zip_longest
+#[cfg_attr(docsrs, doc(cfg(feature = "zip_longest")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both values are present.
+    Both(A, B),
+    /// Only the left value is present.
+    Left(A),
+    /// Only the right value is present.
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns the left value, if present.
+    #[inline]
+    pub fn left(self) -> Option<A> {
+        match self {
+            Self::Both(a, _) | Self::Left(a) => Some(a),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if present.
+    #[inline]
+    pub fn right(self) -> Option<B> {
+        match self {
+            Self::Both(_, b) | Self::Right(b) => Some(b),
+            Self::Left(_) => None,
+        }
+    }
+}
+
+/// An iterator that zips two iterators together, continuing until both are
+/// exhausted.
+///
+/// This struct is created by the [`zip_longest`] method on iterators. See its
+/// documentation for more.
+///
+/// [`zip_longest`]: IterZipLongest::zip_longest
+#[cfg_attr(docsrs, doc(cfg(feature = "zip_longest")))]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipLongest<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+}
+
+impl<I, J> ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+    fn new(a: I, b: J) -> Self {
+        Self {
+            a: a.fuse(),
+            b: b.fuse(),
+        }
+    }
+}
+
+impl<I, J> Iterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let lower = cmp::max(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(cmp::max(a, b)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<I, J> ExactSizeIterator for ZipLongest<I, J>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+{
+}
+
+impl<I, J> FusedIterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+}
@@ -43,16 +43,53 @@
 //!
 //! ## Methods
 //!
+//! - [`all_equal`] returns whether all the elements of the iterator are equal.
+//! - [`checked_sum`] and friends: Returns the sum or product of the elements
+//!   of an iterator, or `None` if it is empty.
 //! - [`collect_array`]: Collects an iterator into an array.
+//! - [`for_each_chunk`]: Calls a closure on each `N` elements of the iterator
+//!   at a time.
+//! - [`into_group_map`] and [`into_group_map_by`] collect the elements of an
+//!   iterator into a `HashMap` of groups by key.
+//! - [`is_ordered`] returns whether the elements of the iterator are sorted.
+//! - [`join`] and [`format_with`] format each element of the iterator and join
+//!   them together with a separator.
 //! - [`min_max`] and friends: Returns the minimum and maximum element of an
 //!   iterator.
+//! - [`most_common`] returns each distinct element of the iterator along with
+//!   the number of times it occurs, sorted by descending frequency.
 //! - [`next_chunk`]: Returns the next `N` elements of the iterator as an array.
+//! - [`partition_result`]: Partitions an iterator of [`Result`]s into the `Ok`
+//!   and `Err` values.
+//! - [`prefix_suffix_products`] returns the product of the elements before and
+//!   after each position of the iterator.
+//! - [`shuffled`] and [`sample`] return all the elements of the iterator in a
+//!   random order or a random sample of them.
 //! - [`sorted`] and friends: Returns a new iterator with all elements sorted.
+//! - [`sum_count`], [`mean`] and [`stats`] return the sum and number of
+//!   elements, the mean, or the count, mean and variance of an iterator in a
+//!   single pass.
 //!
+//! [`all_equal`]: IterAllEqual::all_equal
+//! [`checked_sum`]: IterReduce::checked_sum
 //! [`collect_array`]: IterCollectArray::collect_array
+//! [`for_each_chunk`]: IterForEachChunk::for_each_chunk
+//! [`format_with`]: IterFormat::format_with
+//! [`into_group_map_by`]: IterGroupMap::into_group_map_by
+//! [`into_group_map`]: IterGroupMap::into_group_map
+//! [`is_ordered`]: IterIsOrdered::is_ordered
+//! [`join`]: IterFormat::join
+//! [`mean`]: IterStats::mean
 //! [`min_max`]: IterMinMax::min_max
+//! [`most_common`]: IterMostCommon::most_common
 //! [`next_chunk`]: IterNextChunk::next_chunk
+//! [`partition_result`]: IterPartition::partition_result
+//! [`prefix_suffix_products`]: IterPrefixSuffixProducts::prefix_suffix_products
+//! [`sample`]: IterRandom::sample
+//! [`shuffled`]: IterRandom::shuffled
 //! [`sorted`]: IterSorted::sorted
+//! [`stats`]: IterStats::stats
+//! [`sum_count`]: IterStats::sum_count
 //!
 //! ## Adaptors
 //!
@@ -60,6 +97,10 @@
 //!   a time.
 //! - [`array_windows`] returns an iterator over all contiguous windows of
 //!   length `N`.
+//! - [`array_windows_min`] and [`array_windows_max`] return an iterator over
+//!   the minimum or maximum element of all contiguous windows of length `N`.
+//! - [`array_windows_dot`] returns an iterator over the dot product of all
+//!   contiguous windows of length `N` with a kernel.
 //! - [`array_combinations`] returns an iterator over `K` length combinations of
 //!   all the elements in the underlying iterator.
 //! - [`array_combinations_with_reps`] returns an iterator over `K` length
@@ -67,6 +108,12 @@
 //!   underlying iterator.
 //! - [`cartesian_product`] returns an iterator over the cartesian product of
 //!   the element sets of two iterators.
+//! - [`chunk_by_weight`] returns an iterator over chunks of the iterator whose
+//!   total weight does not exceed a maximum.
+//! - [`chunk_sums`] returns an iterator over the sum of every `N` elements of
+//!   the iterator.
+//! - [`chunks`] returns an iterator over `n` elements of the iterator at a
+//!   time.
 //! - [`circular_array_windows`] returns an iterator over all contiguous windows
 //!   of length `N` that wraps around at the end.
 //! - [`combinations`] returns an iterator over `k` length combinations of all
@@ -74,15 +121,108 @@
 //! - [`combinations_with_reps`] returns an iterator over `k` length
 //!   combinations with repetitions/replacements of all the elements in the
 //!   underlying iterator.
+//! - [`combinations_gray`] returns an iterator over `k` length combinations of
+//!   all the elements in the underlying iterator in revolving door order, where
+//!   each combination differs from the previous by one element.
+//! - [`cycle_n`] returns an iterator that repeats the entire iterator `n`
+//!   times.
+//! - [`dedup_with_count`] and [`run_length_decode`] return an iterator that
+//!   run-length encodes or decodes the iterator.
+//! - [`drop_last`] returns an iterator that yields all but the last `n`
+//!   elements of the underlying iterator.
+//! - [`fold_chunks`] returns an iterator that folds every `N` elements of the
+//!   iterator into a single value.
+//! - [`grid`] returns an iterator over all coordinates of a `D`-dimensional
+//!   grid spanned by the given ranges.
+//! - [`index_product`] returns an iterator over all coordinates of an
+//!   N-dimensional grid with the given dimensions.
+//! - [`map_ok`] and [`filter_ok`] return an iterator that maps or filters the
+//!   `Ok` values of an iterator of [`Result`]s.
+//! - [`memoize`] returns an iterator that caches its elements so that it can be
+//!   cheaply cloned and iterated multiple times.
+//! - [`overlapping_chunks`] returns an iterator over `N` elements of the
+//!   iterator at a time where each chunk overlaps with the previous one.
+//! - [`power_set`] returns an iterator over all the subsets of the elements in
+//!   the underlying iterator, and [`power_set_up_to`] over the subsets with at
+//!   most `max_k` elements.
+//! - [`repeat_each`] and [`repeat_each_n`] return an iterator that yields each
+//!   element a number of times in a row.
+//! - [`rolling_windows`] returns an iterator over all contiguous windows of
+//!   length `N` along with a rolling hash of each window.
+//! - [`running_min`], [`running_max`] and [`running_min_max`] return an
+//!   iterator over the minimum, maximum or both elements seen so far.
+//! - [`slice_circular_array_windows`] returns an iterator over all contiguous
+//!   windows of length `N` of a slice starting at an offset, that wraps around
+//!   at the end.
+//! - [`split_when`] returns an iterator over segments of the iterator, starting
+//!   a new segment whenever a predicate on adjacent elements returns `true`.
+//! - [`step_by_offset`] returns an iterator that skips a number of elements and
+//!   then yields every `step`-th element.
+//! - [`take_exactly`] and [`try_take_exactly`] return an iterator over exactly
+//!   `n` elements, panicking or yielding an error if the iterator is too short.
+//! - [`tee`] splits an iterator into two iterators that both yield all the
+//!   elements of the original iterator.
+//! - [`windows_buffered`] returns an adaptor over all contiguous windows of
+//!   length `n` that borrow from a single reused buffer.
+//! - [`windows`] returns an iterator over all contiguous windows of length `n`.
+//! - [`with_lag`] returns an iterator that pairs each element with the `N`
+//!   elements that came before it.
+//! - [`with_next`] returns an iterator that pairs each element with the element
+//!   that comes after it.
+//! - [`with_phase`] returns an iterator that pairs each element with its index
+//!   modulo a period.
+//! - [`with_prev`] returns an iterator that pairs each element with the element
+//!   that came before it.
+//! - [`zip_longest`] returns an iterator that zips two iterators together until
+//!   both are exhausted.
 //!
 //! [`array_chunks`]: IterArrayChunks::array_chunks
 //! [`array_combinations`]: IterArrayCombinations::array_combinations
 //! [`array_combinations_with_reps`]: IterArrayCombinationsWithReps::array_combinations_with_reps
+//! [`array_windows_dot`]: IterArrayWindowsDot::array_windows_dot
+//! [`array_windows_max`]: IterArrayWindowsMinMax::array_windows_max
+//! [`array_windows_min`]: IterArrayWindowsMinMax::array_windows_min
 //! [`array_windows`]: IterArrayWindows::array_windows
 //! [`cartesian_product`]: IterCartesianProduct::cartesian_product
+//! [`chunk_by_weight`]: IterChunkByWeight::chunk_by_weight
+//! [`chunk_sums`]: IterChunkSums::chunk_sums
+//! [`chunks`]: IterChunks::chunks
 //! [`circular_array_windows`]: IterCircularArrayWindows::circular_array_windows
+//! [`combinations_gray`]: IterCombinationsGray::combinations_gray
 //! [`combinations`]: IterCombinations::combinations
 //! [`combinations_with_reps`]: IterCombinationsWithReps::combinations_with_reps
+//! [`cycle_n`]: IterCycleN::cycle_n
+//! [`dedup_with_count`]: IterDedup::dedup_with_count
+//! [`drop_last`]: IterDropLast::drop_last
+//! [`filter_ok`]: IterMapOk::filter_ok
+//! [`fold_chunks`]: IterFoldChunks::fold_chunks
+//! [`grid`]: grid
+//! [`index_product`]: index_product
+//! [`map_ok`]: IterMapOk::map_ok
+//! [`memoize`]: IterMemoize::memoize
+//! [`overlapping_chunks`]: IterOverlappingChunks::overlapping_chunks
+//! [`power_set_up_to`]: IterPowerSet::power_set_up_to
+//! [`power_set`]: IterPowerSet::power_set
+//! [`repeat_each_n`]: IterRepeatEach::repeat_each_n
+//! [`repeat_each`]: IterRepeatEach::repeat_each
+//! [`rolling_windows`]: IterRollingWindows::rolling_windows
+//! [`run_length_decode`]: IterDedup::run_length_decode
+//! [`running_min`]: IterMinMax::running_min
+//! [`running_max`]: IterMinMax::running_max
+//! [`running_min_max`]: IterMinMax::running_min_max
+//! [`slice_circular_array_windows`]: slice_circular_array_windows
+//! [`split_when`]: IterSplitWhen::split_when
+//! [`step_by_offset`]: IterStepByOffset::step_by_offset
+//! [`take_exactly`]: IterTakeExactly::take_exactly
+//! [`tee`]: IterTee::tee
+//! [`try_take_exactly`]: IterTakeExactly::try_take_exactly
+//! [`windows_buffered`]: IterWindowsBuffered::windows_buffered
+//! [`windows`]: IterWindows::windows
+//! [`with_lag`]: IterWithLag::with_lag
+//! [`with_next`]: IterWithNext::with_next
+//! [`with_phase`]: IterWithPhase::with_phase
+//! [`with_prev`]: IterWithPrev::with_prev
+//! [`zip_longest`]: IterZipLongest::zip_longest
 
 #![warn(unsafe_op_in_unsafe_fn)]
 #![cfg_attr(not(feature = "alloc"), no_std)]
@@ -103,11 +243,19 @@ pub use crate::flatten_tuple::flatten_tuple;
 #[doc(hidden)]
 pub use core;
 
+#[cfg(feature = "all_equal")]
+pub use crate::xtraits::all_equal::IterAllEqual;
+
 #[cfg(feature = "array_chunks")]
-pub use crate::adaptors::array_chunks::{ArrayChunks, IterArrayChunks};
+pub use crate::adaptors::array_chunks::{
+    ArrayChunks, ArrayChunksByKey, ArrayChunksPadded, EnumerateArrayChunks, IterArrayChunks,
+};
 
 #[cfg(feature = "array_combinations")]
-pub use crate::adaptors::array_combinations::{ArrayCombinations, IterArrayCombinations};
+pub use crate::adaptors::array_combinations::{
+    ArrayCombinations, ArrayCombinationsBounded, ArrayCombinationsFiltered, ArrayIndexCombinations,
+    IterArrayCombinations,
+};
 
 #[cfg(feature = "array_combinations_with_reps")]
 pub use crate::adaptors::array_combinations_with_reps::{
@@ -115,32 +263,167 @@ pub use crate::adaptors::array_combinations_with_reps::{
 };
 
 #[cfg(feature = "array_windows")]
-pub use crate::adaptors::array_windows::{ArrayWindows, IterArrayWindows};
+pub use crate::adaptors::array_windows::{
+    ArrayWindows, GatedArrayWindows, IndexedArrayWindows, IterArrayWindows,
+};
+
+#[cfg(feature = "array_windows_dot")]
+pub use crate::adaptors::array_windows_dot::{ArrayWindowsDot, IterArrayWindowsDot};
 
+#[cfg(feature = "array_windows_min_max")]
+pub use crate::adaptors::array_windows_min_max::{
+    ArrayWindowsMax, ArrayWindowsMin, IterArrayWindowsMinMax,
+};
+
+#[cfg(all(feature = "cartesian_product", feature = "alloc"))]
+pub use crate::adaptors::cartesian_product::CartesianProductCollected;
 #[cfg(feature = "cartesian_product")]
-pub use crate::adaptors::cartesian_product::{CartesianProduct, IterCartesianProduct};
+pub use crate::adaptors::cartesian_product::{
+    CartesianProduct, CartesianProductRef, CartesianRows, IterCartesianProduct,
+};
+
+#[cfg(feature = "chunk_by_weight")]
+pub use crate::adaptors::chunk_by_weight::{ChunkByWeight, IterChunkByWeight};
+
+#[cfg(feature = "chunk_sums")]
+pub use crate::adaptors::chunk_sums::{ChunkSums, IterChunkSums};
+
+#[cfg(feature = "chunks")]
+pub use crate::adaptors::chunks::{Chunks, IterChunks};
 
 #[cfg(feature = "circular_array_windows")]
-pub use crate::adaptors::circular_array_windows::{CircularArrayWindows, IterCircularArrayWindows};
+pub use crate::adaptors::circular_array_windows::{
+    slice_circular_array_windows, CircularArrayWindows, CircularPairs, IterCircularArrayWindows,
+    RingWindows,
+};
 
 #[cfg(feature = "collect_array")]
 pub use crate::xtraits::collect_array::IterCollectArray;
 
 #[cfg(feature = "combinations")]
-pub use crate::adaptors::combinations::{Combinations, IterCombinations};
+pub use crate::adaptors::combinations::{
+    Combinations, CombinationsMask, CombinationsRef, IndexCombinations, IterCombinations,
+};
+
+#[cfg(any(feature = "array_combinations", feature = "combinations"))]
+pub use crate::adaptors::generic_combinations::CombinationsState;
+
+#[cfg(feature = "combinations_gray")]
+pub use crate::adaptors::combinations_gray::{CombinationsGray, IterCombinationsGray};
 
 #[cfg(feature = "combinations_with_reps")]
 pub use crate::adaptors::combinations_with_reps::{CombinationsWithReps, IterCombinationsWithReps};
 
+#[cfg(feature = "cycle_n")]
+pub use crate::adaptors::cycle_n::{CycleN, IterCycleN};
+
+#[cfg(feature = "dedup")]
+pub use crate::adaptors::dedup::{DedupWithCount, IterDedup, RunLengthDecode};
+
+#[cfg(feature = "drop_last")]
+pub use crate::adaptors::drop_last::{DropLast, IterDropLast};
+
+#[cfg(feature = "fold_chunks")]
+pub use crate::adaptors::fold_chunks::{FoldChunks, IterFoldChunks};
+
+#[cfg(feature = "for_each_chunk")]
+pub use crate::xtraits::for_each_chunk::IterForEachChunk;
+
+#[cfg(feature = "format")]
+pub use crate::xtraits::format::IterFormat;
+
+#[cfg(feature = "grid")]
+pub use crate::adaptors::grid::{grid, Grid};
+
+#[cfg(feature = "group_map")]
+pub use crate::xtraits::group_map::IterGroupMap;
+
+#[cfg(feature = "index_product")]
+pub use crate::adaptors::index_product::{index_product, IndexProduct};
+
+#[cfg(feature = "is_ordered")]
+pub use crate::xtraits::is_ordered::IterIsOrdered;
+
+#[cfg(feature = "map_ok")]
+pub use crate::adaptors::map_ok::{FilterOk, IterMapOk, MapOk};
+
+#[cfg(feature = "memoize")]
+pub use crate::adaptors::memoize::{IterMemoize, Memoized};
+
+#[cfg(feature = "min_max")]
+pub use crate::adaptors::running_min_max::{RunningMax, RunningMin, RunningMinMax};
 #[cfg(feature = "min_max")]
 pub use crate::xtraits::min_max::IterMinMax;
 
+#[cfg(feature = "most_common")]
+pub use crate::xtraits::most_common::IterMostCommon;
+
 #[cfg(feature = "next_chunk")]
 pub use crate::xtraits::next_chunk::IterNextChunk;
 
+#[cfg(feature = "overlapping_chunks")]
+pub use crate::adaptors::overlapping_chunks::{IterOverlappingChunks, OverlappingChunks};
+
+#[cfg(feature = "partition")]
+pub use crate::xtraits::partition::IterPartition;
+
+#[cfg(feature = "power_set")]
+pub use crate::adaptors::power_set::{IterPowerSet, PowerSet};
+
+#[cfg(feature = "prefix_suffix_products")]
+pub use crate::xtraits::prefix_suffix_products::IterPrefixSuffixProducts;
+
+#[cfg(feature = "rand")]
+pub use crate::xtraits::random::IterRandom;
+
+#[cfg(feature = "reduce")]
+pub use crate::xtraits::reduce::IterReduce;
+
+#[cfg(feature = "repeat_each")]
+pub use crate::adaptors::repeat_each::{IterRepeatEach, RepeatEach};
+
+#[cfg(feature = "rolling_windows")]
+pub use crate::adaptors::rolling_windows::{rolling_hash, IterRollingWindows, RollingWindows};
+
 #[cfg(feature = "sorted")]
 pub use crate::xtraits::sorted::IterSorted;
 
+#[cfg(feature = "split_when")]
+pub use crate::adaptors::split_when::{IterSplitWhen, SplitWhen};
+
+#[cfg(feature = "stats")]
+pub use crate::xtraits::stats::{IterStats, Stats};
+
+#[cfg(feature = "step_by_offset")]
+pub use crate::adaptors::step_by_offset::{IterStepByOffset, StepByOffset};
+
+#[cfg(feature = "take_exactly")]
+pub use crate::adaptors::take_exactly::{IterTakeExactly, ShortError, TakeExactly, TryTakeExactly};
+
+#[cfg(feature = "tee")]
+pub use crate::adaptors::tee::{IterTee, Tee};
+
+#[cfg(feature = "windows")]
+pub use crate::adaptors::windows::{IterWindows, Windows};
+
+#[cfg(feature = "windows_buffered")]
+pub use crate::adaptors::windows_buffered::{IterWindowsBuffered, WindowsBuffered};
+
+#[cfg(feature = "with_lag")]
+pub use crate::adaptors::with_lag::{IterWithLag, WithLag};
+
+#[cfg(feature = "with_next")]
+pub use crate::adaptors::with_next::{IterWithNext, WithNext};
+
+#[cfg(feature = "with_phase")]
+pub use crate::adaptors::with_phase::{IterWithPhase, WithPhase};
+
+#[cfg(feature = "with_prev")]
+pub use crate::adaptors::with_prev::{IterWithPrev, WithPrev};
+
+#[cfg(feature = "zip_longest")]
+pub use crate::adaptors::zip_longest::{EitherOrBoth, IterZipLongest, ZipLongest};
+
 /// Re-exports all iterator extension traits.
 ///
 /// The intention is that this module is used as a `*` import.
@@ -150,6 +433,9 @@ pub use crate::xtraits::sorted::IterSorted;
 /// If you want to refer to a trait directly rather import it from the crate
 /// root.
 pub mod prelude {
+    #[cfg(feature = "all_equal")]
+    pub use super::IterAllEqual;
+
     #[cfg(feature = "array_chunks")]
     pub use super::IterArrayChunks;
 
@@ -162,9 +448,24 @@ pub mod prelude {
     #[cfg(feature = "array_windows")]
     pub use super::IterArrayWindows;
 
+    #[cfg(feature = "array_windows_dot")]
+    pub use super::IterArrayWindowsDot;
+
+    #[cfg(feature = "array_windows_min_max")]
+    pub use super::IterArrayWindowsMinMax;
+
     #[cfg(feature = "cartesian_product")]
     pub use super::IterCartesianProduct;
 
+    #[cfg(feature = "chunk_by_weight")]
+    pub use super::IterChunkByWeight;
+
+    #[cfg(feature = "chunk_sums")]
+    pub use super::IterChunkSums;
+
+    #[cfg(feature = "chunks")]
+    pub use super::IterChunks;
+
     #[cfg(feature = "circular_array_windows")]
     pub use super::IterCircularArrayWindows;
 
@@ -174,15 +475,111 @@ pub mod prelude {
     #[cfg(feature = "combinations")]
     pub use super::IterCombinations;
 
+    #[cfg(feature = "combinations_gray")]
+    pub use super::IterCombinationsGray;
+
     #[cfg(feature = "combinations_with_reps")]
     pub use super::IterCombinationsWithReps;
 
+    #[cfg(feature = "cycle_n")]
+    pub use super::IterCycleN;
+
+    #[cfg(feature = "dedup")]
+    pub use super::IterDedup;
+
+    #[cfg(feature = "drop_last")]
+    pub use super::IterDropLast;
+
+    #[cfg(feature = "fold_chunks")]
+    pub use super::IterFoldChunks;
+
+    #[cfg(feature = "for_each_chunk")]
+    pub use super::IterForEachChunk;
+
+    #[cfg(feature = "format")]
+    pub use super::IterFormat;
+
+    #[cfg(feature = "group_map")]
+    pub use super::IterGroupMap;
+
+    #[cfg(feature = "is_ordered")]
+    pub use super::IterIsOrdered;
+
+    #[cfg(feature = "map_ok")]
+    pub use super::IterMapOk;
+
+    #[cfg(feature = "memoize")]
+    pub use super::IterMemoize;
+
     #[cfg(feature = "min_max")]
     pub use super::IterMinMax;
 
+    #[cfg(feature = "most_common")]
+    pub use super::IterMostCommon;
+
     #[cfg(feature = "next_chunk")]
     pub use super::IterNextChunk;
 
+    #[cfg(feature = "overlapping_chunks")]
+    pub use super::IterOverlappingChunks;
+
+    #[cfg(feature = "partition")]
+    pub use super::IterPartition;
+
+    #[cfg(feature = "power_set")]
+    pub use super::IterPowerSet;
+
+    #[cfg(feature = "prefix_suffix_products")]
+    pub use super::IterPrefixSuffixProducts;
+
+    #[cfg(feature = "rand")]
+    pub use super::IterRandom;
+
+    #[cfg(feature = "reduce")]
+    pub use super::IterReduce;
+
+    #[cfg(feature = "repeat_each")]
+    pub use super::IterRepeatEach;
+
+    #[cfg(feature = "rolling_windows")]
+    pub use super::IterRollingWindows;
+
     #[cfg(feature = "sorted")]
     pub use super::IterSorted;
+
+    #[cfg(feature = "split_when")]
+    pub use super::IterSplitWhen;
+
+    #[cfg(feature = "stats")]
+    pub use super::IterStats;
+
+    #[cfg(feature = "step_by_offset")]
+    pub use super::IterStepByOffset;
+
+    #[cfg(feature = "take_exactly")]
+    pub use super::IterTakeExactly;
+
+    #[cfg(feature = "tee")]
+    pub use super::IterTee;
+
+    #[cfg(feature = "windows")]
+    pub use super::IterWindows;
+
+    #[cfg(feature = "windows_buffered")]
+    pub use super::IterWindowsBuffered;
+
+    #[cfg(feature = "with_lag")]
+    pub use super::IterWithLag;
+
+    #[cfg(feature = "with_next")]
+    pub use super::IterWithNext;
+
+    #[cfg(feature = "with_phase")]
+    pub use super::IterWithPhase;
+
+    #[cfg(feature = "with_prev")]
+    pub use super::IterWithPrev;
+
+    #[cfg(feature = "zip_longest")]
+    pub use super::IterZipLongest;
 }
@@ -0,0 +1,89 @@
+/// An extension trait that provides the [`all_equal`] method and friends for
+/// iterators.
+///
+/// [`all_equal`]: IterAllEqual::all_equal
+#[cfg_attr(docsrs, doc(cfg(feature = "all_equal")))]
+pub trait IterAllEqual: Iterator {
+    /// Returns whether all the elements of the iterator are equal.
+    ///
+    /// Every element is compared to the first one and the iterator stops at
+    /// the first element that is not equal. An empty iterator or an iterator
+    /// with a single element always returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterAllEqual;
+    ///
+    /// assert!("aaa".chars().all_equal());
+    /// assert!(!"aab".chars().all_equal());
+    /// ```
+    fn all_equal(self) -> bool
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.all_equal_value().is_ok()
+    }
+
+    /// Returns the value that all the elements of the iterator are equal to.
+    ///
+    /// - If there are no elements then `Ok(None)` is returned.
+    /// - If all the elements are equal then the first element is returned.
+    /// - Otherwise the first element and the first element that is not equal
+    ///   to it are returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterAllEqual;
+    ///
+    /// assert_eq!("".chars().all_equal_value(), Ok(None));
+    /// assert_eq!("aaa".chars().all_equal_value(), Ok(Some('a')));
+    /// assert_eq!("aabc".chars().all_equal_value(), Err(('a', 'b')));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn all_equal_value(mut self) -> Result<Option<Self::Item>, (Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        let first = match self.next() {
+            Some(first) => first,
+            None => return Ok(None),
+        };
+        match self.find(|item| *item != first) {
+            Some(item) => Err((first, item)),
+            None => Ok(Some(first)),
+        }
+    }
+
+    /// Returns whether all the elements of the iterator are distinct.
+    ///
+    /// The elements are stored in a [`HashSet`] and the iterator stops at the
+    /// first element that has already been seen. An empty iterator or an
+    /// iterator with a single element always returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterAllEqual;
+    ///
+    /// assert!("abc".chars().all_unique());
+    /// assert!(!"abca".chars().all_unique());
+    /// ```
+    ///
+    /// [`HashSet`]: std::collections::HashSet
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "all_equal", feature = "std"))))]
+    fn all_unique(mut self) -> bool
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash,
+    {
+        let mut seen = std::collections::HashSet::new();
+        self.all(|item| seen.insert(item))
+    }
+}
+
+impl<I: ?Sized> IterAllEqual for I where I: Iterator {}
@@ -1,3 +1,5 @@
+use core::iter;
+
 /// An extension trait that provides the [`collect_array`] method for iterators.
 ///
 /// [`collect_array`]: IterCollectArray::collect_array
@@ -41,6 +43,214 @@ pub trait IterCollectArray: Iterator {
             }
         }
     }
+
+    /// Consumes the entire iterator collecting it into an array, filling any
+    /// missing elements with their default value.
+    ///
+    /// This never panics, if the iterator contains less than `N` elements the
+    /// rest of the array is filled with [`Default::default`] and if it
+    /// contains more than `N` elements the rest are consumed and dropped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterCollectArray;
+    ///
+    /// assert_eq!((0..2).collect_array_or_default::<4>(), [0, 1, 0, 0]);
+    /// assert_eq!((0..6).collect_array_or_default::<4>(), [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    fn collect_array_or_default<const N: usize>(mut self) -> [Self::Item; N]
+    where
+        Self: Sized,
+        Self::Item: Default,
+    {
+        let iter = self.by_ref().chain(iter::repeat_with(Default::default));
+        // SAFETY: The chained iterator is infinite so it yields at least N
+        // elements.
+        let arr = unsafe { arrays::from_iter_unchecked(iter) };
+        self.for_each(drop);
+        arr
+    }
+
+    /// Consumes the entire iterator of pairs collecting it into two arrays.
+    ///
+    /// This is the array equivalent of [`Iterator::unzip`].
+    ///
+    /// # Panics
+    ///
+    /// If the iterator contains too little or too many elements to fit in the
+    /// arrays.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterCollectArray;
+    ///
+    /// let (nums, chars) = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().unzip_array();
+    /// assert_eq!(nums, [1, 2, 3]);
+    /// assert_eq!(chars, ['a', 'b', 'c']);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn unzip_array<A, B, const N: usize>(mut self) -> ([A; N], [B; N])
+    where
+        Self: Sized + Iterator<Item = (A, B)>,
+    {
+        let mut bs: [Option<B>; N] = [(); N].map(|_| None);
+        let iter = self.by_ref().zip(bs.iter_mut()).map(|((a, b), slot)| {
+            *slot = Some(b);
+            a
+        });
+        match arrays::from_iter(iter) {
+            Ok(arr) => {
+                if self.next().is_some() {
+                    panic!("expected exactly {} elements, but collected more", N);
+                }
+                // SAFETY: All N slots were filled because `from_iter`
+                // succeeded which means the zipped iterator yielded N times.
+                let bs = unsafe { arrays::from_iter_unchecked(bs.into_iter().flatten()) };
+                (arr, bs)
+            }
+            Err(arr) => {
+                let got = arr.as_slice().len();
+                panic!("expected exactly {} elements, but collected {}", N, got);
+            }
+        }
+    }
+
+    /// Consumes the entire iterator collecting the even-indexed elements into
+    /// one array and the odd-indexed elements into another.
+    ///
+    /// The iterator must contain exactly `2 * N` elements. If it contains too
+    /// little or too many elements then all the collected elements are dropped
+    /// and the total number of elements in the iterator is returned as the
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterCollectArray;
+    ///
+    /// assert_eq!((0..4).deinterleave(), Ok(([0, 2], [1, 3])));
+    /// assert_eq!((0..3).deinterleave::<2>(), Err(3));
+    /// assert_eq!((0..5).deinterleave::<2>(), Err(5));
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn deinterleave<const N: usize>(mut self) -> Result<([Self::Item; N], [Self::Item; N]), usize>
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+        let mut odds: [Option<Self::Item>; N] = [(); N].map(|_| None);
+        let iter = odds.iter_mut().map_while(|slot| {
+            let even = self.next()?;
+            count += 1;
+            *slot = self.next();
+            slot.as_ref()?;
+            count += 1;
+            Some(even)
+        });
+        match arrays::from_iter(iter) {
+            Ok(evens) => {
+                let rest = self.count();
+                if rest != 0 {
+                    return Err(count + rest);
+                }
+                // SAFETY: All N slots were filled because `from_iter`
+                // succeeded which means the mapped iterator yielded N times.
+                let odds = unsafe { arrays::from_iter_unchecked(odds.into_iter().flatten()) };
+                Ok((evens, odds))
+            }
+            Err(_) => Err(count),
+        }
+    }
+
+    /// Consumes the entire iterator collecting the first `N` elements into an
+    /// array and the rest into a [`Vec`].
+    ///
+    /// The array is only `Some` if the iterator yielded at least `N` elements,
+    /// otherwise all the elements that were yielded are returned in the
+    /// [`Vec`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterCollectArray;
+    ///
+    /// let (arr, rest) = (0..5).collect_array_with_overflow::<3>();
+    /// assert_eq!(arr, Some([0, 1, 2]));
+    /// assert_eq!(rest, [3, 4]);
+    ///
+    /// let (arr, rest) = (0..2).collect_array_with_overflow::<3>();
+    /// assert_eq!(arr, None);
+    /// assert_eq!(rest, [0, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "collect_array", feature = "alloc"))))]
+    #[inline]
+    fn collect_array_with_overflow<const N: usize>(
+        mut self,
+    ) -> (Option<[Self::Item; N]>, Vec<Self::Item>)
+    where
+        Self: Sized,
+    {
+        match arrays::from_iter(self.by_ref()) {
+            Ok(arr) => (Some(arr), Vec::from_iter(self)),
+            Err(rem) => (None, Vec::from_iter(rem)),
+        }
+    }
+
+    /// Consumes the entire iterator returning the last `N` elements in an
+    /// array.
+    ///
+    /// The elements are kept in a ring buffer of length `N` as the iterator is
+    /// consumed, so this only uses `O(N)` memory regardless of the length of
+    /// the iterator. Returns `None` if the iterator yielded less than `N`
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterCollectArray;
+    ///
+    /// let log = "boot\nload\nrun\nfail\nhalt";
+    /// assert_eq!(log.lines().last_n(), Some(["run", "fail", "halt"]));
+    /// assert_eq!(log.lines().last_n::<6>(), None);
+    /// ```
+    #[inline]
+    fn last_n<const N: usize>(mut self) -> Option<[Self::Item; N]>
+    where
+        Self: Sized,
+    {
+        let mut arr = arrays::from_iter(self.by_ref()).ok()?;
+        if N == 0 {
+            self.for_each(drop);
+            return Some(arr);
+        }
+        // The index of the oldest element in the ring buffer.
+        let mut i = 0;
+        for item in self {
+            arr[i] = item;
+            i += 1;
+            if i == N {
+                i = 0;
+            }
+        }
+        arr.rotate_left(i);
+        Some(arr)
+    }
 }
 
 impl<I: ?Sized> IterCollectArray for I where I: Iterator {}
@@ -0,0 +1,108 @@
+use arrays::IntoIter;
+
+/// An extension trait that provides the [`for_each_chunk`] and
+/// [`try_for_each_chunk`] methods for iterators.
+///
+/// [`for_each_chunk`]: IterForEachChunk::for_each_chunk
+/// [`try_for_each_chunk`]: IterForEachChunk::try_for_each_chunk
+#[cfg_attr(docsrs, doc(cfg(feature = "for_each_chunk")))]
+pub trait IterForEachChunk: Iterator {
+    /// Calls a closure on each `N` elements of the iterator at a time.
+    ///
+    /// The chunks do not overlap and are passed to the closure by reference.
+    /// If `N` does not divide the length of the iterator, then the last up to
+    /// `N-1` elements are returned.
+    ///
+    /// Each chunk is collected on the stack so there is no heap allocation,
+    /// this makes it well suited for hot loops where the closure can be
+    /// vectorized by the compiler.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterForEachChunk;
+    ///
+    /// let mut sums = Vec::new();
+    /// let rem = (1..=7).for_each_chunk(|[a, b, c]: &[i32; 3]| sums.push(a + b + c));
+    /// assert_eq!(sums, [6, 15]);
+    /// assert_eq!(rem.as_slice(), [7]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn for_each_chunk<const N: usize, F>(mut self, mut f: F) -> IntoIter<Self::Item, N>
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item; N]),
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        loop {
+            match arrays::from_iter(self.by_ref()) {
+                Ok(chunk) => f(&chunk),
+                Err(rem) => return rem,
+            }
+        }
+    }
+
+    /// Calls a fallible closure on each `N` elements of the iterator at a
+    /// time, stopping at the first error.
+    ///
+    /// This is the fallible version of
+    /// [`for_each_chunk`][IterForEachChunk::for_each_chunk], the chunks are
+    /// passed to the closure by value. If the closure returns an error then
+    /// no more elements are taken from the iterator and the error is
+    /// returned. Otherwise the last up to `N-1` elements are returned.
+    ///
+    /// # Panics
+    ///
+    /// If called with `N = 0`.
+    ///
+    /// # Examples
+    ///
+    /// Write fixed size records until the output is full.
+    ///
+    /// ```
+    /// use itermore::IterForEachChunk;
+    ///
+    /// let mut out = Vec::new();
+    /// let mut write = |record: [u8; 2]| {
+    ///     if out.len() == 4 {
+    ///         return Err("output full");
+    ///     }
+    ///     out.extend(record);
+    ///     Ok(())
+    /// };
+    ///
+    /// let rem = (0..5).try_for_each_chunk(&mut write).unwrap();
+    /// assert_eq!(rem.as_slice(), [4]);
+    ///
+    /// let err = (0..4).try_for_each_chunk(&mut write).unwrap_err();
+    /// assert_eq!(err, "output full");
+    /// assert_eq!(out, [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn try_for_each_chunk<const N: usize, E, F>(
+        mut self,
+        mut f: F,
+    ) -> Result<IntoIter<Self::Item, N>, E>
+    where
+        Self: Sized,
+        F: FnMut([Self::Item; N]) -> Result<(), E>,
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        loop {
+            match arrays::from_iter(self.by_ref()) {
+                Ok(chunk) => f(chunk)?,
+                Err(rem) => return Ok(rem),
+            }
+        }
+    }
+}
+
+impl<I: ?Sized> IterForEachChunk for I where I: Iterator {}
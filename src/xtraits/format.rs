@@ -0,0 +1,96 @@
+use core::cell::RefCell;
+use core::fmt;
+use core::fmt::{Display, Write};
+
+/// An extension trait that provides the [`join`] and [`format_with`] methods
+/// for iterators.
+///
+/// [`join`]: IterFormat::join
+/// [`format_with`]: IterFormat::format_with
+#[cfg_attr(docsrs, doc(cfg(feature = "format")))]
+pub trait IterFormat: Iterator {
+    /// Formats each element of the iterator and joins them together with the
+    /// given separator.
+    ///
+    /// This is equivalent to collecting the [`to_string`] of each element
+    /// into a [`Vec`] and calling [`join`][slice::join] on it, except that the
+    /// elements are written directly into the resulting [`String`].
+    ///
+    /// # Panics
+    ///
+    /// If the [`Display`] implementation of an element returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterFormat;
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().join(", "), "1, 2, 3");
+    /// ```
+    ///
+    /// [`to_string`]: std::string::ToString::to_string
+    fn join(self, sep: &str) -> String
+    where
+        Self: Sized,
+        Self::Item: Display,
+    {
+        self.format_with(sep, |item, f| item.fmt(f))
+    }
+
+    /// Formats each element of the iterator using the given function and
+    /// joins them together with the given separator.
+    ///
+    /// The function is passed each element and a [`Formatter`] to write the
+    /// element to, in the same way as [`Display::fmt`].
+    ///
+    /// # Panics
+    ///
+    /// If the function returns an error. Writing to a [`String`] never fails
+    /// so this only happens if the function returns an error itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterFormat;
+    ///
+    /// let s = [1.0, 2.5, 3.25]
+    ///     .into_iter()
+    ///     .format_with(" | ", |x, f| write!(f, "{:.1}", x));
+    /// assert_eq!(s, "1.0 | 2.5 | 3.2");
+    /// ```
+    ///
+    /// [`Formatter`]: fmt::Formatter
+    fn format_with<F>(self, sep: &str, f: F) -> String
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        let f = RefCell::new(f);
+        let mut s = String::new();
+        for (i, item) in self.enumerate() {
+            if i != 0 {
+                s.push_str(sep);
+            }
+            write!(s, "{}", FormatFn { item: &item, f: &f })
+                .expect("a formatting function returned an error");
+        }
+        s
+    }
+}
+
+impl<I: ?Sized> IterFormat for I where I: Iterator {}
+
+/// Implements [`Display`] for an element using a formatting function.
+struct FormatFn<'a, T, F> {
+    item: &'a T,
+    f: &'a RefCell<F>,
+}
+
+impl<T, F> Display for FormatFn<'_, T, F>
+where
+    F: FnMut(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.f.borrow_mut())(self.item, f)
+    }
+}
@@ -0,0 +1,69 @@
+use core::hash::Hash;
+
+use std::collections::HashMap;
+
+/// An extension trait that provides the [`into_group_map`] and
+/// [`into_group_map_by`] methods for iterators.
+///
+/// [`into_group_map`]: IterGroupMap::into_group_map
+/// [`into_group_map_by`]: IterGroupMap::into_group_map_by
+#[cfg_attr(docsrs, doc(cfg(feature = "group_map")))]
+pub trait IterGroupMap: Iterator {
+    /// Consumes the iterator of key-value pairs collecting the values into a
+    /// [`HashMap`] of groups by key.
+    ///
+    /// The values within each group are in the same order as they were
+    /// yielded by the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterGroupMap;
+    ///
+    /// let data = [("a", 1), ("b", 2), ("a", 3), ("c", 4), ("b", 5)];
+    /// let map = data.into_iter().into_group_map();
+    /// assert_eq!(map["a"], [1, 3]);
+    /// assert_eq!(map["b"], [2, 5]);
+    /// assert_eq!(map["c"], [4]);
+    /// ```
+    fn into_group_map<K, V>(self) -> HashMap<K, Vec<V>>
+    where
+        Self: Sized + Iterator<Item = (K, V)>,
+        K: Eq + Hash,
+    {
+        let mut map = HashMap::<_, Vec<_>>::new();
+        for (key, value) in self {
+            map.entry(key).or_default().push(value);
+        }
+        map
+    }
+
+    /// Consumes the iterator collecting the elements into a [`HashMap`] of
+    /// groups by the key returned from the given function.
+    ///
+    /// The elements within each group are in the same order as they were
+    /// yielded by the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterGroupMap;
+    ///
+    /// let map = ["apple", "avocado", "banana", "cherry"]
+    ///     .into_iter()
+    ///     .into_group_map_by(|s| s.chars().next());
+    /// assert_eq!(map[&Some('a')], ["apple", "avocado"]);
+    /// assert_eq!(map[&Some('b')], ["banana"]);
+    /// assert_eq!(map[&Some('c')], ["cherry"]);
+    /// ```
+    fn into_group_map_by<K, F>(self, mut f: F) -> HashMap<K, Vec<Self::Item>>
+    where
+        Self: Sized,
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(|item| (f(&item), item)).into_group_map()
+    }
+}
+
+impl<I: ?Sized> IterGroupMap for I where I: Iterator {}
@@ -0,0 +1,105 @@
+use core::cmp::Ordering;
+
+/// An extension trait that provides the [`is_ordered`] method and friends for
+/// iterators.
+///
+/// These are equivalent to [`Iterator::is_sorted`] and friends which were
+/// stabilized in Rust 1.82, they are named differently so that they do not
+/// conflict. The comparison function passed to [`is_ordered_by`] returns an
+/// [`Ordering`] like the one passed to [`sorted_by`] instead of a `bool`.
+///
+/// [`is_ordered`]: IterIsOrdered::is_ordered
+/// [`is_ordered_by`]: IterIsOrdered::is_ordered_by
+/// [`sorted_by`]: crate::IterSorted::sorted_by
+#[cfg_attr(docsrs, doc(cfg(feature = "is_ordered")))]
+#[allow(clippy::wrong_self_convention)]
+pub trait IterIsOrdered: Iterator {
+    /// Returns whether the elements of the iterator are sorted in ascending
+    /// order.
+    ///
+    /// Every element is compared to the previous one and the iterator stops at
+    /// the first element that is less than the previous one. An empty iterator
+    /// or an iterator with a single element is always sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterIsOrdered;
+    ///
+    /// assert!([1, 2, 2, 9].into_iter().is_ordered());
+    /// assert!(![1, 3, 2, 4].into_iter().is_ordered());
+    /// ```
+    fn is_ordered(self) -> bool
+    where
+        Self: Sized,
+        Self::Item: PartialOrd,
+    {
+        is_ordered(self, |a, b| a <= b)
+    }
+
+    /// Returns whether the elements of the iterator are sorted with respect to
+    /// the given comparison function.
+    ///
+    /// See [`is_ordered`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterIsOrdered;
+    ///
+    /// assert!([9, 2, 2, 1].into_iter().is_ordered_by(|a, b| b.cmp(a)));
+    /// ```
+    ///
+    /// [`is_ordered`]: IterIsOrdered::is_ordered
+    fn is_ordered_by<F>(self, mut compare: F) -> bool
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        is_ordered(self, |a, b| compare(a, b) != Ordering::Greater)
+    }
+
+    /// Returns whether the elements of the iterator are sorted with respect to
+    /// the key returned from the given key function.
+    ///
+    /// See [`is_ordered`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterIsOrdered;
+    ///
+    /// assert!(["c", "bb", "aaa"].into_iter().is_ordered_by_key(|s| s.len()));
+    /// ```
+    ///
+    /// [`is_ordered`]: IterIsOrdered::is_ordered
+    fn is_ordered_by_key<F, K>(self, key: F) -> bool
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> K,
+        K: PartialOrd,
+    {
+        self.map(key).is_ordered()
+    }
+}
+
+impl<I: ?Sized> IterIsOrdered for I where I: Iterator {}
+
+/// Returns whether `le(prev, next)` holds for every pair of adjacent elements.
+fn is_ordered<I, F>(mut iter: I, mut le: F) -> bool
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    let mut prev = match iter.next() {
+        Some(item) => item,
+        None => return true,
+    };
+    for next in iter {
+        if !le(&prev, &next) {
+            return false;
+        }
+        prev = next;
+    }
+    true
+}
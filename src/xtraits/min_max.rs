@@ -1,4 +1,12 @@
 use core::cmp::Ordering;
+use core::convert::Infallible;
+
+use crate::{RunningMax, RunningMin, RunningMinMax};
+
+type Compare<T> = fn(&T, &T) -> Ordering;
+
+/// The minimum and maximum, the maximum is `None` if there is only one element.
+type MinMax<T> = Option<(T, Option<T>)>;
 
 /// An extension trait that provides the [`min_max`] method and friends for
 /// iterators.
@@ -42,6 +50,31 @@ pub trait IterMinMax: Iterator {
         min_max(self, compare)
     }
 
+    /// Returns the minimum and maximum element with respect to the given
+    /// comparison function, along with the number of comparisons performed.
+    ///
+    /// This is the same as [`min_max_by`] and is only intended for testing the
+    /// number of comparisons. For an iterator of length `n >= 2` this is
+    /// `ceil(1.5 * n) - 2`, except when `n` is odd and the last element is a
+    /// new minimum in which case it is one less.
+    ///
+    /// [`min_max_by`]: IterMinMax::min_max_by
+    #[doc(hidden)]
+    #[allow(clippy::type_complexity)]
+    fn min_max_by_counted<F>(self, mut compare: F) -> (Option<(Self::Item, Self::Item)>, usize)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut count = 0;
+        let min_max = min_max(self, |a, b| {
+            count += 1;
+            compare(a, b)
+        });
+        (min_max, count)
+    }
+
     /// Returns the minimum and maximum element with respect to element returned
     /// from the given key function.
     ///
@@ -61,21 +94,378 @@ pub trait IterMinMax: Iterator {
             .min_max_by(|(k1, _), (k2, _)| k1.cmp(k2))
             .map(|((_, min), (_, max))| (min, max))
     }
+
+    /// Returns the minimum and maximum element with respect to element returned
+    /// from the given key function, along with their keys.
+    ///
+    /// The key function is called exactly once for each element. See
+    /// [`min_max`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let iter = [-3, 1, 7, -9, 2].into_iter();
+    /// assert_eq!(
+    ///     iter.min_max_by_key_with_keys(|x: &i32| x.abs()),
+    ///     Some(((1, 1), (9, -9)))
+    /// );
+    /// ```
+    ///
+    /// [`min_max`]: IterMinMax::min_max
+    #[allow(clippy::type_complexity)]
+    fn min_max_by_key_with_keys<F, K>(
+        self,
+        mut key: F,
+    ) -> Option<((K, Self::Item), (K, Self::Item))>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Ord + Clone,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.map(move |item| (key(&item), item))
+            .min_max_by(|(k1, _), (k2, _)| k1.cmp(k2))
+    }
+
+    /// Returns the minimum and maximum element with respect to element returned
+    /// from the given key function, caching the key of every element.
+    ///
+    /// The key function is called exactly once for each element and unlike
+    /// [`min_max_by_key`] the key does not need to implement [`Clone`]. In
+    /// return this collects all the elements and their keys into a [`Vec`], so
+    /// it allocates a buffer proportional to the length of the iterator. See
+    /// [`min_max`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let words = ["apple", "fig", "banana", "kiwi"];
+    /// let (min, max) = words.into_iter().min_max_by_cached_key(|s| s.to_uppercase()).unwrap();
+    /// assert_eq!((min, max), ("apple", "kiwi"));
+    /// ```
+    ///
+    /// [`min_max`]: IterMinMax::min_max
+    /// [`min_max_by_key`]: IterMinMax::min_max_by_key
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "min_max", feature = "alloc"))))]
+    fn min_max_by_cached_key<F, K>(self, mut key: F) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = Vec::from_iter(self.map(|item| (key(&item), item)));
+        let (i, j) = v.iter().min_max_indices_by(|(k1, _), (k2, _)| k1.cmp(k2))?;
+        // Remove the element with the larger index first so that the other
+        // index stays valid.
+        if i == j {
+            let (_, min) = v.swap_remove(i);
+            Some((min.clone(), min))
+        } else if i > j {
+            let (_, min) = v.swap_remove(i);
+            let (_, max) = v.swap_remove(j);
+            Some((min, max))
+        } else {
+            let (_, max) = v.swap_remove(j);
+            let (_, min) = v.swap_remove(i);
+            Some((min, max))
+        }
+    }
+
+    /// Returns the minimum and maximum element with respect to the given
+    /// fallible comparison function.
+    ///
+    /// If the comparison function returns an error then iteration stops and
+    /// the error is returned. See [`min_max`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let cmp = |a: &f64, b: &f64| a.partial_cmp(b).ok_or("NaN");
+    ///
+    /// let iter = [3.0, 1.0, 2.0].into_iter();
+    /// assert_eq!(iter.try_min_max_by(cmp), Ok(Some((1.0, 3.0))));
+    ///
+    /// let iter = [3.0, f64::NAN, 2.0].into_iter();
+    /// assert_eq!(iter.try_min_max_by(cmp), Err("NaN"));
+    /// ```
+    ///
+    /// [`min_max`]: IterMinMax::min_max
+    #[allow(clippy::type_complexity)]
+    fn try_min_max_by<F, E>(self, compare: F) -> Result<Option<(Self::Item, Self::Item)>, E>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Result<Ordering, E>,
+    {
+        Ok(
+            try_min_max_impl(self, compare)?.map(|(min, max)| match max {
+                Some(max) => (min, max),
+                None => (min.clone(), min),
+            }),
+        )
+    }
+
+    /// Returns the minimum and maximum element in the iterator, stopping early
+    /// once elements equal to both of the given bounds have been seen.
+    ///
+    /// `lo` and `hi` should be the smallest and largest values that the
+    /// iterator can possibly contain, for example [`u8::MIN`] and
+    /// [`u8::MAX`]. Once both have been seen no other element can change the
+    /// result so the rest of the iterator is not consumed.
+    ///
+    /// Ties are resolved in the same way as [`min_max`]. However, the elements
+    /// are not compared in pairs, each one is compared against the current
+    /// minimum and maximum which are then compared against the bounds. So on
+    /// an iterator of length `n` this does up to `2 * n` comparisons and
+    /// `2 * n` equality checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let mut iter = [7, 0, 255, 3, 42].into_iter();
+    /// assert_eq!(iter.by_ref().min_max_bounded(u8::MIN, u8::MAX), Some((0, 255)));
+    /// assert_eq!(iter.next(), Some(3));
+    /// ```
+    ///
+    /// [`min_max`]: IterMinMax::min_max
+    fn min_max_bounded(mut self, lo: Self::Item, hi: Self::Item) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        let (mut min, mut max) = (first.clone(), first);
+        while min != lo || max != hi {
+            let item = match self.next() {
+                Some(item) => item,
+                None => break,
+            };
+            if item < min {
+                min = item;
+            } else if item > max {
+                max = item;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Returns an iterator adaptor that yields the minimum element seen so far
+    /// for each element in the underlying iterator.
+    ///
+    /// If several elements are equally minimum, the first element is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let v = Vec::from_iter([3, 1, 2, 0, 4].into_iter().running_min());
+    /// assert_eq!(v, [3, 1, 1, 0, 0]);
+    /// ```
+    #[inline]
+    fn running_min(self) -> RunningMin<Self, Compare<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        RunningMin::new(self, Ord::cmp)
+    }
+
+    /// Returns an iterator adaptor that yields the minimum element seen so far
+    /// with respect to the given comparison function.
+    ///
+    /// See [`running_min`] for more details.
+    ///
+    /// [`running_min`]: IterMinMax::running_min
+    #[inline]
+    fn running_min_by<F>(self, compare: F) -> RunningMin<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        RunningMin::new(self, compare)
+    }
+
+    /// Returns an iterator adaptor that yields the maximum element seen so far
+    /// for each element in the underlying iterator.
+    ///
+    /// If several elements are equally maximum, the first element is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let v = Vec::from_iter([1, 3, 2, 5, 4].into_iter().running_max());
+    /// assert_eq!(v, [1, 3, 3, 5, 5]);
+    /// ```
+    #[inline]
+    fn running_max(self) -> RunningMax<Self, Compare<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        RunningMax::new(self, Ord::cmp)
+    }
+
+    /// Returns an iterator adaptor that yields the maximum element seen so far
+    /// with respect to the given comparison function.
+    ///
+    /// See [`running_max`] for more details.
+    ///
+    /// [`running_max`]: IterMinMax::running_max
+    #[inline]
+    fn running_max_by<F>(self, compare: F) -> RunningMax<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        RunningMax::new(self, compare)
+    }
+
+    /// Returns an iterator adaptor that yields the minimum and maximum element
+    /// seen so far for each element in the underlying iterator.
+    ///
+    /// This is the same as zipping [`running_min`] and [`running_max`]
+    /// together, which is useful for accumulating a bounding box. If several
+    /// elements are equally minimum or maximum, the first element is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let v = Vec::from_iter([3, 1, 4, 1, 5].into_iter().running_min_max());
+    /// assert_eq!(v, [(3, 3), (1, 3), (1, 4), (1, 4), (1, 5)]);
+    /// ```
+    ///
+    /// [`running_min`]: IterMinMax::running_min
+    /// [`running_max`]: IterMinMax::running_max
+    #[inline]
+    fn running_min_max(self) -> RunningMinMax<Self, Compare<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        RunningMinMax::new(self, Ord::cmp)
+    }
+
+    /// Returns an iterator adaptor that yields the minimum and maximum element
+    /// seen so far with respect to the given comparison function.
+    ///
+    /// See [`running_min_max`] for more details.
+    ///
+    /// [`running_min_max`]: IterMinMax::running_min_max
+    #[inline]
+    fn running_min_max_by<F>(self, compare: F) -> RunningMinMax<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        RunningMinMax::new(self, compare)
+    }
+
+    /// Returns the positions of the minimum and maximum element in the
+    /// iterator.
+    ///
+    /// This is the same as [`min_max`] except that the elements are not
+    /// required to implement [`Clone`], instead the indices of the elements
+    /// are returned which can be used to index into the original collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMinMax;
+    ///
+    /// let v = vec![String::from("b"), String::from("a"), String::from("c")];
+    /// let (min, max) = v.iter().min_max_indices().unwrap();
+    /// assert_eq!((&*v[min], &*v[max]), ("a", "c"));
+    /// ```
+    ///
+    /// [`min_max`]: IterMinMax::min_max
+    fn min_max_indices(self) -> Option<(usize, usize)>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.min_max_indices_by(Ord::cmp)
+    }
+
+    /// Returns the positions of the minimum and maximum element with respect
+    /// to the given comparison function.
+    ///
+    /// See [`min_max_indices`] for more details.
+    ///
+    /// [`min_max_indices`]: IterMinMax::min_max_indices
+    fn min_max_indices_by<F>(self, mut compare: F) -> Option<(usize, usize)>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        min_max_impl(self.enumerate(), |(_, a), (_, b)| compare(a, b)).map(
+            |((i, _), max)| match max {
+                Some((j, _)) => (i, j),
+                None => (i, i),
+            },
+        )
+    }
 }
 
 impl<I: ?Sized> IterMinMax for I where I: Iterator {}
 
-fn min_max<I, F>(mut iter: I, mut compare: F) -> Option<(I::Item, I::Item)>
+fn min_max<I, F>(iter: I, compare: F) -> Option<(I::Item, I::Item)>
 where
     I::Item: Clone,
     I: Iterator,
     F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    min_max_impl(iter, compare).map(|(min, max)| match max {
+        Some(max) => (min, max),
+        None => (min.clone(), min),
+    })
+}
+
+/// Returns the minimum and maximum element, the maximum is `None` if the
+/// iterator only has a single element.
+fn min_max_impl<I, F>(iter: I, mut compare: F) -> MinMax<I::Item>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    match try_min_max_impl(iter, |a, b| Ok::<_, Infallible>(compare(a, b))) {
+        Ok(r) => r,
+        Err(never) => match never {},
+    }
+}
+
+/// Returns the minimum and maximum element using a fallible comparison
+/// function, stopping at the first error.
+fn try_min_max_impl<I, F, E>(mut iter: I, mut compare: F) -> Result<MinMax<I::Item>, E>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Result<Ordering, E>,
 {
     let (mut min, mut max) = {
-        let a = iter.next()?;
+        let a = match iter.next() {
+            Some(a) => a,
+            None => return Ok(None),
+        };
         match iter.next() {
-            None => return Some((a.clone(), a)),
-            Some(b) => match compare(&a, &b) {
+            None => return Ok(Some((a, None))),
+            Some(b) => match compare(&a, &b)? {
                 Ordering::Less => (a, b),
                 _ => (b, a),
             },
@@ -85,24 +475,24 @@ where
         let b = match iter.next() {
             Some(b) => b,
             None => {
-                if compare(&a, &min) == Ordering::Less {
+                if compare(&a, &min)? == Ordering::Less {
                     min = a;
-                } else if compare(&a, &max) == Ordering::Greater {
+                } else if compare(&a, &max)? == Ordering::Greater {
                     max = a;
                 }
                 break;
             }
         };
-        let (a, b) = match compare(&a, &b) {
+        let (a, b) = match compare(&a, &b)? {
             Ordering::Less => (a, b),
             _ => (b, a),
         };
-        if compare(&a, &min) == Ordering::Less {
+        if compare(&a, &min)? == Ordering::Less {
             min = a;
         }
-        if compare(&b, &max) == Ordering::Greater {
+        if compare(&b, &max)? == Ordering::Greater {
             max = b;
         }
     }
-    Some((min, max))
+    Ok(Some((min, Some(max))))
 }
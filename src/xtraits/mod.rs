@@ -1,8 +1,30 @@
+#[cfg(feature = "all_equal")]
+pub mod all_equal;
 #[cfg(feature = "collect_array")]
 pub mod collect_array;
+#[cfg(feature = "for_each_chunk")]
+pub mod for_each_chunk;
+#[cfg(feature = "format")]
+pub mod format;
+#[cfg(feature = "group_map")]
+pub mod group_map;
+#[cfg(feature = "is_ordered")]
+pub mod is_ordered;
 #[cfg(feature = "min_max")]
 pub mod min_max;
+#[cfg(feature = "most_common")]
+pub mod most_common;
 #[cfg(feature = "next_chunk")]
 pub mod next_chunk;
+#[cfg(feature = "partition")]
+pub mod partition;
+#[cfg(feature = "prefix_suffix_products")]
+pub mod prefix_suffix_products;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "reduce")]
+pub mod reduce;
 #[cfg(feature = "sorted")]
 pub mod sorted;
+#[cfg(feature = "stats")]
+pub mod stats;
@@ -0,0 +1,76 @@
+use core::cmp::Reverse;
+use core::hash::Hash;
+
+use std::collections::HashMap;
+
+/// An extension trait that provides the [`most_common`] method and friends
+/// for iterators.
+///
+/// [`most_common`]: IterMostCommon::most_common
+#[cfg_attr(docsrs, doc(cfg(feature = "most_common")))]
+pub trait IterMostCommon: Iterator {
+    /// Returns each distinct element of the iterator along with the number of
+    /// times it occurs, sorted by descending frequency.
+    ///
+    /// Elements that occur the same number of times are ordered by their first
+    /// appearance in the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMostCommon;
+    ///
+    /// let v = "abracadabra".chars().most_common();
+    /// assert_eq!(v, [('a', 5), ('b', 2), ('r', 2), ('c', 1), ('d', 1)]);
+    /// ```
+    fn most_common(self) -> Vec<(Self::Item, usize)>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        most_common(self, usize::MAX)
+    }
+
+    /// Returns the `n` most common elements of the iterator along with the
+    /// number of times they occur, sorted by descending frequency.
+    ///
+    /// See [`most_common`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterMostCommon;
+    ///
+    /// let v = "abracadabra".chars().most_common_n(2);
+    /// assert_eq!(v, [('a', 5), ('b', 2)]);
+    /// ```
+    ///
+    /// [`most_common`]: IterMostCommon::most_common
+    fn most_common_n(self, n: usize) -> Vec<(Self::Item, usize)>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash,
+    {
+        most_common(self, n)
+    }
+}
+
+impl<I: ?Sized> IterMostCommon for I where I: Iterator {}
+
+fn most_common<I>(iter: I, n: usize) -> Vec<(I::Item, usize)>
+where
+    I: Iterator,
+    I::Item: Eq + Hash,
+{
+    // Map each element to its count and the index of its first appearance.
+    let mut counts = HashMap::new();
+    for (i, item) in iter.enumerate() {
+        counts.entry(item).or_insert((0, i)).0 += 1;
+    }
+    let mut v = Vec::from_iter(counts);
+    v.sort_unstable_by_key(|&(_, (count, first))| (Reverse(count), first));
+    v.truncate(n);
+    v.into_iter()
+        .map(|(item, (count, _))| (item, count))
+        .collect()
+}
@@ -57,6 +57,101 @@ pub trait IterNextChunk: Iterator {
     {
         arrays::from_iter(self)
     }
+
+    /// Consumes the iterator and returns an array containing the first `N`
+    /// values along with the rest of the iterator.
+    ///
+    /// If there are not enough elements to fill the array then `Err` is
+    /// returned containing the already yielded items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterNextChunk;
+    ///
+    /// let ([version, kind], rest) = [1, 7, 0, 0, 42].into_iter().split_first().unwrap();
+    /// assert_eq!((version, kind), (1, 7));
+    /// assert_eq!(Vec::from_iter(rest), [0, 0, 42]);
+    ///
+    /// let err = [1].into_iter().split_first::<2>().unwrap_err();
+    /// assert_eq!(err.as_slice(), [1]);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn split_first<const N: usize>(
+        mut self,
+    ) -> Result<([Self::Item; N], Self), IntoIter<Self::Item, N>>
+    where
+        Self: Sized,
+    {
+        let head = arrays::from_iter(self.by_ref())?;
+        Ok((head, self))
+    }
+
+    /// Consumes the iterator and returns an array containing the first `N`
+    /// values for which the predicate returns `true` along with the rest of
+    /// the iterator.
+    ///
+    /// The predicate is called on each element in turn, if it returns `false`
+    /// before `N` elements have been collected then `None` is returned instead
+    /// of the array. In that case the already collected elements and the
+    /// element that failed the predicate are dropped, in the same way as
+    /// [`take_while`]. If `N` elements match then no further elements are
+    /// taken from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// Parse a fixed size header of ASCII digits.
+    ///
+    /// ```
+    /// use itermore::IterNextChunk;
+    ///
+    /// let (header, rest) = "2024abc".chars().take_array_while(char::is_ascii_digit);
+    /// assert_eq!(header, Some(['2', '0', '2', '4']));
+    /// assert_eq!(String::from_iter(rest), "abc");
+    ///
+    /// let (header, rest) = "20a4bc".chars().take_array_while::<_, 4>(char::is_ascii_digit);
+    /// assert_eq!(header, None);
+    /// assert_eq!(String::from_iter(rest), "4bc");
+    /// ```
+    ///
+    /// [`take_while`]: Iterator::take_while
+    #[inline]
+    fn take_array_while<P, const N: usize>(mut self, mut pred: P) -> (Option<[Self::Item; N]>, Self)
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let arr = arrays::from_iter(self.by_ref().take_while(|item| pred(item))).ok();
+        (arr, self)
+    }
+
+    /// Consumes the iterator and returns an array containing the first `N`
+    /// values.
+    ///
+    /// This is the same as [`next_chunk`][IterNextChunk::next_chunk] except
+    /// that `None` is returned if there are not enough elements to fill the
+    /// array. No more than `N` elements are taken from the iterator, so it can
+    /// be called on a mutable reference to continue using the iterator
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterNextChunk;
+    ///
+    /// let mut iter = 1..6;
+    /// assert_eq!(iter.by_ref().first_n(), Some([1, 2]));
+    /// assert_eq!(iter.by_ref().first_n(), Some([3, 4]));
+    /// assert_eq!(iter.by_ref().first_n::<2>(), None);
+    /// ```
+    #[inline]
+    fn first_n<const N: usize>(self) -> Option<[Self::Item; N]>
+    where
+        Self: Sized,
+    {
+        arrays::from_iter(self).ok()
+    }
 }
 
 impl<I: ?Sized> IterNextChunk for I where I: Iterator {}
@@ -0,0 +1,37 @@
+/// An extension trait that provides the [`partition_result`] method for
+/// iterators.
+///
+/// [`partition_result`]: IterPartition::partition_result
+#[cfg_attr(docsrs, doc(cfg(feature = "partition")))]
+pub trait IterPartition: Iterator {
+    /// Consumes an iterator of [`Result`]s, collecting all the [`Ok`] values
+    /// into one [`Vec`] and all the [`Err`] values into another.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use itermore::IterPartition;
+    ///
+    /// let (oks, errs) = ["1", "a", "2"].iter().map(|s| s.parse::<i32>()).partition_result();
+    /// assert_eq!(oks, [1, 2]);
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    fn partition_result<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Sized + Iterator<Item = Result<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for item in self {
+            match item {
+                Ok(t) => oks.push(t),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
+}
+
+impl<I: ?Sized> IterPartition for I where I: Iterator {}
@@ -0,0 +1,73 @@
+use core::iter;
+use core::iter::Product;
+use core::ops::Mul;
+
+/// An extension trait that provides the [`prefix_suffix_products`] method for
+/// iterators.
+///
+/// [`prefix_suffix_products`]: IterPrefixSuffixProducts::prefix_suffix_products
+#[cfg_attr(docsrs, doc(cfg(feature = "prefix_suffix_products")))]
+pub trait IterPrefixSuffixProducts: Iterator {
+    /// Consumes the iterator returning the product of the elements before and
+    /// after each position.
+    ///
+    /// The first product of each pair is the product of all the elements
+    /// before that position and the second is the product of all the elements
+    /// after it. The product of no elements is the empty product, usually
+    /// one. Multiplying the two gives the product of all the elements except
+    /// the one at that position, without needing division.
+    ///
+    /// The elements are collected into a [`Vec`] and then multiplied in one
+    /// pass from the front and one pass from the back, so this does
+    /// `2 * (n - 1)` multiplications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterPrefixSuffixProducts;
+    ///
+    /// let v = [1, 2, 3, 4].into_iter().prefix_suffix_products();
+    /// assert_eq!(v, [(1, 24), (1, 12), (2, 4), (6, 1)]);
+    ///
+    /// let except_self = Vec::from_iter(v.into_iter().map(|(p, s)| p * s));
+    /// assert_eq!(except_self, [24, 12, 8, 6]);
+    /// ```
+    fn prefix_suffix_products(self) -> Vec<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone + Mul<Output = Self::Item> + Product,
+    {
+        let empty = || iter::empty::<Self::Item>().product::<Self::Item>();
+        let elements = Vec::from_iter(self);
+        let n = elements.len();
+
+        // The last element is not part of any prefix and the first element is
+        // not part of any suffix, so neither is multiplied. Otherwise the
+        // product of all the elements would be computed, which might overflow
+        // even if none of the returned products do.
+        let mut acc = empty();
+        let mut prefixes = Vec::with_capacity(n);
+        for x in elements.iter().take(n.saturating_sub(1)) {
+            let next = acc.clone() * x.clone();
+            prefixes.push(acc);
+            acc = next;
+        }
+        if n > 0 {
+            prefixes.push(acc);
+        }
+
+        let mut acc = empty();
+        let mut prefixes = prefixes.into_iter().rev();
+        let mut products = Vec::with_capacity(n);
+        for (x, prefix) in elements.into_iter().skip(1).rev().zip(prefixes.by_ref()) {
+            let next = acc.clone() * x;
+            products.push((prefix, acc));
+            acc = next;
+        }
+        products.extend(prefixes.next().map(|prefix| (prefix, acc)));
+        products.reverse();
+        products
+    }
+}
+
+impl<I: ?Sized> IterPrefixSuffixProducts for I where I: Iterator {}
@@ -0,0 +1,88 @@
+use alloc::vec::IntoIter;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// An extension trait that provides the [`shuffled`] and [`sample`] methods
+/// for iterators.
+///
+/// [`shuffled`]: IterRandom::shuffled
+/// [`sample`]: IterRandom::sample
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub trait IterRandom: Iterator {
+    /// Shuffles the iterator using the given random number generator.
+    ///
+    /// Simply collects into a [`Vec`] and shuffles it using rand's
+    /// [`shuffle`], which is a Fisher–Yates shuffle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterRandom;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let mut v = Vec::from_iter((0..10).shuffled(&mut rng));
+    /// v.sort();
+    /// assert_eq!(v, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    ///
+    /// [`shuffle`]: rand::seq::SliceRandom::shuffle
+    fn shuffled<R>(self, rng: &mut R) -> IntoIter<Self::Item>
+    where
+        Self: Sized,
+        R: Rng + ?Sized,
+    {
+        let mut v = Vec::from_iter(self);
+        v.shuffle(rng);
+        v.into_iter()
+    }
+
+    /// Returns `k` elements chosen uniformly at random from the iterator.
+    ///
+    /// This uses reservoir sampling, so the iterator is consumed in a single
+    /// pass in `O(n)` time and only `O(k)` memory is used, which makes it
+    /// suitable for iterators whose length is not known upfront. If the
+    /// iterator has `k` or fewer elements then all of them are returned in
+    /// their original order, otherwise the order of the returned elements is
+    /// not specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterRandom;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let v = (0..100).sample(3, &mut rng);
+    /// assert_eq!(v.len(), 3);
+    /// assert!(v.iter().all(|n| (0..100).contains(n)));
+    ///
+    /// let v = (0..2).sample(3, &mut rng);
+    /// assert_eq!(v, [0, 1]);
+    /// ```
+    fn sample<R>(self, k: usize, rng: &mut R) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        R: Rng + ?Sized,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut reservoir = Vec::with_capacity(k.min(self.size_hint().0));
+        for (i, item) in self.enumerate() {
+            if i < k {
+                reservoir.push(item);
+            } else {
+                // Each element replaces one in the reservoir with probability
+                // k / (i + 1), which keeps every element seen so far equally
+                // likely to be in the reservoir.
+                let j = rng.gen_range(0..=i);
+                if j < k {
+                    reservoir[j] = item;
+                }
+            }
+        }
+        reservoir
+    }
+}
+
+impl<I: ?Sized> IterRandom for I where I: Iterator {}
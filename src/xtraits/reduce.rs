@@ -0,0 +1,105 @@
+use core::iter;
+use core::iter::{Product, Sum};
+
+/// An extension trait that provides the [`checked_sum`] method and friends for
+/// iterators.
+///
+/// [`checked_sum`]: IterReduce::checked_sum
+#[cfg_attr(docsrs, doc(cfg(feature = "reduce")))]
+pub trait IterReduce: Iterator {
+    /// Sums the elements of the iterator.
+    ///
+    /// Unlike [`Iterator::sum`] this returns `None` if the iterator is empty
+    /// instead of the additive identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterReduce;
+    ///
+    /// assert_eq!([1, 2, 3].into_iter().checked_sum::<i32>(), Some(6));
+    /// assert_eq!([0; 0].into_iter().checked_sum::<i32>(), None);
+    /// ```
+    #[inline]
+    fn checked_sum<S>(mut self) -> Option<S>
+    where
+        Self: Sized,
+        S: Sum<Self::Item>,
+    {
+        let first = self.next()?;
+        Some(iter::once(first).chain(self).sum())
+    }
+
+    /// Multiplies the elements of the iterator.
+    ///
+    /// Unlike [`Iterator::product`] this returns `None` if the iterator is
+    /// empty instead of the multiplicative identity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterReduce;
+    ///
+    /// assert_eq!([2, 3, 4].into_iter().checked_product::<i32>(), Some(24));
+    /// assert_eq!([0; 0].into_iter().checked_product::<i32>(), None);
+    /// ```
+    #[inline]
+    fn checked_product<P>(mut self) -> Option<P>
+    where
+        Self: Sized,
+        P: Product<Self::Item>,
+    {
+        let first = self.next()?;
+        Some(iter::once(first).chain(self).product())
+    }
+
+    /// Sums the values returned by the given function for each element.
+    ///
+    /// See [`checked_sum`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterReduce;
+    ///
+    /// let words = ["lorem", "ipsum", "dolor"];
+    /// assert_eq!(words.iter().sum_by(|w| w.len()), Some(15));
+    /// ```
+    ///
+    /// [`checked_sum`]: IterReduce::checked_sum
+    #[inline]
+    fn sum_by<S, F, T>(self, f: F) -> Option<S>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+        S: Sum<T>,
+    {
+        self.map(f).checked_sum()
+    }
+
+    /// Multiplies the values returned by the given function for each element.
+    ///
+    /// See [`checked_product`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterReduce;
+    ///
+    /// let words = ["a", "ab", "abc"];
+    /// assert_eq!(words.iter().product_by(|w| w.len()), Some(6));
+    /// ```
+    ///
+    /// [`checked_product`]: IterReduce::checked_product
+    #[inline]
+    fn product_by<P, F, T>(self, f: F) -> Option<P>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+        P: Product<T>,
+    {
+        self.map(f).checked_product()
+    }
+}
+
+impl<I: ?Sized> IterReduce for I where I: Iterator {}
@@ -21,6 +21,26 @@ pub trait IterSorted: Iterator {
         v.into_iter()
     }
 
+    /// Sorts the iterator in parallel.
+    ///
+    /// Simply collects into a [`Vec`] and sorts it using rayon's
+    /// [`par_sort`].
+    ///
+    /// [`par_sort`]: rayon::slice::ParallelSliceMut::par_sort
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "sorted", feature = "rayon"))))]
+    fn par_sorted(self) -> IntoIter<Self::Item>
+    where
+        Self: Sized + Send,
+        Self::Item: Ord + Send,
+    {
+        use rayon::slice::ParallelSliceMut;
+
+        let mut v = Vec::from_iter(self);
+        v.par_sort();
+        v.into_iter()
+    }
+
     /// Sorts the iterator with a comparator function.
     ///
     /// Simply collects into a [`Vec`] and sorts it using [`slice::sort_by`].
@@ -108,6 +128,140 @@ pub trait IterSorted: Iterator {
         v.sort_unstable_by_key(f);
         v.into_iter()
     }
+
+    /// Sorts the iterator and pairs each element with its position in the
+    /// original iterator.
+    ///
+    /// Simply collects into a [`Vec`] of `(index, element)` pairs and sorts it
+    /// by element using [`slice::sort_by`]. The sort is stable so equal
+    /// elements keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterSorted;
+    ///
+    /// let v = [30, 10, 20].into_iter().sorted_with_indices();
+    /// assert_eq!(v, [(1, 10), (2, 20), (0, 30)]);
+    /// ```
+    fn sorted_with_indices(self) -> Vec<(usize, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v = Vec::from_iter(self.enumerate());
+        v.sort_by(|(_, a), (_, b)| a.cmp(b));
+        v
+    }
+
+    /// Returns the permutation of indices that would sort the iterator.
+    ///
+    /// This is sometimes known as "argsort". See
+    /// [`sorted_with_indices`][IterSorted::sorted_with_indices] for more
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterSorted;
+    ///
+    /// let v = [30, 10, 20].into_iter().sorted_indices();
+    /// assert_eq!(v, [1, 2, 0]);
+    /// ```
+    fn sorted_indices(self) -> Vec<usize>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        self.sorted_with_indices()
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Sorts the iterator by a key and folds each group of elements with equal
+    /// keys into a single value.
+    ///
+    /// Simply collects into a [`Vec`] of `(key, element)` pairs, sorts it by
+    /// key using [`slice::sort_by`] and then folds each run of equal keys
+    /// starting from `init()`. The key function is called exactly once for
+    /// each element and the elements of each group are folded in their
+    /// original order. The groups are yielded in ascending order of key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterSorted;
+    ///
+    /// let sales = [("pear", 3), ("apple", 2), ("pear", 1), ("apple", 5), ("fig", 4)];
+    /// let v: Vec<_> = sales
+    ///     .into_iter()
+    ///     .sorted_group_fold(|(fruit, _)| *fruit, |acc, (_, n)| acc + n, || 0)
+    ///     .collect();
+    /// assert_eq!(v, [("apple", 7), ("fig", 4), ("pear", 4)]);
+    /// ```
+    fn sorted_group_fold<K, B, F, G, H>(
+        self,
+        mut key: F,
+        mut fold: G,
+        mut init: H,
+    ) -> IntoIter<(K, B)>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+        G: FnMut(B, Self::Item) -> B,
+        H: FnMut() -> B,
+    {
+        let mut v = Vec::from_iter(self.map(|item| (key(&item), item)));
+        v.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut groups = Vec::new();
+        let mut curr = None;
+        for (k, item) in v {
+            curr = match curr.take() {
+                Some((ck, acc)) if ck == k => Some((ck, fold(acc, item))),
+                prev => {
+                    groups.extend(prev);
+                    Some((k, fold(init(), item)))
+                }
+            };
+        }
+        groups.extend(curr);
+        groups.into_iter()
+    }
+
+    /// Returns the element that would be at position `k` if the iterator was
+    /// sorted.
+    ///
+    /// Simply collects into a [`Vec`] and partially sorts it using
+    /// [`slice::select_nth_unstable`], which is based on quickselect and takes
+    /// `O(n)` time on average instead of the `O(n log n)` of a full sort.
+    /// Returns `None` if `k` is out of bounds. If several elements are equal
+    /// to the element at position `k` then any of them might be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterSorted;
+    ///
+    /// let data = [7, 1, 9, 4, 3];
+    /// assert_eq!(data.into_iter().select_nth(2), Some(4)); // the median
+    /// assert_eq!(data.into_iter().select_nth(0), Some(1));
+    /// assert_eq!(data.into_iter().select_nth(5), None);
+    /// ```
+    fn select_nth(self, k: usize) -> Option<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v = Vec::from_iter(self);
+        if k >= v.len() {
+            return None;
+        }
+        v.select_nth_unstable(k);
+        Some(v.swap_remove(k))
+    }
 }
 
 impl<I: ?Sized> IterSorted for I where I: Iterator {}
@@ -0,0 +1,149 @@
+use core::iter::Sum;
+
+/// The count, mean and variance of the elements of an iterator.
+///
+/// This struct is returned by the [`stats`] method on iterators. See its
+/// documentation for more.
+///
+/// [`stats`]: IterStats::stats
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The number of elements.
+    pub count: usize,
+
+    /// The arithmetic mean of the elements.
+    pub mean: f64,
+
+    /// The population variance of the elements, that is the mean of the
+    /// squared differences from the mean.
+    pub variance: f64,
+}
+
+impl Stats {
+    /// Returns the sample variance of the elements.
+    ///
+    /// This uses Bessel's correction, dividing by `count - 1` instead of
+    /// `count`, so it is an unbiased estimate of the variance of the
+    /// population the elements were sampled from. Returns `None` if there are
+    /// less than two elements.
+    #[inline]
+    pub fn sample_variance(&self) -> Option<f64> {
+        match self.count {
+            0 | 1 => None,
+            n => Some(self.variance * n as f64 / (n - 1) as f64),
+        }
+    }
+}
+
+/// An extension trait that provides the [`sum_count`] method and friends for
+/// iterators.
+///
+/// [`sum_count`]: IterStats::sum_count
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+pub trait IterStats: Iterator {
+    /// Returns the sum of the elements along with the number of elements.
+    ///
+    /// This consumes the iterator once, so both values can be computed from
+    /// an iterator that can't be iterated twice. An empty iterator returns the
+    /// empty sum, usually zero, and a count of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterStats;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().sum_count(), (14, 5));
+    /// assert_eq!([0u8; 0].into_iter().sum_count(), (0, 0));
+    /// ```
+    #[inline]
+    fn sum_count(self) -> (Self::Item, usize)
+    where
+        Self: Sized,
+        Self::Item: Sum,
+    {
+        let mut count = 0;
+        let sum = self.inspect(|_| count += 1).sum();
+        (sum, count)
+    }
+
+    /// Returns the arithmetic mean of the elements.
+    ///
+    /// The elements are converted to [`f64`] before they are summed, so this
+    /// works for any numeric type that converts losslessly into
+    /// [`f64`]. Returns `None` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::IterStats;
+    ///
+    /// assert_eq!([1, 2, 3, 4].into_iter().mean(), Some(2.5));
+    /// assert_eq!([0u8; 0].into_iter().mean(), None);
+    /// ```
+    #[inline]
+    fn mean(self) -> Option<f64>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let (sum, count) = self.map(Into::into).sum_count();
+        match count {
+            0 => None,
+            n => Some(sum / n as f64),
+        }
+    }
+
+    /// Returns the count, mean and population variance of the elements.
+    ///
+    /// These are computed in a single pass using Welford's algorithm, which
+    /// unlike summing the elements and their squares does not lose precision
+    /// when the variance is small compared to the mean. The elements are
+    /// converted to [`f64`] in the same way as [`mean`]. Returns `None` if the
+    /// iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use itermore::{IterStats, Stats};
+    ///
+    /// let stats = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().stats().unwrap();
+    /// assert_eq!(
+    ///     stats,
+    ///     Stats {
+    ///         count: 8,
+    ///         mean: 5.0,
+    ///         variance: 4.0
+    ///     }
+    /// );
+    /// assert_eq!(stats.sample_variance(), Some(32.0 / 7.0));
+    /// ```
+    ///
+    /// [`mean`]: IterStats::mean
+    fn stats(self) -> Option<Stats>
+    where
+        Self: Sized,
+        Self::Item: Into<f64>,
+    {
+        let mut count = 0;
+        let mut mean = 0.0;
+        // The sum of the squared differences from the current mean.
+        let mut m2 = 0.0;
+        for x in self.map(Into::<f64>::into) {
+            count += 1;
+            let delta = x - mean;
+            mean += delta / count as f64;
+            m2 += delta * (x - mean);
+        }
+        match count {
+            0 => None,
+            n => Some(Stats {
+                count,
+                mean,
+                variance: m2 / n as f64,
+            }),
+        }
+    }
+}
+
+impl<I: ?Sized> IterStats for I where I: Iterator {}
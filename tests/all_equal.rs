@@ -0,0 +1,46 @@
+#![cfg(feature = "all_equal")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn all_equal() {
+    assert!(iter::empty::<i32>().all_equal());
+    assert!([1].into_iter().all_equal());
+    assert!([1, 1, 1].into_iter().all_equal());
+    assert!(![1, 1, 2].into_iter().all_equal());
+    assert!(![2, 1, 1].into_iter().all_equal());
+}
+
+#[test]
+fn all_equal_short_circuits() {
+    let iter = [1, 2].into_iter().chain(iter::from_fn(|| {
+        panic!("consumed past the first unequal element")
+    }));
+    assert!(!iter.all_equal());
+}
+
+#[test]
+fn all_equal_value() {
+    assert_eq!(iter::empty::<i32>().all_equal_value(), Ok(None));
+    assert_eq!([1].into_iter().all_equal_value(), Ok(Some(1)));
+    assert_eq!([1, 1, 1].into_iter().all_equal_value(), Ok(Some(1)));
+    assert_eq!([1, 1, 2, 3].into_iter().all_equal_value(), Err((1, 2)));
+    assert_eq!([3, 1, 1].into_iter().all_equal_value(), Err((3, 1)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn all_unique() {
+    assert!(iter::empty::<i32>().all_unique());
+    assert!([1].into_iter().all_unique());
+    assert!([1, 2, 3].into_iter().all_unique());
+    assert!(![1, 2, 1].into_iter().all_unique());
+    assert!(!"hello".chars().all_unique());
+
+    let iter = [1, 2, 1].into_iter().chain(iter::from_fn(|| {
+        panic!("consumed past the first duplicate")
+    }));
+    assert!(!iter.all_unique());
+}
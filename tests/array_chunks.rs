@@ -119,6 +119,48 @@ fn array_chunks_nth() {
     assert_eq!(iter.nth(1), None);
 }
 
+#[allow(clippy::iter_nth_zero)]
+#[test]
+fn array_chunks_nth_matches_next() {
+    for len in 0..20 {
+        for n in 0..8 {
+            let mut iter = (0..len).array_chunks::<3>();
+            let mut expected = (0..len).array_chunks::<3>();
+            for _ in 0..n {
+                expected.next();
+            }
+            assert_eq!(iter.nth(n), expected.next(), "len = {}, n = {}", len, n);
+            assert_eq!(iter.next(), expected.next(), "len = {}, n = {}", len, n);
+        }
+    }
+}
+
+#[test]
+fn array_chunks_nth_skips_with_inner_nth() {
+    // The elements of skipped chunks are never produced by `next`.
+    let mut produced = 0;
+    let mut iter = iter::from_fn(|| {
+        produced += 1;
+        Some(produced)
+    })
+    .array_chunks::<4>();
+    assert_eq!(iter.nth(2), Some([9, 10, 11, 12]));
+    drop(iter);
+    assert_eq!(produced, 12);
+
+    // This would take a very long time if each chunk was collected.
+    let n = 1 << 28;
+    let mut iter = (0..usize::MAX).array_chunks::<4>();
+    assert_eq!(iter.nth(n), Some([4 * n, 4 * n + 1, 4 * n + 2, 4 * n + 3]));
+}
+
+#[test]
+fn array_chunks_nth_overflow() {
+    let mut iter = (0..10).array_chunks::<4>();
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn array_chunks_len() {
     let iter = (0..6).array_chunks::<1>();
@@ -148,3 +190,267 @@ fn array_chunks_len() {
     let iter = [(); usize::MAX].iter().array_chunks::<2>();
     assert_eq!(iter.len(), usize::MAX / 2);
 }
+
+#[test]
+fn array_chunks_into_parts() {
+    let mut iter = (0..7).array_chunks::<2>();
+    assert_eq!(iter.next(), Some([0, 1]));
+    let (inner, rem) = iter.into_parts();
+    assert!(rem.is_none());
+    assert_eq!(inner.collect::<Vec<_>>(), [2, 3, 4, 5, 6]);
+
+    let mut iter = (0..7).array_chunks::<2>();
+    assert_eq!(iter.by_ref().count(), 3);
+    assert_eq!(iter.next(), None);
+    let (mut inner, rem) = iter.into_parts();
+    assert_eq!(inner.next(), None);
+    assert_eq!(rem.unwrap().collect::<Vec<_>>(), [6]);
+
+    let mut iter = (0..7).array_chunks::<2>();
+    assert_eq!(iter.next(), Some([0, 1]));
+    assert_eq!(iter.next_back(), Some([4, 5]));
+    let (inner, rem) = iter.into_parts();
+    assert_eq!(inner.collect::<Vec<_>>(), [2, 3]);
+    assert_eq!(rem.unwrap().collect::<Vec<_>>(), [6]);
+}
+
+#[test]
+fn array_chunks_padded() {
+    let v = Vec::from_iter([1, 2, 3].into_iter().array_chunks_padded::<2>(0));
+    assert_eq!(v, [[1, 2], [3, 0]]);
+
+    let v = Vec::from_iter([1, 2, 3, 4].into_iter().array_chunks_padded::<2>(0));
+    assert_eq!(v, [[1, 2], [3, 4]]);
+
+    let v = Vec::from_iter([1].into_iter().array_chunks_padded::<4>(0));
+    assert_eq!(v, [[1, 0, 0, 0]]);
+
+    let v = Vec::from_iter(iter::empty::<i32>().array_chunks_padded::<2>(0));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_chunks_padded_size_hint() {
+    let mut iter = (0..5).array_chunks_padded::<2>(0);
+    for len in (0..=3).rev() {
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        assert_eq!(iter.len(), len);
+        iter.next();
+    }
+}
+
+#[test]
+fn array_chunks_remainder_next_back() {
+    let mut iter = (0..11).array_chunks::<4>();
+    assert_eq!(iter.by_ref().count(), 2);
+    let mut rem = iter.into_remainder().unwrap();
+    assert_eq!(rem.next_back(), Some(10));
+    assert_eq!(rem.next(), Some(8));
+    assert_eq!(rem.next_back(), Some(9));
+    assert_eq!(rem.next_back(), None);
+    assert_eq!(rem.next(), None);
+}
+
+#[test]
+fn array_chunks_remainder_drop() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct Foo;
+
+    impl Drop for Foo {
+        fn drop(&mut self) {
+            DROPS.with(|d| d.set(d.get() + 1));
+        }
+    }
+
+    let mut iter = iter::repeat_with(|| Foo).take(7).array_chunks::<4>();
+    drop(iter.next());
+    assert_eq!(DROPS.with(|d| d.get()), 4);
+    assert!(iter.next().is_none());
+
+    // Partially consume the remainder from the back then drop the rest.
+    let mut rem = iter.into_remainder().unwrap();
+    assert_eq!(rem.len(), 3);
+    drop(rem.next_back());
+    assert_eq!(DROPS.with(|d| d.get()), 5);
+    drop(rem);
+    assert_eq!(DROPS.with(|d| d.get()), 7);
+}
+
+#[test]
+fn enumerate_array_chunks() {
+    let mut iter = (0..11).enumerate_array_chunks::<3>();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some((0, [0, 1, 2])));
+    assert_eq!(iter.next(), Some((1, [3, 4, 5])));
+    assert_eq!(iter.next(), Some((2, [6, 7, 8])));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), [9, 10]);
+
+    let mut iter = (0..6).enumerate_array_chunks::<3>();
+    iter.by_ref().for_each(drop);
+    assert!(iter.into_remainder().unwrap().as_slice().is_empty());
+
+    let iter = (0..6).enumerate_array_chunks::<3>();
+    assert!(iter.into_remainder().is_none());
+}
+
+#[test]
+fn enumerate_array_chunks_next_back() {
+    let mut iter = (0..11).enumerate_array_chunks::<3>();
+    assert_eq!(iter.next_back(), Some((2, [6, 7, 8])));
+    assert_eq!(iter.next(), Some((0, [0, 1, 2])));
+    assert_eq!(iter.next_back(), Some((1, [3, 4, 5])));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), [9, 10]);
+}
+
+#[test]
+fn enumerate_array_chunks_debug_clone() {
+    let mut iter = (0..6).enumerate_array_chunks::<2>();
+    iter.next();
+    let _ = format!("{:?}", iter);
+    assert_eq!(iter.clone().next(), Some((1, [2, 3])));
+}
+
+#[test]
+fn array_chunks_aligned() {
+    let (first, mut iter) = (0..7).array_chunks_aligned::<2>(1);
+    assert_eq!(first.as_slice(), [0]);
+    assert_eq!(iter.next(), Some([1, 2]));
+    assert_eq!(iter.next(), Some([3, 4]));
+    assert_eq!(iter.next(), Some([5, 6]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), []);
+}
+
+#[test]
+fn array_chunks_aligned_full_or_empty_first_chunk() {
+    let (first, iter) = (0..5).array_chunks_aligned::<2>(2);
+    assert_eq!(first.as_slice(), [0, 1]);
+    assert_eq!(Vec::from_iter(iter), [[2, 3]]);
+
+    let (first, iter) = (0..5).array_chunks_aligned::<2>(0);
+    assert_eq!(first.as_slice(), []);
+    assert_eq!(Vec::from_iter(iter), [[0, 1], [2, 3]]);
+}
+
+#[test]
+fn array_chunks_aligned_short_iterator() {
+    let (first, mut iter) = (0..2).array_chunks_aligned::<4>(3);
+    assert_eq!(first.as_slice(), [0, 1]);
+    assert_eq!(iter.next(), None);
+
+    let (first, mut iter) = iter::empty::<i32>().array_chunks_aligned::<2>(1);
+    assert_eq!(first.as_slice(), []);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn array_chunks_aligned_first_chunk_too_large() {
+    let _ = (0..5).array_chunks_aligned::<2>(3);
+}
+
+#[test]
+#[should_panic]
+fn array_chunks_aligned_zero() {
+    let _ = (0..5).array_chunks_aligned::<0>(0);
+}
+
+#[test]
+fn array_chunks_by_key() {
+    let data = [('a', 1), ('a', 2), ('b', 3), ('b', 4)];
+    let v = Vec::from_iter(data.into_iter().array_chunks_by_key::<2, _, _>(|&(k, _)| k));
+    assert_eq!(v, [[('a', 1), ('a', 2)], [('b', 3), ('b', 4)]]);
+}
+
+#[test]
+fn array_chunks_by_key_discards_partial() {
+    let data = [
+        ('a', 1),
+        ('b', 2),
+        ('b', 3),
+        ('b', 4),
+        ('a', 5),
+        ('a', 6),
+        ('c', 7),
+    ];
+    let v = Vec::from_iter(data.into_iter().array_chunks_by_key::<2, _, _>(|&(k, _)| k));
+    assert_eq!(v, [[('b', 2), ('b', 3)], [('a', 5), ('a', 6)]]);
+
+    let v = Vec::from_iter(data.into_iter().array_chunks_by_key::<3, _, _>(|&(k, _)| k));
+    assert_eq!(v, [[('b', 2), ('b', 3), ('b', 4)]]);
+
+    let v = Vec::from_iter(data.into_iter().array_chunks_by_key::<4, _, _>(|&(k, _)| k));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_chunks_by_key_single() {
+    let v = Vec::from_iter((0..5).array_chunks_by_key::<1, _, _>(|n| n / 2));
+    assert_eq!(v, [[0], [1], [2], [3], [4]]);
+
+    let v = Vec::from_iter((0..7).array_chunks_by_key::<2, _, _>(|_| ()));
+    assert_eq!(v, [[0, 1], [2, 3], [4, 5]]);
+}
+
+#[test]
+fn array_chunks_by_key_calls_key_once() {
+    let mut calls = 0;
+    let v = Vec::from_iter((0..10).array_chunks_by_key::<3, _, _>(|n| {
+        calls += 1;
+        n / 4
+    }));
+    assert_eq!(v, [[0, 1, 2], [4, 5, 6]]);
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn array_chunks_by_key_debug_clone() {
+    let mut iter = (0..6).array_chunks_by_key::<2, _, _>(|n| n / 3);
+    assert_eq!(iter.next(), Some([0, 1]));
+    let _ = format!("{:?}", iter);
+    assert_eq!(Vec::from_iter(iter.clone()), Vec::from_iter(iter));
+}
+
+#[test]
+#[should_panic]
+fn array_chunks_by_key_zero() {
+    let _ = (0..6).array_chunks_by_key::<0, _, _>(|n| *n);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_array_chunks_map() {
+    use std::thread;
+    use std::time::Duration;
+
+    let (v, rem) = (0..1000).par_array_chunks_map(|[a, b, c]: [u64; 3]| {
+        // Vary the time taken so that the chunks finish out of order.
+        thread::sleep(Duration::from_micros((a * 7919) % 50));
+        a * b + c
+    });
+    let w = Vec::from_iter(
+        (0..1000)
+            .array_chunks()
+            .map(|[a, b, c]: [u64; 3]| a * b + c),
+    );
+    assert_eq!(v, w);
+    assert_eq!(rem.as_slice(), [999]);
+
+    let (v, rem) = (0..2).par_array_chunks_map(|c: [i32; 3]| c);
+    assert!(v.is_empty());
+    assert_eq!(rem.as_slice(), [0, 1]);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+#[should_panic]
+fn par_array_chunks_map_zero() {
+    let _ = (0..6).par_array_chunks_map(|_: [i32; 0]| 0);
+}
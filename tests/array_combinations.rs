@@ -66,3 +66,261 @@ fn array_combinations_edge_cases() {
     assert!(it.next().is_none());
     assert!(it.next().is_none());
 }
+
+#[test]
+fn array_combinations_fill_next_into() {
+    let mut iter = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(String::from)
+        .array_combinations::<3>();
+    let mut buf = [String::new(), String::new(), String::new()];
+    assert!(iter.fill_next_into(&mut buf));
+    let ptrs: Vec<_> = buf.iter().map(|s| s.as_ptr()).collect();
+    let mut v = vec![buf.concat()];
+    while iter.fill_next_into(&mut buf) {
+        v.push(buf.concat());
+    }
+    assert_eq!(v, ["abc", "abd", "acd", "bcd"]);
+    assert!(!iter.fill_next_into(&mut buf));
+    // The existing allocations should have been reused
+    let new_ptrs: Vec<_> = buf.iter().map(|s| s.as_ptr()).collect();
+    assert_eq!(new_ptrs, ptrs);
+}
+
+#[test]
+fn array_combinations_total() {
+    for n in 0..8 {
+        let mut iter = (0..n).array_combinations::<3>();
+        let total = iter.total().unwrap();
+        assert_eq!(total, (0..n).array_combinations::<3>().count() as u128);
+    }
+}
+
+#[test]
+fn array_combinations_resume_from() {
+    for n in 0..7 {
+        let expected: Vec<_> = (0..n).array_combinations::<3>().collect();
+        for i in 0..=expected.len() {
+            let mut iter = (0..n).array_combinations::<3>();
+            iter.by_ref().take(i).for_each(drop);
+            let iter = itermore::ArrayCombinations::<_, 3>::resume_from(0..n, iter.state());
+            assert_eq!(iter.collect::<Vec<_>>(), expected[i..]);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn array_combinations_resume_from_wrong_len() {
+    let iter = (0..5).array_combinations::<2>();
+    let _ = itermore::ArrayCombinations::<_, 3>::resume_from(0..5, iter.state());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn array_combinations_state_serde() {
+    let mut iter = "abcde".chars().array_combinations::<2>();
+    iter.by_ref().take(3).for_each(drop);
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: itermore::CombinationsState = serde_json::from_str(&json).unwrap();
+
+    let resumed = itermore::ArrayCombinations::<_, 2>::resume_from("abcde".chars(), state);
+    assert!(resumed.eq(iter));
+}
+
+#[test]
+fn array_combinations_produced() {
+    for n in 0..8 {
+        let mut iter = (0..n).array_combinations::<3>();
+        assert_eq!(iter.produced(), 0);
+        let mut i = 0;
+        while iter.next().is_some() {
+            i += 1;
+            assert_eq!(iter.produced(), i);
+        }
+        assert_eq!(iter.produced(), i);
+    }
+
+    let mut iter = (0..10).array_combinations::<4>();
+    iter.by_ref().take(20).for_each(drop);
+    let state = iter.state();
+    let resumed = itermore::ArrayCombinations::<_, 4>::resume_from(0..10, state);
+    assert_eq!(resumed.produced(), 20);
+}
+
+#[test]
+fn array_index_combinations() {
+    let v = Vec::from_iter("abcd".chars().array_index_combinations::<2>());
+    assert_eq!(v, [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]]);
+}
+
+#[test]
+fn array_index_combinations_matches_array_combinations() {
+    for n in 0..7 {
+        let expected = Vec::from_iter((0..n).array_combinations::<3>());
+        let v = Vec::from_iter((0..n).array_index_combinations::<3>());
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+fn array_index_combinations_not_clone() {
+    struct NotClone;
+
+    let mut iter = [NotClone, NotClone, NotClone]
+        .into_iter()
+        .array_index_combinations::<2>();
+    assert_eq!(iter.next(), Some([0, 1]));
+    assert_eq!(iter.next(), Some([0, 2]));
+    assert_eq!(iter.next(), Some([1, 2]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn array_index_combinations_zero_k() {
+    let _ = (0..6).array_index_combinations::<0>();
+}
+
+#[test]
+fn array_combinations_bounded_infinite() {
+    let v = Vec::from_iter((0..).array_combinations_bounded::<3>(5));
+    assert_eq!(v, [[0, 1, 2], [0, 1, 3], [0, 1, 4]]);
+
+    let v = Vec::from_iter((0..).array_combinations_bounded::<1>(3));
+    assert_eq!(v, [[0], [1], [2]]);
+}
+
+#[test]
+fn array_combinations_bounded_stops_consuming() {
+    let mut consumed = 0;
+    let mut iter = (0..)
+        .inspect(|_| consumed += 1)
+        .array_combinations_bounded::<2>(3);
+    assert_eq!(iter.next(), Some([0, 1]));
+    assert_eq!(iter.next(), Some([0, 2]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(consumed, 4);
+}
+
+#[test]
+fn array_combinations_bounded_within_limit() {
+    for max_buffer in [4, 5, 100, usize::MAX] {
+        let v = Vec::from_iter((0..4).array_combinations_bounded::<2>(max_buffer));
+        let w = Vec::from_iter((0..4).array_combinations::<2>());
+        assert_eq!(v, w);
+    }
+}
+
+#[test]
+fn array_combinations_bounded_k_greater_than_limit() {
+    let mut consumed = 0;
+    let mut iter = (0..)
+        .inspect(|_| consumed += 1)
+        .array_combinations_bounded::<3>(2);
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(consumed, 0);
+}
+
+#[test]
+#[should_panic]
+fn array_combinations_bounded_zero_k() {
+    let _ = (0..6).array_combinations_bounded::<0>(3);
+}
+
+#[test]
+fn array_combinations_filtered_accept_all() {
+    let v = Vec::from_iter((0..7).array_combinations_filtered::<3, _>(|_| true));
+    let w = Vec::from_iter((0..7).array_combinations::<3>());
+    assert_eq!(v, w);
+
+    let v = Vec::from_iter((0..5).array_combinations_filtered::<1, _>(|_| true));
+    assert_eq!(v, [[0], [1], [2], [3], [4]]);
+
+    let v = Vec::from_iter((0..2).array_combinations_filtered::<3, _>(|_| true));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_combinations_filtered_never_yields_pruned() {
+    let accept = |c: &[i32]| c.iter().sum::<i32>() % 3 != 0 || c.len() == 1;
+    let v = Vec::from_iter((0..8).array_combinations_filtered::<3, _>(accept));
+    let w = Vec::from_iter(
+        (0..8)
+            .array_combinations::<3>()
+            .filter(|c| (1..=3).all(|n| accept(&c[..n]))),
+    );
+    assert!(!v.is_empty());
+    assert_eq!(v, w);
+}
+
+#[test]
+fn array_combinations_filtered_never_extends_rejected() {
+    let mut calls = Vec::new();
+    let accept = |c: &[i32]| c.first() != Some(&1) && !c.ends_with(&[3, 4]);
+    let v = Vec::from_iter((0..6).array_combinations_filtered::<3, _>(|c: &[i32]| {
+        calls.push(c.to_vec());
+        accept(c)
+    }));
+    assert_eq!(
+        v,
+        [
+            [0, 1, 2],
+            [0, 1, 3],
+            [0, 1, 4],
+            [0, 1, 5],
+            [0, 2, 3],
+            [0, 2, 4],
+            [0, 2, 5],
+            [0, 3, 5],
+            [0, 4, 5],
+            [2, 3, 5],
+            [2, 4, 5]
+        ]
+    );
+    for call in &calls {
+        for n in 1..call.len() {
+            assert!(
+                accept(&call[..n]),
+                "rejected prefix was extended: {:?}",
+                call
+            );
+        }
+    }
+    // Each prefix is only tried once.
+    let mut deduped = calls.clone();
+    deduped.sort();
+    deduped.dedup();
+    assert_eq!(deduped.len(), calls.len());
+}
+
+#[test]
+fn array_combinations_filtered_reject_all() {
+    let mut calls = 0;
+    let v = Vec::from_iter((0..6).array_combinations_filtered::<2, _>(|_| {
+        calls += 1;
+        false
+    }));
+    assert!(v.is_empty());
+    // Only the first position is tried for each element, the last element is
+    // also tried because the iterator is not yet known to be exhausted.
+    assert_eq!(calls, 6);
+}
+
+#[test]
+fn array_combinations_filtered_debug_clone() {
+    let mut iter = (0..5).array_combinations_filtered::<2, _>(|c: &[i32]| c[0] != 1);
+    assert_eq!(iter.next(), Some([0, 1]));
+    let _ = format!("{:?}", iter);
+    assert_eq!(Vec::from_iter(iter.clone()), Vec::from_iter(iter));
+}
+
+#[test]
+#[should_panic]
+fn array_combinations_filtered_zero_k() {
+    let _ = (0..6).array_combinations_filtered::<0, _>(|_| true);
+}
@@ -70,3 +70,57 @@ fn array_combinations_with_reps_smoke() {
     let v = Vec::from_iter((1..5).array_combinations_with_reps::<4>());
     assert_eq!(v.len(), 256);
 }
+
+#[test]
+fn array_combinations_with_reps_rev() {
+    let v = Vec::from_iter((0..2).array_combinations_with_reps::<2>().rev());
+    assert_eq!(v, [[1, 1], [1, 0], [0, 1], [0, 0]]);
+
+    let v = Vec::from_iter((0..0).array_combinations_with_reps::<2>().rev());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_combinations_with_reps_double_ended() {
+    let mut iter = (0..2).array_combinations_with_reps::<2>();
+    assert_eq!(iter.next_back(), Some([1, 1]));
+    assert_eq!(iter.next(), Some([0, 0]));
+    assert_eq!(iter.next(), Some([0, 1]));
+    assert_eq!(iter.next_back(), Some([1, 0]));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn array_combinations_with_reps_size_hint() {
+    let mut iter = (0..3).array_combinations_with_reps::<3>();
+    for remaining in (0..=27).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+
+    let mut iter = (0..3).array_combinations_with_reps::<2>();
+    iter.next_back();
+    iter.next_back();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+}
+
+#[test]
+fn array_combinations_with_reps_size_hint_overflow() {
+    let mut iter = (0..usize::MAX).array_combinations_with_reps::<2>();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    iter.next();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn array_combinations_with_reps_total() {
+    for n in 0..6 {
+        let mut iter = (0..n).array_combinations_with_reps::<3>();
+        let total = iter.total().unwrap();
+        assert_eq!(
+            total,
+            (0..n).array_combinations_with_reps::<3>().count() as u128
+        );
+    }
+}
@@ -3,6 +3,7 @@
 use core::iter;
 
 use itermore::prelude::*;
+use itermore::ArrayWindows;
 
 #[test]
 fn array_windows_debug() {
@@ -112,3 +113,351 @@ fn array_windows_len() {
     let iter = [(); usize::MAX].iter().array_windows::<2>();
     assert_eq!(iter.len(), usize::MAX - 1);
 }
+
+#[test]
+fn array_windows_size_hint_after_next() {
+    let mut iter = (0..6).array_windows::<3>();
+    for len in (0..=4).rev() {
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        assert_eq!(iter.len(), len);
+        assert_eq!(iter.clone().count(), len);
+        iter.next();
+    }
+}
+
+#[test]
+fn array_windows_rev() {
+    let v = Vec::from_iter((0..5).array_windows::<3>().rev());
+    assert_eq!(v, [[2, 3, 4], [1, 2, 3], [0, 1, 2]]);
+
+    let v = Vec::from_iter((0..2).array_windows::<3>().rev());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn array_windows_double_ended() {
+    let mut iter = (0..5).array_windows::<3>();
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next_back(), Some([2, 3, 4]));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some([1, 2, 3]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    // Every way of interleaving front and back yields all the windows.
+    for len in 0..7 {
+        let expected = Vec::from_iter((0..len).array_windows::<3>());
+        for pattern in 0..(1u32 << (expected.len() + 1)) {
+            let mut iter = (0..len).array_windows::<3>();
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for i in 0.. {
+                assert_eq!(iter.len(), expected.len() - front.len() - back.len());
+                let item = if pattern & (1 << i) == 0 {
+                    iter.next().map(|w| front.push(w))
+                } else {
+                    iter.next_back().map(|w| back.push(w))
+                };
+                if item.is_none() {
+                    break;
+                }
+            }
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+        }
+    }
+}
+
+#[test]
+fn array_windows_rfind() {
+    let data = [5, 1, 2, 3, 1, 0, 4, 2, 1];
+
+    let mut iter = data.into_iter().array_windows();
+    assert_eq!(iter.rfind(|[a, b]| a < b), Some([0, 4]));
+    assert_eq!(iter.next_back(), Some([1, 0]));
+
+    let mut iter = data.into_iter().array_windows::<3>();
+    assert_eq!(iter.rfind(|[a, b, c]| a < b && b < c), Some([1, 2, 3]));
+    assert_eq!(iter.rfind(|[a, b, c]| a < b && b < c), None);
+}
+
+#[test]
+fn array_windows_rposition() {
+    let data = [5, 1, 2, 3, 1, 0, 4, 2, 1];
+
+    let mut iter = data.into_iter().array_windows();
+    assert_eq!(iter.rposition(|[a, b]| a < b), Some(5));
+    assert_eq!(iter.rposition(|[a, b]| a < b), Some(2));
+
+    let mut iter = data.into_iter().array_windows();
+    iter.next();
+    assert_eq!(iter.rposition(|[a, b]| a < b), Some(4));
+    assert_eq!(iter.rposition(|[a, b]| a > b), Some(3));
+    assert_eq!(iter.rposition(|_: [i32; 2]| true), Some(2));
+    assert_eq!(iter.rposition(|_: [i32; 2]| false), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn array_windows_with_prefix() {
+    let mut iter = ArrayWindows::with_prefix(3..6, [0, 1, 2]);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(Vec::from_iter(iter), [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+
+    let mut iter = ArrayWindows::with_prefix(iter::empty(), [0, 1]);
+    assert_eq!(iter.next(), Some([0, 1]));
+    assert_eq!(iter.next(), None);
+
+    // The same windows are yielded as if the prefix was part of the iterator.
+    for len in 0..5 {
+        let expected = Vec::from_iter((0..len + 3).array_windows::<3>());
+        for pattern in 0..(1u32 << (expected.len() + 1)) {
+            let mut iter = ArrayWindows::with_prefix(3..len + 3, [0, 1, 2]);
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for i in 0.. {
+                assert_eq!(iter.len(), expected.len() - front.len() - back.len());
+                let item = if pattern & (1 << i) == 0 {
+                    iter.next().map(|w| front.push(w))
+                } else {
+                    iter.next_back().map(|w| back.push(w))
+                };
+                if item.is_none() {
+                    break;
+                }
+            }
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+        }
+    }
+}
+
+#[test]
+fn array_windows_into_inner() {
+    let mut iter = (0..8).array_windows::<3>();
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next(), Some([1, 2, 3]));
+    assert_eq!(Vec::from_iter(iter.into_inner()), [4, 5, 6, 7]);
+
+    let iter = (0..8).array_windows::<3>();
+    assert_eq!(Vec::from_iter(iter.into_inner()), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let mut iter = (0..8).array_windows::<3>();
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next_back(), Some([5, 6, 7]));
+    assert_eq!(Vec::from_iter(iter.into_inner()), [3, 4]);
+
+    let mut iter = ArrayWindows::with_prefix(3..8, [0, 1, 2]);
+    assert_eq!(iter.next(), Some([0, 1, 2]));
+    assert_eq!(iter.next(), Some([1, 2, 3]));
+    assert_eq!(Vec::from_iter(iter.into_inner()), [4, 5, 6, 7]);
+}
+
+#[test]
+fn indexed_array_windows() {
+    let iter = "abcde".chars().indexed_array_windows::<2>();
+    assert_eq!(
+        Vec::from_iter(iter),
+        [
+            (0, ['a', 'b']),
+            (1, ['b', 'c']),
+            (2, ['c', 'd']),
+            (3, ['d', 'e'])
+        ]
+    );
+
+    let mut iter = (10..20).indexed_array_windows::<3>();
+    assert_eq!(iter.len(), 8);
+    for i in 0..8 {
+        let (index, window) = iter.next().unwrap();
+        assert_eq!(index, i);
+        assert_eq!(window[0], 10 + i);
+    }
+    assert_eq!(iter.next(), None);
+
+    let iter = (0..2).indexed_array_windows::<3>();
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn indexed_array_windows_next_back() {
+    let mut iter = (0..6).indexed_array_windows::<3>();
+    assert_eq!(iter.next_back(), Some((3, [3, 4, 5])));
+    assert_eq!(iter.next(), Some((0, [0, 1, 2])));
+    assert_eq!(iter.next_back(), Some((2, [2, 3, 4])));
+    assert_eq!(iter.next(), Some((1, [1, 2, 3])));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn indexed_array_windows_debug_clone() {
+    let mut iter = (0..4).indexed_array_windows::<2>();
+    iter.next();
+    let _ = format!("{:?}", iter);
+    assert_eq!(iter.clone().next(), Some((1, [1, 2])));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn array_windows_copy_and_clone_match_slice_windows() {
+    fn check<const N: usize>(len: usize) {
+        let nums = Vec::from_iter(0..len);
+        let strings = Vec::from_iter(nums.iter().map(|n| n.to_string()));
+
+        let expected = Vec::from_iter(nums.windows(N).map(|w| <[usize; N]>::try_from(w).unwrap()));
+        let copied = Vec::from_iter(nums.iter().copied().array_windows::<N>());
+        assert_eq!(copied, expected);
+
+        let expected = Vec::from_iter(strings.windows(N).map(|w| w.to_vec()));
+        let cloned = Vec::from_iter(strings.iter().cloned().array_windows::<N>().map(Vec::from));
+        assert_eq!(cloned, expected);
+
+        let expected = Vec::from_iter(
+            nums.windows(N)
+                .rev()
+                .map(|w| <[usize; N]>::try_from(w).unwrap()),
+        );
+        let copied = Vec::from_iter(nums.iter().copied().array_windows::<N>().rev());
+        assert_eq!(copied, expected);
+    }
+
+    for len in 0..12 {
+        check::<1>(len);
+        check::<2>(len);
+        check::<3>(len);
+        check::<5>(len);
+        check::<8>(len);
+    }
+}
+
+#[test]
+fn array_windows_for_each() {
+    let mut out = [0; 8];
+    let n = (1..=6).array_windows_for_each(&mut out, |[a, b, c]: &[i32; 3]| a + b + c);
+    assert_eq!(n, 4);
+    assert_eq!(out, [6, 9, 12, 15, 0, 0, 0, 0]);
+
+    let mut out = [[0; 2]; 3];
+    let n = (0..).array_windows_for_each(&mut out, |w: &[i32; 2]| *w);
+    assert_eq!(n, 3);
+    assert_eq!(out, [[0, 1], [1, 2], [2, 3]]);
+}
+
+#[test]
+fn array_windows_for_each_short() {
+    let mut out = [0; 4];
+    let n = (0..2).array_windows_for_each(&mut out, |_: &[i32; 3]| 1);
+    assert_eq!(n, 0);
+    assert_eq!(out, [0; 4]);
+
+    let n = iter::empty::<i32>().array_windows_for_each(&mut out, |_: &[i32; 1]| 1);
+    assert_eq!(n, 0);
+}
+
+#[test]
+fn array_windows_for_each_slice_full() {
+    let mut iter = 0..10;
+    let mut out = [0; 2];
+    let n = iter
+        .by_ref()
+        .array_windows_for_each(&mut out, |[a, b]: &[i32; 2]| a * b);
+    assert_eq!(n, 2);
+    assert_eq!(out, [0, 2]);
+    assert_eq!(iter.next(), Some(3));
+
+    let mut iter = 0..10;
+    let n = iter
+        .by_ref()
+        .array_windows_for_each(&mut [0; 0], |_: &[i32; 2]| 0);
+    assert_eq!(n, 0);
+    assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn array_windows_for_each_not_clone() {
+    struct NotClone(i32);
+
+    let mut out = [0; 3];
+    let n = (0..4)
+        .map(NotClone)
+        .array_windows_for_each(&mut out, |[a, b]: &[NotClone; 2]| a.0 + b.0);
+    assert_eq!(n, 3);
+    assert_eq!(out, [1, 3, 5]);
+}
+
+#[test]
+#[should_panic]
+fn array_windows_for_each_zero() {
+    (0..6).array_windows_for_each(&mut [0; 4], |_: &[i32; 0]| 0);
+}
+
+#[test]
+fn gated_array_windows() {
+    let v = Vec::from_iter((1..=8).gated_array_windows::<2, _>(|n| n % 2 == 0));
+    assert_eq!(v, [[1, 2], [3, 4], [5, 6], [7, 8]]);
+
+    let v = Vec::from_iter((1..=8).gated_array_windows::<4, _>(|n| n % 2 == 0));
+    assert_eq!(v, [[1, 2, 3, 4], [3, 4, 5, 6], [5, 6, 7, 8]]);
+
+    let v = Vec::from_iter(
+        [2, 4, 5, 6, 7, 7, 8]
+            .into_iter()
+            .gated_array_windows(|n| n % 2 == 0),
+    );
+    assert_eq!(v, [[2, 4], [5, 6], [7, 8]]);
+}
+
+#[test]
+fn gated_array_windows_matches_filter() {
+    let data = [3, 8, 2, 5, 0, 1, 1, 6, 4, 9];
+    for gate in [
+        |n: &i32| n % 2 == 0,
+        |n: &i32| *n > 4,
+        |_: &i32| true,
+        |_: &i32| false,
+    ] {
+        let v = Vec::from_iter(data.into_iter().gated_array_windows::<3, _>(gate));
+        let w = Vec::from_iter(
+            data.into_iter()
+                .array_windows::<3>()
+                .filter(|w| gate(&w[2])),
+        );
+        assert_eq!(v, w);
+    }
+}
+
+#[test]
+fn gated_array_windows_calls_gate_once() {
+    let mut calls = 0;
+    let v = Vec::from_iter((0..10).gated_array_windows::<4, _>(|n| {
+        calls += 1;
+        n % 3 == 0
+    }));
+    assert_eq!(v, [[0, 1, 2, 3], [3, 4, 5, 6], [6, 7, 8, 9]]);
+    assert_eq!(calls, 7);
+}
+
+#[test]
+fn gated_array_windows_short() {
+    let v = Vec::from_iter((0..2).gated_array_windows::<3, _>(|_| true));
+    assert!(v.is_empty());
+    let iter = (0..10).gated_array_windows::<3, _>(|_| true);
+    assert_eq!(iter.size_hint(), (0, Some(8)));
+}
+
+#[test]
+fn gated_array_windows_debug_clone() {
+    let mut iter = (0..10).gated_array_windows::<2, _>(|n| n % 2 == 0);
+    assert_eq!(iter.next(), Some([1, 2]));
+    let _ = format!("{:?}", iter);
+    assert_eq!(Vec::from_iter(iter.clone()), Vec::from_iter(iter));
+}
+
+#[test]
+#[should_panic]
+fn gated_array_windows_zero() {
+    let _ = (0..6).gated_array_windows::<0, _>(|_| true);
+}
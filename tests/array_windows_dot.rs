@@ -0,0 +1,59 @@
+#![cfg(feature = "array_windows_dot")]
+
+use itermore::IterArrayWindowsDot;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn check<const N: usize>(data: &[i64], kernel: [i64; N]) {
+    let v: Vec<_> = data.iter().copied().array_windows_dot(kernel).collect();
+    let expected: Vec<i64> = data
+        .windows(N)
+        .map(|w| w.iter().zip(&kernel).map(|(x, k)| x * k).sum())
+        .collect();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn array_windows_dot_basic() {
+    let v: Vec<_> = [1, 2, 3, 4].into_iter().array_windows_dot([1, 1]).collect();
+    assert_eq!(v, [3, 5, 7]);
+}
+
+#[test]
+fn array_windows_dot_random() {
+    let mut rng = StdRng::seed_from_u64(1895);
+    for _ in 0..100 {
+        let data: Vec<i64> = (0..rng.gen_range(0..40))
+            .map(|_| rng.gen_range(-100..100))
+            .collect();
+        check(&data, [rng.gen_range(-5..5)]);
+        check(&data, [1, -2, 1]);
+        check(&data, [rng.gen_range(-5..5), 3, 0, rng.gen_range(-5..5), 7]);
+    }
+}
+
+#[test]
+fn array_windows_dot_floats() {
+    let v: Vec<f64> = [1.0, 2.0, 4.0, 8.0]
+        .into_iter()
+        .array_windows_dot([0.5, 0.5])
+        .collect();
+    assert_eq!(v, [1.5, 3.0, 6.0]);
+}
+
+#[test]
+fn array_windows_dot_too_short() {
+    let mut iter = [1, 2].into_iter().array_windows_dot([1, 1, 1]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn array_windows_dot_size_hint() {
+    let mut iter = (0..6).array_windows_dot([1, 2]);
+    assert_eq!(iter.len(), 5);
+    iter.next();
+    assert_eq!(iter.len(), 4);
+    let _ = format!("{:?}", iter.clone());
+    assert_eq!(iter.count(), 4);
+}
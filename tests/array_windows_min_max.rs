@@ -0,0 +1,60 @@
+#![cfg(feature = "array_windows_min_max")]
+
+use itermore::prelude::*;
+
+fn check<const N: usize>(data: &[u8]) {
+    let mins = Vec::from_iter(data.iter().copied().array_windows_min::<N>());
+    let maxs = Vec::from_iter(data.iter().copied().array_windows_max::<N>());
+    let naive_mins = Vec::from_iter(data.windows(N).map(|w| *w.iter().min().unwrap()));
+    let naive_maxs = Vec::from_iter(data.windows(N).map(|w| *w.iter().max().unwrap()));
+    assert_eq!(mins, naive_mins);
+    assert_eq!(maxs, naive_maxs);
+}
+
+#[test]
+fn array_windows_min_max_random() {
+    use rand::prelude::*;
+    let mut rng = StdRng::seed_from_u64(1863);
+    for len in 0..50 {
+        let data = Vec::from_iter((0..len).map(|_| rng.gen_range(0..10)));
+        check::<1>(&data);
+        check::<2>(&data);
+        check::<3>(&data);
+        check::<7>(&data);
+        check::<16>(&data);
+    }
+}
+
+#[test]
+fn array_windows_min_max_monotonic() {
+    check::<3>(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    check::<3>(&[8, 7, 6, 5, 4, 3, 2, 1]);
+    check::<4>(&[5, 5, 5, 5, 5, 5]);
+}
+
+#[test]
+fn array_windows_min_max_size_hint() {
+    let mut iter = (0..10).array_windows_min::<4>();
+    assert_eq!(iter.len(), 7);
+    iter.next();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.count(), 6);
+
+    let iter = (0..3).array_windows_max::<4>();
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn array_windows_min_max_debug_clone() {
+    let mut iter = (0..10).array_windows_max::<3>();
+    iter.next();
+    let _ = format!("{:?}", iter);
+    assert_eq!(iter.clone().next(), Some(3));
+}
+
+#[test]
+#[should_panic]
+fn array_windows_min_zero() {
+    let _ = (0..10).array_windows_min::<0>();
+}
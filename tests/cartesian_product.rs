@@ -158,3 +158,277 @@ fn cartesian_product_macro() {
     .collect();
     assert_eq!(v, [(1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23)]);
 }
+
+#[test]
+fn cartesian_product_ref() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Debug)]
+    struct Counted(i32);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.with(|c| c.set(c.get() + 1));
+            Self(self.0)
+        }
+    }
+
+    let mut iter = [Counted(1), Counted(2), Counted(3)]
+        .into_iter()
+        .cartesian_product_ref([10, 20]);
+    let mut v = Vec::new();
+    let mut sum = 0;
+    while let Some((a, b)) = iter.next_ref() {
+        v.push((a.0, b));
+        sum += a.0 * b;
+    }
+    assert_eq!(v, [(1, 10), (1, 20), (2, 10), (2, 20), (3, 10), (3, 20)]);
+    assert_eq!(sum, 180);
+    assert!(iter.next_ref().is_none());
+    assert_eq!(CLONES.with(|c| c.get()), 0);
+
+    let mut iter = iter::empty::<i32>().cartesian_product_ref([1]);
+    assert!(iter.next_ref().is_none());
+
+    let mut iter = [1].into_iter().cartesian_product_ref(iter::empty::<i32>());
+    assert!(iter.next_ref().is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cartesian_product_collected() {
+    struct NoClone<I>(I);
+
+    impl<I: Iterator> Iterator for NoClone<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+    }
+
+    let v = Vec::from_iter((0..3).cartesian_product_collected(NoClone("αβ".chars())));
+    assert_eq!(
+        v,
+        [(0, 'α'), (0, 'β'), (1, 'α'), (1, 'β'), (2, 'α'), (2, 'β')]
+    );
+
+    let v = Vec::from_iter((0..3).cartesian_product_collected(NoClone(iter::empty::<i32>())));
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter(iter::empty::<i32>().cartesian_product_collected(NoClone(0..3)));
+    assert!(v.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cartesian_product_collected_size_hint() {
+    let mut iter = (0..3).cartesian_product_collected(0..2);
+    for len in (0..=6).rev() {
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        iter.next();
+    }
+    assert_eq!(iter.next(), None);
+
+    let iter = (0..usize::MAX).cartesian_product_collected(0..2);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn cartesian_product_next_back_in_row() {
+    let mut iter = (0..1).cartesian_product(0..5);
+    assert_eq!(iter.next(), Some((0, 0)));
+    assert_eq!(iter.next_back_in_row(), Some((0, 4)));
+    assert_eq!(iter.next(), Some((0, 1)));
+    assert_eq!(iter.next_back_in_row(), Some((0, 3)));
+    assert_eq!(iter.next(), Some((0, 2)));
+    assert_eq!(iter.next_back_in_row(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back_in_row(), None);
+}
+
+#[test]
+fn cartesian_product_next_back_in_row_next_row() {
+    let mut iter = "ab".chars().cartesian_product(0..2);
+    assert_eq!(iter.next_back_in_row(), Some(('a', 1)));
+    assert_eq!(iter.next_back_in_row(), Some(('a', 0)));
+    assert_eq!(iter.next_back_in_row(), None);
+    assert_eq!(iter.next(), Some(('b', 0)));
+    assert_eq!(iter.next_back_in_row(), Some(('b', 1)));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = iter::empty::<i32>().cartesian_product(0..2);
+    assert_eq!(iter.next_back_in_row(), None);
+}
+
+#[test]
+fn cartesian_product_count_last() {
+    fn naive<I: Iterator>(iter: I) -> (usize, Option<I::Item>) {
+        let mut count = 0;
+        let mut last = None;
+        for item in iter {
+            count += 1;
+            last = Some(item);
+        }
+        (count, last)
+    }
+
+    for a in 0..4 {
+        for b in 0..4 {
+            let mut iter = (0..a).cartesian_product(0..b);
+            loop {
+                assert_eq!(
+                    (iter.clone().count(), iter.clone().last()),
+                    naive(iter.clone())
+                );
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn cartesian_product_count_last_not_exact_size() {
+    let iter = (0..10)
+        .filter(|x| x % 3 == 0)
+        .cartesian_product((0..5).filter(|x| x % 2 == 1));
+    assert_eq!(iter.clone().count(), 8);
+    assert_eq!(iter.last(), Some((9, 3)));
+}
+
+#[test]
+fn cartesian_product_count_last_after_next_back_in_row() {
+    let mut iter = (0..2).cartesian_product(0..3);
+    assert_eq!(iter.next_back_in_row(), Some((0, 2)));
+    assert_eq!(iter.clone().count(), 5);
+    assert_eq!(iter.clone().last(), Some((1, 2)));
+
+    let mut iter = (0..2).cartesian_product(0..3);
+    iter.nth(3);
+    assert_eq!(iter.next_back_in_row(), Some((1, 2)));
+    assert_eq!(iter.clone().count(), 1);
+    assert_eq!(iter.last(), Some((1, 1)));
+}
+
+#[test]
+fn cartesian_rows() {
+    let rows = Vec::from_iter((0..3).cartesian_rows(['a', 'b']).map(Vec::from_iter));
+    assert_eq!(
+        rows,
+        [
+            [(0, 'a'), (0, 'b')],
+            [(1, 'a'), (1, 'b')],
+            [(2, 'a'), (2, 'b')]
+        ]
+    );
+}
+
+#[test]
+fn cartesian_rows_flatten_eq_cartesian_product() {
+    let v = Vec::from_iter((0..4).cartesian_rows(1..5).flatten());
+    let w = Vec::from_iter((0..4).cartesian_product(1..5));
+    assert_eq!(v, w);
+
+    let v = Vec::from_iter((0..4).cartesian_rows(iter::empty::<i32>()).flatten());
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter(iter::empty::<i32>().cartesian_rows(0..4).flatten());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn cartesian_rows_len_rev() {
+    let mut rows = (0..3).cartesian_rows(0..2);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(Vec::from_iter(rows.next_back().unwrap()), [(2, 0), (2, 1)]);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        Vec::from_iter(rows.rev().flatten()),
+        [(1, 0), (1, 1), (0, 0), (0, 1)]
+    );
+}
+
+#[test]
+fn cartesian_product_flat_macro() {
+    use itermore::cartesian_product_flat;
+
+    let v: Vec<i32> = cartesian_product_flat!(1..3).collect();
+    assert_eq!(v, [1, 2]);
+
+    let v = Vec::from_iter(cartesian_product_flat!(0..2, "ab".chars()));
+    assert_eq!(v, Vec::from_iter(cartesian_product!(0..2, "ab".chars())));
+
+    let v = Vec::from_iter(cartesian_product_flat!(0..2, "ab".chars(), [-1, 1]));
+    assert_eq!(
+        v,
+        Vec::from_iter(cartesian_product!(0..2, "ab".chars(), [-1, 1]))
+    );
+
+    let v = Vec::from_iter(cartesian_product_flat!(
+        0..2,
+        1..3,
+        2..4,
+        3..5,
+        4..6,
+        5..7,
+        6..8,
+        7..9,
+        8..10,
+        9..11,
+        10..12,
+        11..13,
+    ));
+    let w = Vec::from_iter(cartesian_product!(
+        0..2,
+        1..3,
+        2..4,
+        3..5,
+        4..6,
+        5..7,
+        6..8,
+        7..9,
+        8..10,
+        9..11,
+        10..12,
+        11..13,
+    ));
+    assert_eq!(v.len(), 4096);
+    assert_eq!(v, w);
+
+    let v = Vec::from_iter(cartesian_product_flat!(0..2, iter::empty::<i32>(), 0..2));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn cartesian_product_len_checked() {
+    let mut iter = (0..3).cartesian_product([1, 2]);
+    for expected in (0..=6).rev() {
+        assert_eq!(iter.len_checked(), Some(expected));
+        assert_eq!(iter.clone().count(), expected);
+        iter.next();
+    }
+    assert_eq!(iter.len_checked(), Some(0));
+
+    assert_eq!((0..0).cartesian_product(0..5).len_checked(), Some(0));
+    assert_eq!((0..5).cartesian_product(0..0).len_checked(), Some(0));
+}
+
+#[test]
+fn cartesian_product_len_checked_overflow() {
+    let iter = (0..usize::MAX).cartesian_product(0..usize::MAX);
+    assert_eq!(iter.len_checked(), None);
+
+    // The first element of `I` is already taken out, so the product of the
+    // rest and the current row are added separately.
+    let n = usize::MAX / 2;
+    let mut iter = (0..n + 1).cartesian_product(0..2);
+    assert_eq!(iter.len_checked(), None);
+    iter.next();
+    assert_eq!(iter.len_checked(), Some(usize::MAX));
+}
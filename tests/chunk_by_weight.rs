@@ -0,0 +1,74 @@
+#![cfg(feature = "chunk_by_weight")]
+
+use itermore::IterChunkByWeight;
+
+#[test]
+fn chunk_by_weight_basic() {
+    let v: Vec<_> = [1, 2, 3, 4]
+        .into_iter()
+        .chunk_by_weight(5, |x| *x)
+        .collect();
+    assert_eq!(v, [vec![1, 2], vec![3], vec![4]]);
+}
+
+#[test]
+fn chunk_by_weight_exact_fit() {
+    let v: Vec<_> = [2, 3, 1, 4, 5]
+        .into_iter()
+        .chunk_by_weight(5, |x| *x)
+        .collect();
+    assert_eq!(v, [vec![2, 3], vec![1, 4], vec![5]]);
+}
+
+#[test]
+fn chunk_by_weight_oversized() {
+    let v: Vec<_> = [1, 9, 2, 2, 7, 0]
+        .into_iter()
+        .chunk_by_weight(5, |x| *x)
+        .collect();
+    assert_eq!(v, [vec![1], vec![9], vec![2, 2], vec![7], vec![0]]);
+}
+
+#[test]
+fn chunk_by_weight_by_len() {
+    let v: Vec<_> = ["lorem", "ipsum", "do", "lor", "sit", "amet"]
+        .into_iter()
+        .chunk_by_weight(10, |s| s.len())
+        .collect();
+    assert_eq!(
+        v,
+        [
+            vec!["lorem", "ipsum"],
+            vec!["do", "lor", "sit"],
+            vec!["amet"]
+        ]
+    );
+}
+
+#[test]
+fn chunk_by_weight_calls_weight_once() {
+    let mut calls = 0;
+    let v: Vec<_> = (0..10)
+        .chunk_by_weight(10, |x| {
+            calls += 1;
+            *x
+        })
+        .collect();
+    assert_eq!(v.concat(), (0..10).collect::<Vec<_>>());
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn chunk_by_weight_empty() {
+    let mut iter = (0..0).chunk_by_weight(5, |x| *x);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn chunk_by_weight_size_hint() {
+    let mut iter = [1, 2, 3, 4].into_iter().chunk_by_weight(5, |x| *x);
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+}
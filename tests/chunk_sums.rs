@@ -0,0 +1,67 @@
+#![cfg(feature = "chunk_sums")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn chunk_sums_smoke() {
+    let v = Vec::from_iter([1, 2, 3, 4, 5, 6].into_iter().chunk_sums::<2>());
+    assert_eq!(v, [3, 7, 11]);
+
+    let v = Vec::from_iter([1, 2, 3, 4, 5, 6].into_iter().chunk_sums::<3>());
+    assert_eq!(v, [6, 15]);
+
+    let v = Vec::from_iter([1, 2, 3].into_iter().chunk_sums::<1>());
+    assert_eq!(v, [1, 2, 3]);
+
+    let v = Vec::from_iter(iter::empty::<i32>().chunk_sums::<2>());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn chunk_sums_remainder() {
+    let mut iter = (1..=6).chunk_sums::<2>();
+    assert_eq!(iter.next(), Some(3));
+    assert!(iter.into_remainder().is_none());
+
+    let mut iter = (1..=6).chunk_sums::<2>();
+    for _ in iter.by_ref() {}
+    assert!(iter.into_remainder().is_none());
+
+    let mut iter = (1..=7).chunk_sums::<2>();
+    assert_eq!(Vec::from_iter(iter.by_ref()), [3, 7, 11]);
+    assert_eq!(iter.into_remainder(), Some(7));
+
+    let mut iter = (1..=8).chunk_sums::<3>();
+    assert_eq!(Vec::from_iter(iter.by_ref()), [6, 15]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_remainder(), Some(15));
+}
+
+#[test]
+fn chunk_sums_size_hint() {
+    let iter = (0..7).chunk_sums::<2>();
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn chunk_sums_zero() {
+    let _ = (0..7).chunk_sums::<0>();
+}
+
+#[test]
+fn chunk_sums_debug() {
+    let iter = (0..6).chunk_sums::<2>();
+    let _ = format!("{:?}", iter);
+}
+
+#[test]
+fn chunk_sums_clone() {
+    let mut iter = (0..6).chunk_sums::<2>();
+    let mut iter2 = iter.clone();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter2.next(), Some(1));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "chunks")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn chunks_smoke() {
+    let v = Vec::from_iter((1..=6).chunks(2));
+    assert_eq!(v, [[1, 2], [3, 4], [5, 6]]);
+
+    let v = Vec::from_iter((1..=6).chunks(6));
+    assert_eq!(v, [[1, 2, 3, 4, 5, 6]]);
+
+    let v = Vec::from_iter(iter::empty::<i32>().chunks(2));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn chunks_remainder() {
+    let mut iter = (1..=6).chunks(2);
+    assert_eq!(iter.next(), Some(vec![1, 2]));
+    assert_eq!(iter.clone().into_remainder(), None);
+    for _ in iter.by_ref() {}
+    assert_eq!(iter.into_remainder(), Some(vec![]));
+
+    let mut iter = (1..=7).chunks(3);
+    assert_eq!(Vec::from_iter(iter.by_ref()), [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(iter.into_remainder(), Some(vec![7]));
+
+    let mut iter = (1..=2).chunks(3);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_remainder(), Some(vec![1, 2]));
+}
+
+#[test]
+fn chunks_size_hint() {
+    let mut iter = (0..7).chunks(2);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.len(), 3);
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.count(), 2);
+}
+
+#[test]
+#[should_panic]
+fn chunks_zero() {
+    let _ = (0..7).chunks(0);
+}
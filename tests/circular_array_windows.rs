@@ -1,6 +1,7 @@
 #![cfg(feature = "circular_array_windows")]
 
 use itermore::prelude::*;
+use itermore::slice_circular_array_windows;
 
 #[test]
 fn circular_array_windows_debug() {
@@ -55,3 +56,68 @@ fn circular_array_windows_size_hint() {
     assert_eq!(iter.len(), 5);
     assert_eq!(iter.count(), 5);
 }
+
+#[test]
+fn circular_pairs() {
+    let v: Vec<_> = [1, 2, 3].into_iter().circular_pairs().collect();
+    assert_eq!(v, [(1, 2), (2, 3), (3, 1)]);
+}
+
+#[test]
+fn circular_pairs_single() {
+    let v: Vec<_> = [1].into_iter().circular_pairs().collect();
+    assert_eq!(v, [(1, 1)]);
+}
+
+#[test]
+fn circular_pairs_empty() {
+    let mut iter = (0..0).circular_pairs();
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn circular_pairs_size_hint() {
+    let mut iter = ["a", "b", "c", "d"].iter().circular_pairs();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some((&"a", &"b")));
+    assert_eq!(iter.len(), 3);
+    let _ = format!("{:?}", iter.clone());
+    assert_eq!(iter.last(), Some((&"d", &"a")));
+}
+
+#[test]
+fn slice_circular_array_windows_smoke() {
+    let data = [1, 2, 3, 4];
+
+    let v = Vec::from_iter(slice_circular_array_windows::<_, 3>(&data, 1));
+    assert_eq!(v, [[2, 3, 4], [3, 4, 1], [4, 1, 2], [1, 2, 3]]);
+
+    let v = Vec::from_iter(slice_circular_array_windows::<_, 2>(&data, 7));
+    assert_eq!(v, [[4, 1], [1, 2], [2, 3], [3, 4]]);
+
+    let v = Vec::from_iter(slice_circular_array_windows::<_, 6>(&data, 3));
+    assert_eq!(v[0], [4, 1, 2, 3, 4, 1]);
+    assert_eq!(v.len(), 4);
+}
+
+#[test]
+fn slice_circular_array_windows_empty() {
+    let mut iter = slice_circular_array_windows::<i32, 2>(&[], 3);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn slice_circular_array_windows_strings() {
+    let data = Vec::from_iter((0..4).map(|i| i.to_string()));
+    let mut iter = slice_circular_array_windows::<_, 2>(&data, 3);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some([String::from("3"), String::from("0")]));
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn slice_circular_array_windows_zero() {
+    let _ = slice_circular_array_windows::<_, 0>(&[1, 2], 0);
+}
@@ -1,5 +1,7 @@
 #![cfg(feature = "collect_array")]
 
+use std::iter;
+
 use itermore::prelude::*;
 
 #[test]
@@ -28,3 +30,151 @@ fn collect_array_too_few() {
 fn collect_array_too_many() {
     let _: [_; 3] = (0..4).collect_array();
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn collect_array_with_overflow() {
+    let (arr, rest) = (0..0).collect_array_with_overflow::<0>();
+    assert_eq!(arr, Some([]));
+    assert!(rest.is_empty());
+
+    // fewer
+    let (arr, rest) = (0..2).collect_array_with_overflow::<3>();
+    assert_eq!(arr, None);
+    assert_eq!(rest, [0, 1]);
+
+    // exactly
+    let (arr, rest) = (0..3).collect_array_with_overflow::<3>();
+    assert_eq!(arr, Some([0, 1, 2]));
+    assert!(rest.is_empty());
+
+    // more
+    let (arr, rest) = (0..7).collect_array_with_overflow::<3>();
+    assert_eq!(arr, Some([0, 1, 2]));
+    assert_eq!(rest, [3, 4, 5, 6]);
+}
+
+#[test]
+fn unzip_array() {
+    let (a, b): ([i32; 0], [char; 0]) = iter::empty().unzip_array();
+    assert_eq!(a, []);
+    assert_eq!(b, []);
+
+    let (a, b) = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().unzip_array();
+    assert_eq!(a, [1, 2, 3]);
+    assert_eq!(b, ['a', 'b', 'c']);
+}
+
+#[test]
+#[should_panic]
+fn unzip_array_too_few() {
+    let _: ([i32; 3], [char; 3]) = [(1, 'a'), (2, 'b')].into_iter().unzip_array();
+}
+
+#[test]
+#[should_panic]
+fn unzip_array_too_many() {
+    let _: ([i32; 1], [char; 1]) = [(1, 'a'), (2, 'b')].into_iter().unzip_array();
+}
+
+#[test]
+fn unzip_array_drop() {
+    use std::panic;
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let pairs = [(rc.clone(), rc.clone()), (rc.clone(), rc.clone())];
+        let _: ([_; 3], [_; 3]) = pairs.into_iter().unzip_array();
+    }));
+    assert!(result.is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn deinterleave() {
+    assert_eq!(iter::empty::<i32>().deinterleave::<0>(), Ok(([], [])));
+    assert_eq!(
+        [0, 1, 2, 3].into_iter().deinterleave(),
+        Ok(([0, 2], [1, 3]))
+    );
+    assert_eq!(
+        "abcdef".chars().deinterleave(),
+        Ok((['a', 'c', 'e'], ['b', 'd', 'f']))
+    );
+}
+
+#[test]
+fn deinterleave_wrong_length() {
+    assert_eq!((0..0).deinterleave::<2>(), Err(0));
+    assert_eq!((0..1).deinterleave::<2>(), Err(1));
+    assert_eq!((0..2).deinterleave::<2>(), Err(2));
+    assert_eq!((0..3).deinterleave::<2>(), Err(3));
+    assert_eq!((0..5).deinterleave::<2>(), Err(5));
+    assert_eq!((0..10).deinterleave::<2>(), Err(10));
+    assert_eq!((0..1).deinterleave::<0>(), Err(1));
+}
+
+#[test]
+fn deinterleave_drop() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    for n in 0..8 {
+        let result = iter::repeat_with(|| rc.clone()).take(n).deinterleave::<2>();
+        assert_eq!(result.is_ok(), n == 4);
+        drop(result);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
+
+#[test]
+fn last_n() {
+    assert_eq!((0..10).last_n::<3>(), Some([7, 8, 9]));
+    assert_eq!((0..10).filter(|x| x % 3 == 0).last_n::<2>(), Some([6, 9]));
+    for len in 0..12usize {
+        let expected = Vec::from_iter(len.saturating_sub(4)..len);
+        let arr = (0..len).last_n::<4>();
+        if len < 4 {
+            assert_eq!(arr, None);
+        } else {
+            assert_eq!(arr.unwrap(), expected.as_slice());
+        }
+    }
+}
+
+#[test]
+fn last_n_exact() {
+    assert_eq!((0..3).last_n::<3>(), Some([0, 1, 2]));
+}
+
+#[test]
+fn last_n_shorter() {
+    assert_eq!(iter::empty::<i32>().last_n::<1>(), None);
+    assert_eq!((0..2).last_n::<3>(), None);
+}
+
+#[test]
+fn last_n_zero() {
+    let mut iter = 0..5;
+    assert_eq!(iter.by_ref().last_n::<0>(), Some([]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn collect_array_or_default() {
+    assert_eq!((0..2).collect_array_or_default::<4>(), [0, 1, 0, 0]);
+    assert_eq!((0..4).collect_array_or_default::<4>(), [0, 1, 2, 3]);
+    assert_eq!(iter::empty::<i32>().collect_array_or_default::<2>(), [0, 0]);
+    assert_eq!((0..2).collect_array_or_default::<0>(), []);
+}
+
+#[test]
+fn collect_array_or_default_too_many() {
+    let mut count = 0;
+    let arr = (0..6)
+        .inspect(|_| count += 1)
+        .collect_array_or_default::<4>();
+    assert_eq!(arr, [0, 1, 2, 3]);
+    assert_eq!(count, 6);
+}
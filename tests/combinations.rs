@@ -66,3 +66,355 @@ fn combinations_edge_cases() {
     assert!(it.next().is_none());
     assert!(it.next().is_none());
 }
+
+#[test]
+fn combinations_ref() {
+    let mut iter = (1..5).combinations(2);
+    let v = Vec::from_iter(iter.combinations_ref());
+    assert_eq!(
+        v,
+        [[&1, &2], [&1, &3], [&1, &4], [&2, &3], [&2, &4], [&3, &4]]
+    );
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn combinations_ref_after_next() {
+    let mut iter = (1..5).combinations(3);
+    assert_eq!(iter.next(), Some(vec![1, 2, 3]));
+    let v = Vec::from_iter(iter.combinations_ref());
+    assert_eq!(v, [[&1, &2, &4], [&1, &3, &4], [&2, &3, &4]]);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn combinations_ref_then_next() {
+    let mut iter = (1..5).combinations(3);
+    let _ = iter.combinations_ref();
+    assert_eq!(iter.next(), Some(vec![1, 2, 3]));
+    assert_eq!(iter.next(), Some(vec![1, 2, 4]));
+
+    let mut iter = (1..3).combinations(3);
+    assert_eq!(iter.combinations_ref().next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn combinations_ref_points_to_buffer() {
+    let mut iter = ["a", "b", "c"]
+        .into_iter()
+        .map(String::from)
+        .combinations(2);
+    let v = Vec::from_iter(iter.combinations_ref());
+    assert_eq!(v.len(), 3);
+    // [a, b], [a, c], [b, c]
+    assert!(std::ptr::eq(v[0][0], v[1][0]));
+    assert!(std::ptr::eq(v[0][1], v[2][0]));
+    assert!(std::ptr::eq(v[1][1], v[2][1]));
+}
+
+#[test]
+fn combinations_fill_next_into() {
+    let mut iter = (1..5).combinations(3);
+    let mut buf = [0; 3];
+    let mut v = Vec::new();
+    while iter.fill_next_into(&mut buf) {
+        v.push(buf);
+    }
+    assert_eq!(v, [[1, 2, 3], [1, 2, 4], [1, 3, 4], [2, 3, 4]]);
+    assert!(!iter.fill_next_into(&mut buf));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = (1..3).combinations(3);
+    assert!(!iter.fill_next_into(&mut buf));
+}
+
+#[test]
+fn combinations_fill_next_into_mixed() {
+    let mut iter = (1..5).combinations(2);
+    let mut buf = vec![0; 2];
+    assert_eq!(iter.next(), Some(vec![1, 2]));
+    assert!(iter.fill_next_into(&mut buf));
+    assert_eq!(buf, [1, 3]);
+    assert_eq!(iter.next(), Some(vec![1, 4]));
+}
+
+#[test]
+#[should_panic]
+fn combinations_fill_next_into_wrong_len() {
+    let mut iter = (1..5).combinations(2);
+    iter.fill_next_into(&mut [0; 3]);
+}
+
+#[test]
+fn combinations_total() {
+    for n in 0..8 {
+        for k in 1..5 {
+            let mut iter = (0..n).combinations(k);
+            let total = iter.total().unwrap();
+            assert_eq!(total, (0..n).combinations(k).count() as u128);
+        }
+    }
+
+    let mut iter = (0..5).combinations(2);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.total(), Some(10));
+    assert_eq!(iter.count(), 8);
+
+    let mut iter = (0..100).combinations(50);
+    assert_eq!(iter.total(), Some(100891344545564193334812497256));
+
+    let mut iter = (0..128).combinations(64);
+    assert_eq!(iter.total(), Some(23951146041928082866135587776380551750));
+
+    let mut iter = (0..200).combinations(100);
+    assert_eq!(iter.total(), None);
+}
+
+#[test]
+fn combinations_total_large() {
+    assert_eq!(
+        (0..130).combinations(65).total(),
+        Some(95067625827960698145584333020095113100)
+    );
+    assert_eq!(
+        (0..131).combinations(65).total(),
+        Some(188694833082770476622296176145946360850)
+    );
+    assert_eq!(
+        (0..129).combinations(60).total(),
+        Some(34935580079182300856186858764174912800)
+    );
+    // C(140, 60) is about 2.3e40 which doesn't fit in a u128.
+    assert_eq!((0..140).combinations(60).total(), None);
+}
+
+#[test]
+fn combinations_resume_from() {
+    for n in 0..7 {
+        for k in 1..5 {
+            let expected: Vec<_> = (0..n).combinations(k).collect();
+            for i in 0..=expected.len() {
+                let mut iter = (0..n).combinations(k);
+                iter.by_ref().take(i).for_each(drop);
+                let iter = itermore::Combinations::resume_from(0..n, iter.state());
+                assert_eq!(iter.collect::<Vec<_>>(), expected[i..]);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn combinations_resume_from_short_iter() {
+    let mut iter = (0..5).combinations(2);
+    iter.by_ref().take(5).for_each(drop);
+    let _ = itermore::Combinations::resume_from(0..2, iter.state());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn combinations_state_serde() {
+    let mut iter = "abcde".chars().combinations(3);
+    iter.by_ref().take(4).for_each(drop);
+    let json = serde_json::to_string(&iter.state()).unwrap();
+    let state: itermore::CombinationsState = serde_json::from_str(&json).unwrap();
+    assert_eq!(state, iter.state());
+
+    let resumed = itermore::Combinations::resume_from("abcde".chars(), state);
+    assert!(resumed.eq(iter));
+}
+
+#[test]
+fn combinations_produced() {
+    for n in 0..8 {
+        for k in 1..5 {
+            let mut iter = (0..n).combinations(k);
+            assert_eq!(iter.produced(), 0);
+            let mut i = 0;
+            while iter.next().is_some() {
+                i += 1;
+                assert_eq!(iter.produced(), i);
+            }
+            assert_eq!(iter.produced(), i);
+            assert_eq!(iter.total(), Some(i));
+        }
+    }
+
+    let mut iter = (0..).combinations(3);
+    iter.by_ref().take(1000).for_each(drop);
+    assert_eq!(iter.produced(), 1000);
+}
+
+#[test]
+fn combinations_nth() {
+    for n in 0..9 {
+        for k in 1..5 {
+            let all: Vec<_> = (0..n).combinations(k).collect();
+            for skip in 0..all.len() + 2 {
+                let mut iter = (0..n).combinations(k);
+                assert_eq!(iter.nth(skip), all.get(skip).cloned());
+                assert_eq!(iter.next(), all.get(skip + 1).cloned());
+            }
+        }
+    }
+}
+
+#[test]
+fn combinations_nth_after_next() {
+    let all: Vec<_> = (0..7).combinations(3).collect();
+    for start in 0..all.len() {
+        for skip in 0..all.len() - start + 1 {
+            let mut iter = (0..7).combinations(3);
+            iter.by_ref().take(start).for_each(drop);
+            let mut expected = all[start..].iter().cloned();
+            assert_eq!(iter.nth(skip), expected.nth(skip));
+            assert!(iter.eq(expected));
+        }
+    }
+}
+
+#[test]
+fn combinations_nth_unknown_len() {
+    let all: Vec<_> = (0..6).combinations(2).collect();
+    for skip in 0..all.len() + 1 {
+        let mut iter = (0..6).filter(|_| true).combinations(2);
+        assert_eq!(iter.nth(skip), all.get(skip).cloned());
+    }
+
+    // An infinite iterator falls back to advancing one at a time.
+    let mut iter = (0..).combinations(2);
+    assert_eq!(iter.nth(3), Some(vec![0, 4]));
+}
+
+#[test]
+fn combinations_nth_deep() {
+    let mut iter = (0..100).combinations(10);
+    assert_eq!(
+        iter.nth(1_000_000_000),
+        Some(vec![0, 1, 2, 4, 6, 14, 26, 49, 53, 77])
+    );
+    assert_eq!(iter.produced(), 1_000_000_001);
+}
+
+#[test]
+fn combinations_nth_large_total() {
+    let mut iter = (0..128).combinations(64);
+    let comb = iter.nth(usize::MAX / 3).unwrap();
+    assert_eq!(comb[..44], Vec::from_iter(0..44));
+    assert_eq!(
+        comb[44..],
+        [47, 48, 50, 60, 61, 63, 64, 70, 75, 87, 90, 92, 97, 101, 102, 103, 118, 119, 124, 125]
+    );
+    assert_eq!(iter.produced(), (usize::MAX / 3) as u128 + 1);
+}
+
+#[test]
+fn index_combinations() {
+    let v = Vec::from_iter("abcd".chars().index_combinations(2));
+    assert_eq!(
+        v,
+        [[0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]].map(Vec::from)
+    );
+}
+
+#[test]
+fn index_combinations_matches_combinations() {
+    for n in 0..7 {
+        for k in 1..5 {
+            let expected = Vec::from_iter((0..n).combinations(k));
+            let v = Vec::from_iter((0..n).index_combinations(k));
+            assert_eq!(v, expected);
+        }
+    }
+}
+
+#[test]
+fn index_combinations_not_clone() {
+    struct NotClone;
+
+    let mut iter = [NotClone, NotClone, NotClone]
+        .into_iter()
+        .index_combinations(2);
+    assert_eq!(iter.next(), Some(vec![0, 1]));
+    assert_eq!(iter.next(), Some(vec![0, 2]));
+    assert_eq!(iter.next(), Some(vec![1, 2]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn index_combinations_zero_k() {
+    let _ = (0..6).index_combinations(0);
+}
+
+fn decode(mask: u64) -> Vec<usize> {
+    Vec::from_iter((0..64).filter(|i| mask >> i & 1 == 1))
+}
+
+#[test]
+fn combinations_mask() {
+    let v = Vec::from_iter((0..4).combinations_mask(2).map(decode));
+    assert_eq!(
+        v,
+        [
+            vec![0, 1],
+            vec![0, 2],
+            vec![1, 2],
+            vec![0, 3],
+            vec![1, 3],
+            vec![2, 3]
+        ]
+    );
+}
+
+#[test]
+fn combinations_mask_matches_index_combinations() {
+    for n in 0..10 {
+        for k in 1..=n + 1 {
+            let masks = Vec::from_iter((0..n).combinations_mask(k));
+            assert!(masks.windows(2).all(|w| w[0] < w[1]));
+            assert!(masks.iter().all(|m| m.count_ones() as usize == k));
+
+            let mut v = Vec::from_iter(masks.into_iter().map(decode));
+            v.sort();
+            let w = Vec::from_iter((0..n).index_combinations(k));
+            assert_eq!(v, w, "n = {}, k = {}", n, k);
+        }
+    }
+}
+
+#[test]
+fn combinations_mask_64() {
+    let v = Vec::from_iter((0..64).combinations_mask(1));
+    assert_eq!(v, Vec::from_iter((0..64).map(|i| 1 << i)));
+
+    let v = Vec::from_iter((0..64).combinations_mask(63));
+    assert_eq!(v.len(), 64);
+    assert_eq!(v.first(), Some(&(u64::MAX >> 1)));
+    assert_eq!(v.last(), Some(&(u64::MAX - 1)));
+
+    let v = Vec::from_iter((0..64).combinations_mask(64));
+    assert_eq!(v, [u64::MAX]);
+
+    assert_eq!((0..64).combinations_mask(2).count(), 64 * 63 / 2);
+}
+
+#[test]
+#[should_panic(expected = "iterator must have at most 64 elements")]
+fn combinations_mask_too_long() {
+    let _ = (0..65).combinations_mask(2);
+}
+
+#[test]
+#[should_panic(expected = "iterator must have at most 64 elements")]
+fn combinations_mask_infinite() {
+    let _ = (0..).combinations_mask(2);
+}
+
+#[test]
+#[should_panic]
+fn combinations_mask_zero_k() {
+    let _ = (0..6).combinations_mask(0);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "combinations")]
+
+use itermore::prelude::*;
+
+mod alloc_counter {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    thread_local! {
+        static REALLOCS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    pub struct Counter;
+
+    unsafe impl GlobalAlloc for Counter {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let _ = REALLOCS.try_with(|r| r.set(r.get() + 1));
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    pub fn reallocs() -> usize {
+        REALLOCS.with(|r| r.get())
+    }
+}
+
+#[global_allocator]
+static ALLOC: alloc_counter::Counter = alloc_counter::Counter;
+
+#[test]
+fn combinations_no_realloc_exact_size() {
+    let before = alloc_counter::reallocs();
+    let mut count = 0;
+    for c in (0..100).combinations(3) {
+        count += c.len();
+    }
+    assert_eq!(count, 3 * 161700);
+    assert_eq!(alloc_counter::reallocs(), before);
+}
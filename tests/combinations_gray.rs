@@ -0,0 +1,102 @@
+#![cfg(feature = "combinations_gray")]
+
+use std::collections::HashSet;
+
+use itermore::IterCombinationsGray;
+
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+#[test]
+fn combinations_gray_debug() {
+    let iter = (0..6).combinations_gray(2);
+    let _ = format!("{:?}", iter);
+}
+
+#[test]
+fn combinations_gray_clone() {
+    let mut iter = (0..6).combinations_gray(2);
+    iter.next();
+    let mut iter2 = iter.clone();
+    assert_eq!(iter.next(), Some(vec![1, 2]));
+    assert_eq!(iter2.next(), Some(vec![1, 2]));
+}
+
+#[test]
+#[should_panic]
+fn combinations_gray_zero_k() {
+    let _ = (0..6).combinations_gray(0);
+}
+
+#[test]
+fn combinations_gray_smoke() {
+    let v = Vec::from_iter((0..5).combinations_gray(3));
+    assert_eq!(
+        v,
+        [
+            [0, 1, 2],
+            [0, 2, 3],
+            [1, 2, 3],
+            [0, 1, 3],
+            [0, 3, 4],
+            [1, 3, 4],
+            [2, 3, 4],
+            [0, 2, 4],
+            [1, 2, 4],
+            [0, 1, 4],
+        ]
+        .map(Vec::from)
+    );
+}
+
+#[test]
+fn combinations_gray_edge_cases() {
+    assert_eq!((0..0).combinations_gray(1).next(), None);
+    assert_eq!((0..2).combinations_gray(3).next(), None);
+    assert_eq!(Vec::from_iter((0..3).combinations_gray(3)), [vec![0, 1, 2]]);
+    assert_eq!(
+        Vec::from_iter((0..3).combinations_gray(1)),
+        [vec![0], vec![1], vec![2]]
+    );
+}
+
+#[test]
+fn combinations_gray_single_element_difference() {
+    for n in 0..10 {
+        for k in 1..=n {
+            let v = Vec::from_iter((0..n).combinations_gray(k));
+            for w in v.windows(2) {
+                let a = HashSet::<_>::from_iter(&w[0]);
+                let b = HashSet::<_>::from_iter(&w[1]);
+                assert_eq!(a.difference(&b).count(), 1, "{:?} -> {:?}", w[0], w[1]);
+                assert_eq!(b.difference(&a).count(), 1, "{:?} -> {:?}", w[0], w[1]);
+            }
+        }
+    }
+}
+
+#[test]
+fn combinations_gray_all_combinations() {
+    for n in 0..10 {
+        for k in 1..=n + 1 {
+            let v = Vec::from_iter((0..n).combinations_gray(k));
+            assert_eq!(v.len(), binomial(n, k));
+            assert!(v
+                .iter()
+                .all(|c| c.len() == k && c.windows(2).all(|w| w[0] < w[1])));
+            assert_eq!(HashSet::<_>::from_iter(&v).len(), v.len());
+        }
+    }
+}
+
+#[test]
+fn combinations_gray_fused() {
+    let mut iter = (0..2).combinations_gray(2);
+    assert_eq!(iter.next(), Some(vec![0, 1]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -65,3 +65,101 @@ fn combinations_with_reps_smoke() {
     let v = Vec::from_iter((1..5).combinations_with_reps(4));
     assert_eq!(v.len(), 256);
 }
+
+#[test]
+fn combinations_with_reps_rev() {
+    let v = Vec::from_iter((0..2).combinations_with_reps(2).rev());
+    assert_eq!(v, [[1, 1], [1, 0], [0, 1], [0, 0]]);
+
+    let v = Vec::from_iter((0..0).combinations_with_reps(2).rev());
+    assert!(v.is_empty());
+}
+
+#[test]
+fn combinations_with_reps_double_ended() {
+    let mut iter = (0..2).combinations_with_reps(2);
+    assert_eq!(iter.next(), Some(vec![0, 0]));
+    assert_eq!(iter.next_back(), Some(vec![1, 1]));
+    assert_eq!(iter.next_back(), Some(vec![1, 0]));
+    assert_eq!(iter.next(), Some(vec![0, 1]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    // Every way of interleaving front and back yields all N^K combinations.
+    let expected = Vec::from_iter((0..3).combinations_with_reps(2));
+    for pattern in 0..(1u32 << expected.len()) {
+        let mut iter = (0..3).combinations_with_reps(2);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for i in 0.. {
+            let item = if pattern & (1 << i) == 0 {
+                iter.next().map(|c| front.push(c))
+            } else {
+                iter.next_back().map(|c| back.push(c))
+            };
+            if item.is_none() {
+                break;
+            }
+        }
+        front.extend(back.into_iter().rev());
+        assert_eq!(front, expected);
+    }
+}
+
+#[test]
+fn combinations_with_reps_size_hint() {
+    let mut iter = (0..3).combinations_with_reps(2);
+    for remaining in (0..=9).rev() {
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        iter.next();
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut iter = (0..3).combinations_with_reps(2);
+    assert_eq!(iter.next_back(), Some(vec![2, 2]));
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    assert_eq!(iter.next(), Some(vec![0, 0]));
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    assert_eq!(iter.by_ref().rev().count(), 7);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn combinations_with_reps_size_hint_unknown() {
+    let mut iter = (0..3).filter(|_| true).combinations_with_reps(2);
+    assert_eq!(iter.size_hint(), (0, Some(9)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (0, Some(8)));
+    iter.next_back();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+
+    let iter = (0..).combinations_with_reps(2);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn combinations_with_reps_size_hint_overflow() {
+    let mut iter = (0..usize::MAX).combinations_with_reps(2);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+    iter.next();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn combinations_with_reps_total() {
+    for n in 0..6 {
+        for k in 1..4 {
+            let mut iter = (0..n).combinations_with_reps(k);
+            let total = iter.total().unwrap();
+            assert_eq!(total, (0..n).combinations_with_reps(k).count() as u128);
+        }
+    }
+
+    let mut iter = (0..3).combinations_with_reps(2);
+    iter.next();
+    assert_eq!(iter.total(), Some(9));
+    assert_eq!(iter.count(), 8);
+
+    let mut iter = (0..=u8::MAX).combinations_with_reps(17);
+    assert_eq!(iter.total(), None);
+}
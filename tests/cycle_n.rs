@@ -0,0 +1,52 @@
+#![cfg(feature = "cycle_n")]
+
+use std::iter;
+
+use itermore::IterCycleN;
+
+#[test]
+fn cycle_n() {
+    let v: Vec<_> = ['a', 'b'].iter().cycle_n(3).collect();
+    assert_eq!(v, [&'a', &'b', &'a', &'b', &'a', &'b']);
+
+    let v: Vec<_> = (0..4).cycle_n(1).collect();
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn cycle_n_zero() {
+    let mut iter = (0..4).cycle_n(0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn cycle_n_empty() {
+    let mut iter = (0..0).cycle_n(usize::MAX);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn cycle_n_size_hint() {
+    let mut iter = (0..4).cycle_n(3);
+    for n in (0..=12).rev() {
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (0..usize::MAX).cycle_n(2);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+
+    let iter = iter::repeat(1).take(2).filter(|_| true).cycle_n(2);
+    assert_eq!(iter.size_hint(), (0, Some(4)));
+}
+
+#[test]
+fn cycle_n_fused() {
+    let mut iter = (0..2).cycle_n(2);
+    assert_eq!(iter.by_ref().count(), 4);
+    assert_eq!(iter.next(), None);
+}
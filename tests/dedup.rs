@@ -0,0 +1,71 @@
+#![cfg(feature = "dedup")]
+
+use itermore::IterDedup;
+
+#[test]
+fn dedup_with_count() {
+    let v: Vec<_> = [1, 1, 2, 3, 3, 3, 1]
+        .into_iter()
+        .dedup_with_count()
+        .collect();
+    assert_eq!(v, [(2, 1), (1, 2), (3, 3), (1, 1)]);
+}
+
+#[test]
+fn dedup_with_count_empty() {
+    let mut iter = (0..0).dedup_with_count();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn dedup_with_count_size_hint() {
+    let mut iter = [1, 1, 2, 2].into_iter().dedup_with_count();
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+}
+
+#[test]
+fn run_length_decode() {
+    let v: Vec<_> = [(2, 'a'), (0, 'x'), (1, 'b'), (3, 'c')]
+        .into_iter()
+        .run_length_decode()
+        .collect();
+    assert_eq!(v, ['a', 'a', 'b', 'c', 'c', 'c']);
+}
+
+#[test]
+fn run_length_decode_size_hint() {
+    let mut iter = [(3, 'a')].into_iter().run_length_decode();
+    assert_eq!(iter.size_hint(), (0, None));
+    iter.next();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn run_length_round_trip() {
+    let data = ['a', 'a', 'b', 'a'];
+    let encoded: Vec<_> = data.into_iter().dedup_with_count().collect();
+    assert_eq!(encoded, [(2, 'a'), (1, 'b'), (1, 'a')]);
+    let decoded: Vec<_> = encoded.into_iter().run_length_decode().collect();
+    assert_eq!(decoded, data);
+}
+
+#[test]
+fn run_length_round_trip_random() {
+    use rand::prelude::*;
+    let mut rng = StdRng::seed_from_u64(1884);
+    for _ in 0..100 {
+        let data: Vec<u8> = (0..rng.gen_range(0..50))
+            .map(|_| rng.gen_range(0..3))
+            .collect();
+        let decoded: Vec<_> = data
+            .iter()
+            .dedup_with_count()
+            .run_length_decode()
+            .copied()
+            .collect();
+        assert_eq!(decoded, data);
+    }
+}
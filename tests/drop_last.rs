@@ -0,0 +1,49 @@
+#![cfg(feature = "drop_last")]
+
+use std::iter;
+
+use itermore::IterDropLast;
+
+#[test]
+fn drop_last() {
+    let v = Vec::from_iter([1, 2, 3, 4].into_iter().drop_last(2));
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn drop_last_edge_cases() {
+    assert_eq!(iter::empty::<i32>().drop_last(0).next(), None);
+    assert_eq!(iter::empty::<i32>().drop_last(2).next(), None);
+    assert_eq!(Vec::from_iter((0..3).drop_last(0)), [0, 1, 2]);
+    assert_eq!((0..3).drop_last(3).next(), None);
+    assert_eq!((0..3).drop_last(5).next(), None);
+}
+
+#[test]
+fn drop_last_not_exact_size() {
+    let v = Vec::from_iter((0..10).filter(|x| x % 2 == 0).drop_last(1));
+    assert_eq!(v, [0, 2, 4, 6]);
+}
+
+#[test]
+fn drop_last_infinite() {
+    let v = Vec::from_iter((0..).drop_last(3).take(4));
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn drop_last_size_hint() {
+    let mut iter = (0..6).drop_last(2);
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(Vec::from_iter(iter), [1, 2, 3]);
+
+    let iter = (0..2).drop_last(5);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn drop_last_huge_n() {
+    assert_eq!((0..3).drop_last(usize::MAX).next(), None);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "fold_chunks")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn fold_chunks_smoke() {
+    let v = Vec::from_iter(
+        [1, 2, 3, 4]
+            .into_iter()
+            .fold_chunks::<2, _, _, _>(|| 1, |a, b| a * b),
+    );
+    assert_eq!(v, [2, 12]);
+
+    let v = Vec::from_iter((1..=6).fold_chunks::<3, _, _, _>(Vec::new, |mut v, x| {
+        v.push(x);
+        v
+    }));
+    assert_eq!(v, [[1, 2, 3], [4, 5, 6]]);
+
+    let v = Vec::from_iter(iter::empty::<i32>().fold_chunks::<2, _, _, _>(|| 0, |a, b| a + b));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn fold_chunks_remainder() {
+    let mut iter = (1..=5).fold_chunks::<2, _, _, _>(|| 1, |a, b| a * b);
+    assert_eq!(Vec::from_iter(iter.by_ref()), [2, 12]);
+    assert_eq!(iter.into_remainder().unwrap().as_slice(), [5]);
+}
+
+#[test]
+fn fold_chunks_size_hint() {
+    let iter = (0..7).fold_chunks::<2, _, _, _>(|| 0, |a, b| a + b);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+#[should_panic]
+fn fold_chunks_zero() {
+    let _ = (0..7).fold_chunks::<0, _, _, _>(|| 0, |a, b| a + b);
+}
+
+#[test]
+fn fold_chunks_debug() {
+    let iter = (0..6).fold_chunks::<2, _, _, _>(|| 0, |a, b| a + b);
+    let _ = format!("{:?}", iter);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "for_each_chunk")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn for_each_chunk() {
+    let mut chunks = Vec::new();
+    let rem = (0..6).for_each_chunk(|c: &[i32; 2]| chunks.push(*c));
+    assert_eq!(chunks, [[0, 1], [2, 3], [4, 5]]);
+    assert_eq!(rem.as_slice(), []);
+
+    let mut chunks = Vec::new();
+    let rem = (0..8).for_each_chunk(|c: &[i32; 3]| chunks.push(*c));
+    assert_eq!(chunks, [[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(rem.as_slice(), [6, 7]);
+
+    let mut chunks = Vec::new();
+    let rem = (0..2).for_each_chunk(|c: &[i32; 3]| chunks.push(*c));
+    assert!(chunks.is_empty());
+    assert_eq!(rem.as_slice(), [0, 1]);
+
+    let mut count = 0;
+    let rem = iter::empty::<i32>().for_each_chunk(|_: &[i32; 3]| count += 1);
+    assert_eq!(count, 0);
+    assert_eq!(rem.as_slice(), []);
+}
+
+#[test]
+fn for_each_chunk_by_ref() {
+    let mut iter = 0..7;
+    let mut chunks = Vec::new();
+    let rem = iter
+        .by_ref()
+        .take(5)
+        .for_each_chunk(|c: &[i32; 2]| chunks.push(*c));
+    assert_eq!(chunks, [[0, 1], [2, 3]]);
+    assert_eq!(rem.as_slice(), [4]);
+    assert_eq!(iter.next(), Some(5));
+}
+
+#[test]
+#[should_panic]
+fn for_each_chunk_zero() {
+    (0..6).for_each_chunk(|_: &[i32; 0]| {});
+}
+
+#[test]
+fn try_for_each_chunk() {
+    let mut chunks = Vec::new();
+    let rem = (0..8).try_for_each_chunk(|c: [i32; 3]| {
+        chunks.push(c);
+        Ok::<_, ()>(())
+    });
+    assert_eq!(chunks, [[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(rem.unwrap().as_slice(), [6, 7]);
+
+    let mut count = 0;
+    let rem = iter::empty::<i32>().try_for_each_chunk(|_: [i32; 3]| {
+        count += 1;
+        Ok::<_, ()>(())
+    });
+    assert_eq!(count, 0);
+    assert_eq!(rem.unwrap().as_slice(), []);
+}
+
+#[test]
+fn try_for_each_chunk_err() {
+    let mut iter = 0..10;
+    let mut chunks = Vec::new();
+    let err = iter
+        .by_ref()
+        .try_for_each_chunk(|c: [i32; 2]| {
+            if c[0] == 4 {
+                return Err(c);
+            }
+            chunks.push(c);
+            Ok(())
+        })
+        .unwrap_err();
+    assert_eq!(err, [4, 5]);
+    assert_eq!(chunks, [[0, 1], [2, 3]]);
+    assert_eq!(iter.next(), Some(6));
+}
+
+#[test]
+#[should_panic]
+fn try_for_each_chunk_zero() {
+    let _ = (0..6).try_for_each_chunk(|_: [i32; 0]| Ok::<_, ()>(()));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "format")]
+
+use std::fmt;
+use std::iter;
+
+use itermore::IterFormat;
+
+#[test]
+fn join() {
+    assert_eq!([1, 2, 3].into_iter().join(", "), "1, 2, 3");
+    assert_eq!(["a", "b"].into_iter().join(""), "ab");
+    assert_eq!([1].into_iter().join(", "), "1");
+    assert_eq!(iter::empty::<i32>().join(", "), "");
+}
+
+#[test]
+fn join_matches_collect() {
+    let data = [4.5, -1.0, 0.125];
+    let expected = data
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join("; ");
+    assert_eq!(data.iter().join("; "), expected);
+}
+
+#[test]
+fn format_with() {
+    let s = [1, 2, 3]
+        .into_iter()
+        .format_with(", ", |x, f| write!(f, "<{}>", x));
+    assert_eq!(s, "<1>, <2>, <3>");
+}
+
+#[test]
+fn format_with_formatter_flags() {
+    let s = [(1, 'a'), (22, 'b')]
+        .into_iter()
+        .format_with("\n", |(n, c), f| write!(f, "{:>3}: {}", n, c));
+    assert_eq!(s, "  1: a\n 22: b");
+}
+
+#[test]
+fn format_with_stateful() {
+    let mut i = 0;
+    let s = ["x", "y", "z"].into_iter().format_with(" ", |x, f| {
+        i += 1;
+        write!(f, "{}{}", x, i)
+    });
+    assert_eq!(s, "x1 y2 z3");
+}
+
+#[test]
+#[should_panic(expected = "a formatting function returned an error")]
+fn format_with_error() {
+    let _ = [1].into_iter().format_with(", ", |_, _| Err(fmt::Error));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "grid")]
+
+use itermore::grid;
+
+#[test]
+fn grid_2x3() {
+    let v: Vec<_> = grid([0..2, 0..3]).collect();
+    assert_eq!(v, [[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]);
+}
+
+#[test]
+fn grid_offset_ranges() {
+    let v: Vec<_> = grid([3..5, 10..11, 7..9]).collect();
+    assert_eq!(v, [[3, 10, 7], [3, 10, 8], [4, 10, 7], [4, 10, 8]]);
+}
+
+#[test]
+fn grid_matches_nested_loops() {
+    let mut expected = Vec::new();
+    for i in 1..4 {
+        for j in 0..2 {
+            for k in 2..5 {
+                expected.push([i, j, k]);
+            }
+        }
+    }
+    assert_eq!(grid([1..4, 0..2, 2..5]).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn grid_empty() {
+    let mut iter = grid([0..2, 3..3]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn grid_zero_dimensions() {
+    let v: Vec<_> = grid([]).collect();
+    assert_eq!(v, [[]]);
+}
+
+#[test]
+fn grid_size_hint() {
+    let mut iter = grid([0..2, 0..3]);
+    for n in (0..=6).rev() {
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+        iter.next();
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn grid_size_hint_overflow() {
+    let iter = grid([0..usize::MAX, 0..usize::MAX]);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
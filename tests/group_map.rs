@@ -0,0 +1,38 @@
+#![cfg(feature = "group_map")]
+
+use std::collections::HashMap;
+
+use itermore::IterGroupMap;
+
+#[test]
+fn into_group_map() {
+    let map = (0..10).map(|n| (n % 2 == 0, n)).into_group_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&true], [0, 2, 4, 6, 8]);
+    assert_eq!(map[&false], [1, 3, 5, 7, 9]);
+}
+
+#[test]
+fn into_group_map_by() {
+    let map = [5, 2, 9, 4, 4, 7, 1]
+        .into_iter()
+        .into_group_map_by(|n| n % 2);
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&0], [2, 4, 4]);
+    assert_eq!(map[&1], [5, 9, 7, 1]);
+}
+
+#[test]
+fn into_group_map_by_single_group() {
+    let map = (0..4).into_group_map_by(|_| ());
+    assert_eq!(map, HashMap::from([((), vec![0, 1, 2, 3])]));
+}
+
+#[test]
+fn into_group_map_empty() {
+    let map = std::iter::empty::<(i32, i32)>().into_group_map();
+    assert!(map.is_empty());
+
+    let map = std::iter::empty::<i32>().into_group_map_by(|n| n % 2);
+    assert!(map.is_empty());
+}
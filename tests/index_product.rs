@@ -0,0 +1,43 @@
+#![cfg(feature = "index_product")]
+
+use itermore::index_product;
+
+#[test]
+fn index_product_two_dims() {
+    let v = Vec::from_iter(index_product(&[2, 3]));
+    assert_eq!(v, [[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]);
+}
+
+#[test]
+fn index_product_edge_cases() {
+    assert_eq!(Vec::from_iter(index_product(&[])), [Vec::<usize>::new()]);
+    assert_eq!(index_product(&[3, 0, 2]).next(), None);
+    assert_eq!(Vec::from_iter(index_product(&[3])), [[0], [1], [2]]);
+}
+
+#[test]
+fn index_product_size_hint() {
+    let mut iter = index_product(&[2, 3, 4]);
+    assert_eq!(iter.size_hint(), (24, Some(24)));
+    for n in (0..24).rev() {
+        iter.next();
+        assert_eq!(iter.size_hint(), (n, Some(n)));
+    }
+    assert_eq!(iter.next(), None);
+
+    let iter = index_product(&[usize::MAX, 2]);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn index_product_matches_nested_loops() {
+    let mut expected = Vec::new();
+    for i in 0..3 {
+        for j in 0..1 {
+            for k in 0..4 {
+                expected.push(vec![i, j, k]);
+            }
+        }
+    }
+    assert_eq!(Vec::from_iter(index_product(&[3, 1, 4])), expected);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "is_ordered")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn is_ordered() {
+    assert!(iter::empty::<i32>().is_ordered());
+    assert!([1].into_iter().is_ordered());
+    assert!([1, 2, 3].into_iter().is_ordered());
+    assert!([1, 1, 1].into_iter().is_ordered());
+    assert!(![2, 1].into_iter().is_ordered());
+    assert!(![1, 2, 3, 0].into_iter().is_ordered());
+    assert!(![1.0, f64::NAN, 2.0].into_iter().is_ordered());
+}
+
+#[test]
+fn is_ordered_short_circuits() {
+    let iter = [1, 3, 2].into_iter().chain(iter::from_fn(|| {
+        panic!("consumed past the first unordered pair")
+    }));
+    assert!(!iter.is_ordered());
+}
+
+#[test]
+fn is_ordered_by() {
+    assert!(iter::empty::<i32>().is_ordered_by(|a, b| b.cmp(a)));
+    assert!([1].into_iter().is_ordered_by(|a, b| b.cmp(a)));
+    assert!([3, 2, 2, 1].into_iter().is_ordered_by(|a, b| b.cmp(a)));
+    assert!(![3, 2, 4].into_iter().is_ordered_by(|a, b| b.cmp(a)));
+}
+
+#[test]
+fn is_ordered_by_key() {
+    assert!(iter::empty::<&str>().is_ordered_by_key(str::len));
+    assert!(["a"].into_iter().is_ordered_by_key(str::len));
+    assert!(["z", "yy", "xxx"].into_iter().is_ordered_by_key(str::len));
+    assert!(!["aa", "b"].into_iter().is_ordered_by_key(str::len));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "map_ok")]
+
+use itermore::IterMapOk;
+
+fn mixed() -> impl ExactSizeIterator<Item = Result<i32, &'static str>> {
+    [Ok(1), Err("a"), Ok(2), Ok(3), Err("b"), Ok(4)].into_iter()
+}
+
+#[test]
+fn map_ok_smoke() {
+    let v = Vec::from_iter(mixed().map_ok(|n| n * 10));
+    assert_eq!(v, [Ok(10), Err("a"), Ok(20), Ok(30), Err("b"), Ok(40)]);
+}
+
+#[test]
+fn map_ok_changes_type() {
+    let v = Vec::from_iter(mixed().map_ok(|n| n % 2 == 0));
+    assert_eq!(
+        v,
+        [Ok(false), Err("a"), Ok(true), Ok(false), Err("b"), Ok(true)]
+    );
+}
+
+#[test]
+fn map_ok_not_called_on_err() {
+    let mut calls = 0;
+    mixed().map_ok(|n| calls += n).for_each(drop);
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn map_ok_size_hint() {
+    let iter = mixed().map_ok(|n| n + 1);
+    assert_eq!(iter.len(), 6);
+}
+
+#[test]
+fn filter_ok_smoke() {
+    let v = Vec::from_iter(mixed().filter_ok(|n| n % 2 == 0));
+    assert_eq!(v, [Err("a"), Ok(2), Err("b"), Ok(4)]);
+}
+
+#[test]
+fn filter_ok_keeps_all_err() {
+    let v = Vec::from_iter(mixed().filter_ok(|_| false));
+    assert_eq!(v, [Err("a"), Err("b")]);
+}
+
+#[test]
+fn filter_ok_size_hint() {
+    let mut iter = mixed().filter_ok(|n| *n > 2);
+    assert_eq!(iter.size_hint(), (0, Some(6)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (0, Some(4)));
+}
+
+#[test]
+fn filter_ok_then_map_ok() {
+    let v = Vec::from_iter(mixed().filter_ok(|n| *n > 2).map_ok(|n| n * n));
+    assert_eq!(v, [Err("a"), Ok(9), Err("b"), Ok(16)]);
+}
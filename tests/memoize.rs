@@ -0,0 +1,57 @@
+#![cfg(feature = "memoize")]
+
+use std::cell::Cell;
+
+use itermore::IterMemoize;
+
+#[test]
+fn memoize_three_clones() {
+    let calls = Cell::new(0);
+    let iter = (0..5).inspect(|_| calls.set(calls.get() + 1)).memoize();
+
+    let mut a = iter.clone();
+    let mut b = iter.clone();
+    let c = iter;
+
+    assert_eq!(a.next(), Some(0));
+    assert_eq!(a.next(), Some(1));
+    assert_eq!(calls.get(), 2);
+
+    assert_eq!(b.next(), Some(0));
+    assert_eq!(b.next(), Some(1));
+    assert_eq!(b.next(), Some(2));
+    assert_eq!(calls.get(), 3);
+
+    assert_eq!(c.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(calls.get(), 5);
+
+    assert_eq!(a.collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(b.collect::<Vec<_>>(), [3, 4]);
+    assert_eq!(calls.get(), 5);
+}
+
+#[test]
+fn memoize_clone_keeps_position() {
+    let mut a = "abc".chars().memoize();
+    a.next();
+    let b = a.clone();
+    assert_eq!(a.collect::<String>(), "bc");
+    assert_eq!(b.collect::<String>(), "bc");
+}
+
+#[test]
+fn memoize_size_hint() {
+    let mut a = (0..4).memoize();
+    let b = a.clone();
+    assert_eq!(a.len(), 4);
+    a.next();
+    a.next();
+    assert_eq!(a.len(), 2);
+    assert_eq!(b.len(), 4);
+}
+
+#[test]
+fn memoize_debug() {
+    let iter = (0..4).memoize();
+    let _ = format!("{:?}", iter);
+}
@@ -58,6 +58,89 @@ fn min_max_by_key() {
     assert_eq!([3, 2, 1].into_iter().min_max_by_key(key), Some((3, 1)));
 }
 
+#[test]
+fn min_max_by_key_with_keys() {
+    let key = |item: &i32| item.abs();
+    assert_eq!(iter::empty::<i32>().min_max_by_key_with_keys(key), None);
+
+    assert_eq!(
+        [-4].into_iter().min_max_by_key_with_keys(key),
+        Some(((4, -4), (4, -4)))
+    );
+    assert_eq!(
+        [-3, 1, 7, -9, 2].into_iter().min_max_by_key_with_keys(key),
+        Some(((1, 1), (9, -9)))
+    );
+    assert_eq!(
+        [5, -2, -8, 3].into_iter().min_max_by_key_with_keys(key),
+        Some(((2, -2), (8, -8)))
+    );
+}
+
+#[test]
+fn min_max_by_key_with_keys_calls_key_once() {
+    let mut calls = 0;
+    let result = [3, -1, 4, -1, 5, -9, 2, 6]
+        .into_iter()
+        .min_max_by_key_with_keys(|x: &i32| {
+            calls += 1;
+            x.abs()
+        });
+    assert_eq!(result, Some(((1, -1), (9, -9))));
+    assert_eq!(calls, 8);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn min_max_by_cached_key() {
+    let key = |item: &i32| -item;
+    assert_eq!(iter::empty::<i32>().min_max_by_cached_key(key), None);
+    assert_eq!([1].into_iter().min_max_by_cached_key(key), Some((1, 1)));
+    assert_eq!([1, 2].into_iter().min_max_by_cached_key(key), Some((2, 1)));
+    assert_eq!([2, 1].into_iter().min_max_by_cached_key(key), Some((2, 1)));
+    assert_eq!(
+        [2, 3, 1].into_iter().min_max_by_cached_key(key),
+        Some((3, 1))
+    );
+    assert_eq!(
+        [3, 1, 2].into_iter().min_max_by_cached_key(key),
+        Some((3, 1))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn min_max_by_cached_key_matches_min_max_by_key() {
+    use rand::prelude::*;
+    let mut rng = StdRng::seed_from_u64(1899);
+    for _ in 0..100 {
+        let data: Vec<(u8, usize)> = (0..rng.gen_range(0..20))
+            .map(|i| (rng.gen_range(0..5), i))
+            .collect();
+        assert_eq!(
+            data.iter().min_max_by_cached_key(|(k, _)| *k),
+            data.iter().min_max_by_key(|(k, _)| *k),
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn min_max_by_cached_key_calls_key_once() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct NotClone(i32);
+
+    let mut calls = 0;
+    let result = [3, -1, 4, -1, 5, -9, 2, 6]
+        .into_iter()
+        .min_max_by_cached_key(|x: &i32| {
+            calls += 1;
+            NotClone(x.abs())
+        });
+    assert_eq!(result, Some((-1, -9)));
+    assert_eq!(calls, 8);
+}
+
 #[test]
 fn min_max_shuffled() {
     use rand::prelude::*;
@@ -69,3 +152,211 @@ fn min_max_shuffled() {
         assert_eq!(data.iter().copied().min_max_by_key(|x| -x), Some((99, 0)));
     }
 }
+
+#[test]
+fn min_max_indices() {
+    assert_eq!(iter::empty::<i32>().min_max_indices(), None);
+
+    assert_eq!([1].into_iter().min_max_indices(), Some((0, 0)));
+
+    assert_eq!([1, 2].into_iter().min_max_indices(), Some((0, 1)));
+    assert_eq!([2, 1].into_iter().min_max_indices(), Some((1, 0)));
+
+    assert_eq!([1, 2, 3].into_iter().min_max_indices(), Some((0, 2)));
+    assert_eq!([1, 3, 2].into_iter().min_max_indices(), Some((0, 1)));
+    assert_eq!([2, 1, 3].into_iter().min_max_indices(), Some((1, 2)));
+    assert_eq!([2, 3, 1].into_iter().min_max_indices(), Some((2, 1)));
+    assert_eq!([3, 1, 2].into_iter().min_max_indices(), Some((1, 0)));
+    assert_eq!([3, 2, 1].into_iter().min_max_indices(), Some((2, 0)));
+}
+
+#[test]
+fn min_max_indices_not_clone() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct NotClone(i32);
+
+    let v = [NotClone(3), NotClone(1), NotClone(4), NotClone(2)];
+    let (min, max) = v.iter().min_max_indices().unwrap();
+    assert_eq!(v[min], NotClone(1));
+    assert_eq!(v[max], NotClone(4));
+}
+
+#[test]
+fn min_max_by_counted() {
+    assert_eq!((0..0).min_max_by_counted(Ord::cmp), (None, 0));
+    assert_eq!((0..1).min_max_by_counted(Ord::cmp), (Some((0, 0)), 0));
+    for n in 2..100usize {
+        // ceil(1.5 * n) - 2
+        let expected = (3 * n + 1) / 2 - 2;
+        let (result, count) = (0..n).min_max_by_counted(Ord::cmp);
+        assert_eq!(result, Some((0, n - 1)));
+        assert_eq!(count, expected);
+
+        // A new minimum at the end of an odd length iterator is only compared
+        // against the current minimum.
+        let (result, count) = (0..n).rev().min_max_by_counted(Ord::cmp);
+        assert_eq!(result, Some((0, n - 1)));
+        assert_eq!(count, expected - n % 2);
+    }
+}
+
+#[test]
+fn min_max_indices_by_comparisons() {
+    for n in 1..100 {
+        let mut count = 0;
+        let result = (0..n).min_max_indices_by(|a, b| {
+            count += 1;
+            a.cmp(b)
+        });
+        assert_eq!(result, Some((0, n - 1)));
+        if n % 2 == 0 {
+            assert_eq!(count, 3 * n / 2 - 2);
+        } else {
+            // The odd element out is compared against both the minimum and the
+            // maximum because the input is ascending.
+            assert_eq!(count, 3 * (n - 1) / 2);
+        }
+    }
+}
+
+#[test]
+fn running_min() {
+    let v = Vec::from_iter(iter::empty::<i32>().running_min());
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter([3, 1, 2, 0, 4].into_iter().running_min());
+    assert_eq!(v, [3, 1, 1, 0, 0]);
+}
+
+#[test]
+fn running_max() {
+    let v = Vec::from_iter(iter::empty::<i32>().running_max());
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter([1, 3, 2, 5, 4].into_iter().running_max());
+    assert_eq!(v, [1, 3, 3, 5, 5]);
+}
+
+#[test]
+fn running_min_max_by() {
+    let rev = |a: &i32, b: &i32| Reverse(a).cmp(&Reverse(b));
+
+    let v = Vec::from_iter([1, 3, 2, 5, 4].into_iter().running_min_by(rev));
+    assert_eq!(v, [1, 3, 3, 5, 5]);
+
+    let v = Vec::from_iter([1, 3, 2, 5, 4].into_iter().running_max_by(rev));
+    assert_eq!(v, [1, 1, 1, 1, 1]);
+}
+
+#[test]
+fn running_max_first_of_equal() {
+    let cmp = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+    let v = Vec::from_iter(
+        [(1, 'a'), (1, 'b'), (2, 'c')]
+            .into_iter()
+            .running_max_by(cmp),
+    );
+    assert_eq!(v, [(1, 'a'), (1, 'a'), (2, 'c')]);
+}
+
+#[test]
+fn running_min_and_max() {
+    let v = Vec::from_iter(iter::empty::<i32>().running_min_max());
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter([3, 1, 4, 1, 5].into_iter().running_min_max());
+    assert_eq!(v, [(3, 3), (1, 3), (1, 4), (1, 4), (1, 5)]);
+}
+
+#[test]
+fn running_min_and_max_matches_running_min_and_running_max() {
+    use rand::prelude::*;
+    let mut rng = StdRng::seed_from_u64(1907);
+    for _ in 0..100 {
+        let data: Vec<i32> = (0..rng.gen_range(0..20))
+            .map(|_| rng.gen_range(-10..10))
+            .collect();
+        let expected = Vec::from_iter(data.iter().running_min().zip(data.iter().running_max()));
+        assert_eq!(Vec::from_iter(data.iter().running_min_max()), expected);
+    }
+}
+
+#[test]
+fn running_min_and_max_first_of_equal() {
+    let cmp = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+    let v = Vec::from_iter(
+        [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]
+            .into_iter()
+            .running_min_max_by(cmp),
+    );
+    assert_eq!(
+        v,
+        [
+            ((1, 'a'), (1, 'a')),
+            ((0, 'b'), (1, 'a')),
+            ((0, 'b'), (1, 'a')),
+            ((0, 'b'), (1, 'a')),
+        ]
+    );
+}
+
+#[test]
+fn running_min_max_size_hint() {
+    let iter = (0..5).running_min();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.len(), 5);
+
+    let iter = (0..5).running_min_max();
+    assert_eq!(iter.len(), 5);
+}
+
+#[test]
+fn min_max_bounded() {
+    assert_eq!(iter::empty::<u8>().min_max_bounded(0, 255), None);
+    assert_eq!([3].into_iter().min_max_bounded(0, 255), Some((3, 3)));
+    assert_eq!(
+        [3, 1, 5, 2].into_iter().min_max_bounded(0, 255),
+        Some((1, 5))
+    );
+
+    // The iterator panics if it is consumed past the bounds.
+    let iter = [7u8, 0, 3, 255]
+        .into_iter()
+        .chain(iter::from_fn(|| panic!("consumed past the bounds")));
+    assert_eq!(iter.min_max_bounded(u8::MIN, u8::MAX), Some((0, 255)));
+
+    let iter = [u8::MAX]
+        .into_iter()
+        .chain(iter::from_fn(|| panic!("consumed past the bounds")));
+    assert_eq!(iter.min_max_bounded(u8::MAX, u8::MAX), Some((255, 255)));
+}
+
+#[test]
+fn try_min_max_by() {
+    let cmp = |a: &i32, b: &i32| Ok::<_, ()>(a.cmp(b));
+    assert_eq!(iter::empty().try_min_max_by(cmp), Ok(None));
+    assert_eq!([1].into_iter().try_min_max_by(cmp), Ok(Some((1, 1))));
+    assert_eq!(
+        [3, 1, 4, 1, 5, 9, 2].into_iter().try_min_max_by(cmp),
+        Ok(Some((1, 9)))
+    );
+}
+
+#[test]
+fn try_min_max_by_error() {
+    let mut seen = Vec::new();
+    let result = (0..10).inspect(|&x| seen.push(x)).try_min_max_by(|a, b| {
+        if *a == 5 || *b == 5 {
+            return Err(5);
+        }
+        Ok(a.cmp(b))
+    });
+    assert_eq!(result, Err(5));
+    assert_eq!(seen, [0, 1, 2, 3, 4, 5]);
+
+    let cmp = |a: &f64, b: &f64| a.partial_cmp(b).ok_or("NaN");
+    assert_eq!(
+        [1.0, 2.0, f64::NAN].into_iter().try_min_max_by(cmp),
+        Err("NaN")
+    );
+}
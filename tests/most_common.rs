@@ -0,0 +1,35 @@
+#![cfg(feature = "most_common")]
+
+use std::iter;
+
+use itermore::IterMostCommon;
+
+#[test]
+fn most_common() {
+    let v = [3, 1, 2, 1, 3, 3, 4].into_iter().most_common();
+    assert_eq!(v, [(3, 3), (1, 2), (2, 1), (4, 1)]);
+}
+
+#[test]
+fn most_common_ties_by_first_appearance() {
+    let v = ["b", "a", "c", "a", "b", "c"].into_iter().most_common();
+    assert_eq!(v, [("b", 2), ("a", 2), ("c", 2)]);
+}
+
+#[test]
+fn most_common_empty() {
+    assert!(iter::empty::<i32>().most_common().is_empty());
+    assert!(iter::empty::<i32>().most_common_n(3).is_empty());
+}
+
+#[test]
+fn most_common_n() {
+    let data = "the quick brown fox jumps over the lazy dog the end";
+    let v = data.split(' ').most_common_n(2);
+    assert_eq!(v, [("the", 3), ("quick", 1)]);
+
+    assert!(data.split(' ').most_common_n(0).is_empty());
+
+    let all = data.split(' ').most_common();
+    assert_eq!(data.split(' ').most_common_n(100), all);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "next_chunk")]
+
+use itermore::prelude::*;
+
+#[test]
+fn split_first() {
+    let (head, rest) = (0..5).split_first::<2>().unwrap();
+    assert_eq!(head, [0, 1]);
+    assert_eq!(Vec::from_iter(rest), [2, 3, 4]);
+
+    let (head, mut rest) = (0..3).split_first::<3>().unwrap();
+    assert_eq!(head, [0, 1, 2]);
+    assert_eq!(rest.next(), None);
+
+    let (head, rest) = (0..3).split_first::<0>().unwrap();
+    assert_eq!(head, []);
+    assert_eq!(rest.len(), 3);
+}
+
+#[test]
+fn split_first_too_few() {
+    let err = (0..2).split_first::<3>().unwrap_err();
+    assert_eq!(err.as_slice(), [0, 1]);
+    assert_eq!(Vec::from_iter(err), [0, 1]);
+
+    let err = (0..0).split_first::<1>().unwrap_err();
+    assert!(err.as_slice().is_empty());
+}
+
+#[test]
+fn first_n_exact() {
+    assert_eq!((0..3).first_n::<3>(), Some([0, 1, 2]));
+}
+
+#[test]
+fn first_n_more() {
+    let mut iter = 0..5;
+    assert_eq!(iter.by_ref().first_n::<3>(), Some([0, 1, 2]));
+    assert_eq!(Vec::from_iter(iter), [3, 4]);
+}
+
+#[test]
+fn first_n_fewer() {
+    let mut iter = 0..2;
+    assert_eq!(iter.by_ref().first_n::<3>(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn first_n_does_not_consume_past_n() {
+    let mut count = 0;
+    let mut iter = (0..).inspect(|_| count += 1);
+    assert_eq!(iter.by_ref().first_n::<4>(), Some([0, 1, 2, 3]));
+    drop(iter);
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn take_array_while_exact() {
+    let (arr, mut rest) = [1, 2, 3, 10].into_iter().take_array_while(|&x| x < 5);
+    assert_eq!(arr, Some([1, 2, 3]));
+    assert_eq!(rest.next(), Some(10));
+    assert_eq!(rest.next(), None);
+}
+
+#[test]
+fn take_array_while_more() {
+    let (arr, rest) = (0..10).take_array_while(|&x| x < 5);
+    assert_eq!(arr, Some([0, 1, 2]));
+    assert_eq!(Vec::from_iter(rest), [3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn take_array_while_fewer() {
+    let (arr, rest) = (0..10).take_array_while::<_, 3>(|&x| x < 2);
+    assert_eq!(arr, None);
+    assert_eq!(Vec::from_iter(rest), [3, 4, 5, 6, 7, 8, 9]);
+
+    let (arr, rest) = (0..2).take_array_while::<_, 3>(|_| true);
+    assert_eq!(arr, None);
+    assert_eq!(Vec::from_iter(rest), []);
+}
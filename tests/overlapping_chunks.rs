@@ -0,0 +1,59 @@
+#![cfg(feature = "overlapping_chunks")]
+
+use itermore::prelude::*;
+
+#[test]
+fn overlapping_chunks_overlap_zero() {
+    let v = Vec::from_iter((0..7).overlapping_chunks::<3>(0));
+    assert_eq!(v, [[0, 1, 2], [3, 4, 5]]);
+
+    let v = Vec::from_iter((0..7).overlapping_chunks::<1>(0));
+    assert_eq!(v, [[0], [1], [2], [3], [4], [5], [6]]);
+}
+
+#[test]
+fn overlapping_chunks_overlap_one() {
+    let v = Vec::from_iter((0..7).overlapping_chunks::<3>(1));
+    assert_eq!(v, [[0, 1, 2], [2, 3, 4], [4, 5, 6]]);
+
+    let v = Vec::from_iter((0..6).overlapping_chunks::<3>(1));
+    assert_eq!(v, [[0, 1, 2], [2, 3, 4]]);
+
+    let v = Vec::from_iter((0..8).overlapping_chunks::<4>(1));
+    assert_eq!(v, [[0, 1, 2, 3], [3, 4, 5, 6]]);
+}
+
+#[test]
+fn overlapping_chunks_overlap_n_minus_one() {
+    let v = Vec::from_iter((0..5).overlapping_chunks::<3>(2));
+    assert_eq!(v, [[0, 1, 2], [1, 2, 3], [2, 3, 4]]);
+}
+
+#[test]
+fn overlapping_chunks_short() {
+    assert_eq!((0..2).overlapping_chunks::<3>(1).next(), None);
+    assert_eq!((0..0).overlapping_chunks::<1>(0).next(), None);
+}
+
+#[test]
+fn overlapping_chunks_size_hint() {
+    for n in 0..20 {
+        for overlap in 0..4 {
+            let mut iter = (0..n).overlapping_chunks::<4>(overlap);
+            let mut len = iter.len();
+            assert_eq!(iter.clone().count(), len);
+            while iter.next().is_some() {
+                len -= 1;
+                assert_eq!(iter.len(), len);
+                assert_eq!(iter.clone().count(), len);
+            }
+            assert_eq!(len, 0);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn overlapping_chunks_overlap_too_large() {
+    let _ = (0..10).overlapping_chunks::<3>(3);
+}
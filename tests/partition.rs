@@ -0,0 +1,22 @@
+#![cfg(feature = "partition")]
+
+use std::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn partition_result() {
+    let (oks, errs): (Vec<i32>, Vec<&str>) = iter::empty().partition_result();
+    assert!(oks.is_empty());
+    assert!(errs.is_empty());
+
+    let (oks, errs) = [Ok(1), Err("a"), Ok(2)].into_iter().partition_result();
+    assert_eq!(oks, [1, 2]);
+    assert_eq!(errs, ["a"]);
+
+    let (oks, errs) = [Err::<i32, _>("a"), Err("b")]
+        .into_iter()
+        .partition_result();
+    assert!(oks.is_empty());
+    assert_eq!(errs, ["a", "b"]);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "power_set")]
+
+use std::iter;
+
+use itermore::IterPowerSet;
+
+#[test]
+fn power_set() {
+    let v = Vec::from_iter([1, 2, 3].into_iter().power_set());
+    assert_eq!(
+        v,
+        [
+            vec![],
+            vec![1],
+            vec![2],
+            vec![3],
+            vec![1, 2],
+            vec![1, 3],
+            vec![2, 3],
+            vec![1, 2, 3],
+        ]
+    );
+}
+
+#[test]
+fn power_set_empty() {
+    let v = Vec::from_iter(iter::empty::<i32>().power_set());
+    assert_eq!(v, [Vec::<i32>::new()]);
+}
+
+#[test]
+fn power_set_count() {
+    for n in 0..10 {
+        assert_eq!((0..n).power_set().count(), 1 << n);
+    }
+}
+
+#[test]
+fn power_set_up_to() {
+    let v = Vec::from_iter([1, 2, 3].into_iter().power_set_up_to(2));
+    assert_eq!(v.len(), 7);
+    assert_eq!(
+        v,
+        [
+            vec![],
+            vec![1],
+            vec![2],
+            vec![3],
+            vec![1, 2],
+            vec![1, 3],
+            vec![2, 3],
+        ]
+    );
+}
+
+#[test]
+fn power_set_up_to_zero() {
+    let v = Vec::from_iter([1, 2, 3].into_iter().power_set_up_to(0));
+    assert_eq!(v, [Vec::<i32>::new()]);
+}
+
+#[test]
+fn power_set_up_to_larger_than_len() {
+    let v = Vec::from_iter([1, 2].into_iter().power_set_up_to(5));
+    assert_eq!(v, [vec![], vec![1], vec![2], vec![1, 2]]);
+}
+
+#[test]
+fn power_set_up_to_infinite() {
+    let mut iter = (0..).power_set_up_to(2);
+    assert_eq!(iter.next(), Some(vec![]));
+    assert_eq!(iter.next(), Some(vec![0]));
+    assert_eq!(iter.nth(99), Some(vec![100]));
+}
+
+#[test]
+fn power_set_fused() {
+    let mut iter = [1].into_iter().power_set();
+    assert_eq!(iter.next(), Some(vec![]));
+    assert_eq!(iter.next(), Some(vec![1]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
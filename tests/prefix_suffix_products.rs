@@ -0,0 +1,47 @@
+#![cfg(feature = "prefix_suffix_products")]
+
+use std::iter;
+
+use itermore::IterPrefixSuffixProducts;
+
+#[test]
+fn prefix_suffix_products() {
+    let v = [1, 2, 3, 4].into_iter().prefix_suffix_products();
+    assert_eq!(v, [(1, 24), (1, 12), (2, 4), (6, 1)]);
+    let except_self = Vec::from_iter(v.into_iter().map(|(p, s)| p * s));
+    assert_eq!(except_self, [24, 12, 8, 6]);
+}
+
+#[test]
+fn prefix_suffix_products_with_zero() {
+    let v = [3, 0, 5].into_iter().prefix_suffix_products();
+    assert_eq!(v, [(1, 0), (3, 5), (0, 1)]);
+    let except_self = Vec::from_iter(v.into_iter().map(|(p, s)| p * s));
+    assert_eq!(except_self, [0, 15, 0]);
+}
+
+#[test]
+fn prefix_suffix_products_edge_cases() {
+    assert!(iter::empty::<i32>().prefix_suffix_products().is_empty());
+    assert_eq!([7].into_iter().prefix_suffix_products(), [(1, 1)]);
+    assert_eq!(
+        [2.5, 4.0].into_iter().prefix_suffix_products(),
+        [(1.0, 4.0), (2.5, 1.0)]
+    );
+}
+
+#[test]
+fn prefix_suffix_products_matches_naive() {
+    let data = [2u64, 3, 5, 7, 11, 13];
+    let v = data.into_iter().prefix_suffix_products();
+    for (i, (p, s)) in v.into_iter().enumerate() {
+        assert_eq!(p, data[..i].iter().product::<u64>());
+        assert_eq!(s, data[i + 1..].iter().product::<u64>());
+    }
+}
+
+#[test]
+fn prefix_suffix_products_does_not_multiply_all() {
+    let v = [u8::MAX, 1, 2].into_iter().prefix_suffix_products();
+    assert_eq!(v, [(1, 2), (u8::MAX, 2), (u8::MAX, 1)]);
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "rand")]
+
+use rand::prelude::*;
+
+use itermore::IterRandom;
+
+#[test]
+fn shuffled_seeded() {
+    let v = Vec::from_iter((0..10).shuffled(&mut StdRng::seed_from_u64(1930)));
+    let w = Vec::from_iter((0..10).shuffled(&mut StdRng::seed_from_u64(1930)));
+    assert_eq!(v, w);
+
+    // The same permutation as shuffling a slice directly.
+    let mut expected = Vec::from_iter(0..10);
+    expected.shuffle(&mut StdRng::seed_from_u64(1930));
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn shuffled_is_permutation() {
+    let mut rng = StdRng::seed_from_u64(1930);
+    for len in [0, 1, 2, 10, 1000] {
+        let mut v = Vec::from_iter((0..len).shuffled(&mut rng));
+        v.sort_unstable();
+        assert_eq!(v, Vec::from_iter(0..len));
+    }
+}
+
+#[test]
+fn shuffled_dyn_rng() {
+    let rng: &mut dyn RngCore = &mut StdRng::seed_from_u64(1930);
+    assert_eq!("ab".chars().shuffled(rng).len(), 2);
+}
+
+#[test]
+fn sample_len() {
+    let mut rng = StdRng::seed_from_u64(1931);
+    for len in [0, 1, 5, 10, 100] {
+        for k in [0, 1, 3, 10, 200] {
+            let v = (0..len).sample(k, &mut rng);
+            assert_eq!(v.len(), k.min(len), "len = {}, k = {}", len, k);
+            assert!(v.iter().all(|n| (0..len).contains(n)));
+
+            let mut sorted = v.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), v.len(), "elements are not distinct");
+        }
+    }
+}
+
+#[test]
+fn sample_short_keeps_order() {
+    let mut rng = StdRng::seed_from_u64(1931);
+    assert_eq!("abc".chars().sample(5, &mut rng), ['a', 'b', 'c']);
+}
+
+#[test]
+fn sample_seeded() {
+    let v = (0..1000).sample(5, &mut StdRng::seed_from_u64(1931));
+    let w = (0..1000).sample(5, &mut StdRng::seed_from_u64(1931));
+    assert_eq!(v, w);
+}
+
+#[test]
+fn sample_uniform() {
+    // Every element should be chosen roughly the same number of times.
+    let mut rng = StdRng::seed_from_u64(1931);
+    let mut counts = [0; 10];
+    for _ in 0..10_000 {
+        for n in (0..10).sample(3, &mut rng) {
+            counts[n] += 1;
+        }
+    }
+    for count in counts {
+        assert!((2700..3300).contains(&count), "{:?}", counts);
+    }
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "reduce")]
+
+use std::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn checked_sum() {
+    assert_eq!(iter::empty::<i32>().checked_sum::<i32>(), None);
+    assert_eq!([1].into_iter().checked_sum::<i32>(), Some(1));
+    assert_eq!([1, 2, 3].into_iter().checked_sum::<i32>(), Some(6));
+    assert_eq!([-1, 1].into_iter().checked_sum::<i32>(), Some(0));
+}
+
+#[test]
+fn checked_product() {
+    assert_eq!(iter::empty::<i32>().checked_product::<i32>(), None);
+    assert_eq!([2].into_iter().checked_product::<i32>(), Some(2));
+    assert_eq!([2, 3, 4].into_iter().checked_product::<i32>(), Some(24));
+    assert_eq!([0, 3].into_iter().checked_product::<i32>(), Some(0));
+}
+
+#[test]
+fn sum_by() {
+    assert_eq!(iter::empty::<&str>().sum_by::<usize, _, _>(str::len), None);
+    assert_eq!(["a", "bc", "def"].into_iter().sum_by(str::len), Some(6));
+}
+
+#[test]
+fn product_by() {
+    assert_eq!(
+        iter::empty::<&str>().product_by::<usize, _, _>(str::len),
+        None
+    );
+    assert_eq!(["ab", "cde", "f"].into_iter().product_by(str::len), Some(6));
+}
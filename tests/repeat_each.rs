@@ -0,0 +1,57 @@
+#![cfg(feature = "repeat_each")]
+
+use itermore::IterRepeatEach;
+
+#[test]
+fn repeat_each() {
+    let v: Vec<_> = [1, 2].into_iter().repeat_each::<3>().collect();
+    assert_eq!(v, [1, 1, 1, 2, 2, 2]);
+
+    let v: Vec<_> = [1, 2].into_iter().repeat_each::<1>().collect();
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn repeat_each_zero() {
+    let mut iter = [1, 2].into_iter().repeat_each::<0>();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn repeat_each_n() {
+    for n in 0..5 {
+        let v: Vec<_> = "abc".chars().repeat_each_n(n).collect();
+        let expected: Vec<_> = "abc"
+            .chars()
+            .flat_map(|c| std::iter::repeat(c).take(n))
+            .collect();
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+fn repeat_each_moves_last_copy() {
+    use std::rc::Rc;
+
+    let rc = Rc::new(());
+    let mut iter = [rc.clone()].into_iter().repeat_each::<2>();
+    let a = iter.next().unwrap();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    let b = iter.next().unwrap();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop((a, b));
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn repeat_each_size_hint() {
+    let mut iter = (0..4).repeat_each_n(3);
+    assert_eq!(iter.size_hint(), (12, Some(12)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (11, Some(11)));
+    assert_eq!(iter.count(), 11);
+
+    let iter = (0..usize::MAX).repeat_each::<2>();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
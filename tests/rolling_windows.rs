@@ -0,0 +1,74 @@
+#![cfg(feature = "rolling_windows")]
+
+use itermore::{rolling_hash, IterRollingWindows};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn check<const N: usize>(data: &[u8]) {
+    let mut count = 0;
+    for (window, hash) in data.iter().copied().rolling_windows::<N>() {
+        assert_eq!(hash, rolling_hash(&window));
+        count += 1;
+    }
+    assert_eq!(count, data.len().saturating_sub(N - 1));
+}
+
+#[test]
+fn rolling_windows_matches_full_hash() {
+    let mut rng = StdRng::seed_from_u64(1877);
+    for _ in 0..100 {
+        let len = rng.gen_range(0..64);
+        let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+        check::<1>(&data);
+        check::<2>(&data);
+        check::<3>(&data);
+        check::<7>(&data);
+        check::<16>(&data);
+    }
+}
+
+#[test]
+fn rolling_windows_chars() {
+    let windows: Vec<_> = "hello".chars().rolling_windows::<3>().collect();
+    assert_eq!(
+        windows,
+        [
+            (['h', 'e', 'l'], rolling_hash(&['h', 'e', 'l'])),
+            (['e', 'l', 'l'], rolling_hash(&['e', 'l', 'l'])),
+            (['l', 'l', 'o'], rolling_hash(&['l', 'l', 'o'])),
+        ]
+    );
+}
+
+#[test]
+fn rolling_windows_equal_windows_equal_hashes() {
+    let hashes: Vec<_> = b"abcabc"
+        .iter()
+        .copied()
+        .rolling_windows::<3>()
+        .map(|(_, h)| h)
+        .collect();
+    assert_eq!(hashes[0], hashes[3]);
+    assert_ne!(hashes[0], hashes[1]);
+}
+
+#[test]
+fn rolling_windows_too_short() {
+    let mut iter = [1u8, 2].into_iter().rolling_windows::<3>();
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn rolling_windows_size_hint() {
+    let mut iter = [1u32, 2, 3, 4, 5].into_iter().rolling_windows::<2>();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.next();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn rolling_windows_zero() {
+    let _ = [1u8].into_iter().rolling_windows::<0>().next();
+}
@@ -7,3 +7,100 @@ fn sorted() {
     let v: Vec<_> = [1, 3, 2].into_iter().sorted().collect();
     assert_eq!(v, [1, 2, 3]);
 }
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_sorted() {
+    use rand::prelude::*;
+
+    let mut rng = StdRng::seed_from_u64(1860);
+    let data: Vec<u32> = (0..10_000).map(|_| rng.gen_range(0..1000)).collect();
+    let v = Vec::from_iter(data.iter().par_sorted());
+    assert_eq!(v, Vec::from_iter(data.iter().sorted()));
+}
+
+#[test]
+fn sorted_with_indices() {
+    let v = [30, 10, 20].into_iter().sorted_with_indices();
+    assert_eq!(v, [(1, 10), (2, 20), (0, 30)]);
+
+    let v = "baab".chars().sorted_with_indices();
+    assert_eq!(v, [(1, 'a'), (2, 'a'), (0, 'b'), (3, 'b')]);
+
+    assert!(std::iter::empty::<u8>().sorted_with_indices().is_empty());
+}
+
+#[test]
+fn sorted_indices() {
+    assert_eq!([30, 10, 20].into_iter().sorted_indices(), [1, 2, 0]);
+
+    let data = [5, 3, 9, 1, 3];
+    let indices = data.iter().sorted_indices();
+    let v = Vec::from_iter(indices.iter().map(|&i| data[i]));
+    assert_eq!(v, Vec::from_iter(data.iter().copied().sorted()));
+}
+
+#[test]
+fn sorted_group_fold() {
+    let data = [(2, 10), (1, 1), (2, 20), (3, 5), (1, 2)];
+    let v = Vec::from_iter(data.into_iter().sorted_group_fold(
+        |(k, _)| *k,
+        |acc, (_, v)| acc + v,
+        || 0,
+    ));
+    assert_eq!(v, [(1, 3), (2, 30), (3, 5)]);
+
+    let v = Vec::from_iter(std::iter::empty::<(u8, u8)>().sorted_group_fold(
+        |(k, _)| *k,
+        |acc, (_, v)| acc + v,
+        || 0,
+    ));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn sorted_group_fold_preserves_order() {
+    let words = ["bb", "a", "cc", "b", "aa", "c"];
+    let v = Vec::from_iter(words.into_iter().sorted_group_fold(
+        |w| w.len(),
+        |mut acc: Vec<_>, w| {
+            acc.push(w);
+            acc
+        },
+        Vec::new,
+    ));
+    assert_eq!(v, [(1, vec!["a", "b", "c"]), (2, vec!["bb", "cc", "aa"])]);
+}
+
+#[test]
+fn select_nth() {
+    let data = [5, 1, 4, 1, 3, 9, 2, 6];
+    for k in 0..=data.len() {
+        assert_eq!(
+            data.into_iter().select_nth(k),
+            data.into_iter().sorted().nth(k),
+            "k = {}",
+            k
+        );
+    }
+    assert_eq!(std::iter::empty::<u8>().select_nth(0), None);
+}
+
+#[test]
+fn select_nth_random() {
+    use rand::prelude::*;
+
+    let mut rng = StdRng::seed_from_u64(1929);
+    for len in [1, 2, 10, 101, 1000] {
+        let data: Vec<u32> = (0..len).map(|_| rng.gen_range(0..100)).collect();
+        for k in [0, len / 4, len / 2, len - 1] {
+            assert_eq!(
+                data.iter().select_nth(k),
+                data.iter().sorted().nth(k),
+                "len = {}, k = {}",
+                len,
+                k
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "split_when")]
+
+use itermore::IterSplitWhen;
+
+#[test]
+fn split_when_large_deltas() {
+    let v: Vec<_> = [1, 2, 10, 11, 3]
+        .into_iter()
+        .split_when(|a: &i32, b: &i32| (a - b).abs() > 5)
+        .collect();
+    assert_eq!(v, [vec![1, 2], vec![10, 11], vec![3]]);
+}
+
+#[test]
+fn split_when_never() {
+    let v: Vec<_> = (0..5).split_when(|_, _| false).collect();
+    assert_eq!(v, [vec![0, 1, 2, 3, 4]]);
+}
+
+#[test]
+fn split_when_always() {
+    let v: Vec<_> = (0..3).split_when(|_, _| true).collect();
+    assert_eq!(v, [vec![0], vec![1], vec![2]]);
+}
+
+#[test]
+fn split_when_empty() {
+    let mut iter = (0..0).split_when(|_, _| true);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn split_when_previous_element() {
+    let mut calls = Vec::new();
+    let v: Vec<_> = "aabbbc"
+        .chars()
+        .split_when(|a, b| {
+            calls.push((*a, *b));
+            a != b
+        })
+        .collect();
+    assert_eq!(v, [vec!['a', 'a'], vec!['b', 'b', 'b'], vec!['c']]);
+    assert_eq!(
+        calls,
+        [('a', 'a'), ('a', 'b'), ('b', 'b'), ('b', 'b'), ('b', 'c')]
+    );
+}
+
+#[test]
+fn split_when_not_clone() {
+    #[derive(Debug, PartialEq)]
+    struct NotClone(i32);
+
+    let v: Vec<_> = [1, 2, 4]
+        .into_iter()
+        .map(NotClone)
+        .split_when(|a, b| b.0 - a.0 > 1)
+        .collect();
+    assert_eq!(v, [vec![NotClone(1), NotClone(2)], vec![NotClone(4)]]);
+}
+
+#[test]
+fn split_when_size_hint() {
+    let mut iter = (0..5).split_when(|a, b| b - a > 0);
+    assert_eq!(iter.size_hint(), (1, Some(5)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "stats")]
+
+use std::iter;
+
+use itermore::IterStats;
+
+#[test]
+fn sum_count() {
+    assert_eq!([3, 1, 4, 1, 5].into_iter().sum_count(), (14, 5));
+    assert_eq!([2.5, 0.5].into_iter().sum_count(), (3.0, 2));
+}
+
+#[test]
+fn sum_count_empty() {
+    assert_eq!(iter::empty::<i32>().sum_count(), (0, 0));
+    assert_eq!(iter::empty::<f64>().sum_count(), (0.0, 0));
+}
+
+#[test]
+fn sum_count_single_pass() {
+    let mut calls = 0;
+    let (sum, count) = (1..=10).inspect(|_| calls += 1).sum_count();
+    assert_eq!((sum, count), (55, 10));
+    assert_eq!(calls, 10);
+}
+
+#[test]
+fn mean() {
+    assert_eq!([1, 2, 3, 4].into_iter().mean(), Some(2.5));
+    assert_eq!([7u8].into_iter().mean(), Some(7.0));
+    assert_eq!([-1.5f32, 1.5].into_iter().mean(), Some(0.0));
+}
+
+#[test]
+fn mean_empty() {
+    assert_eq!(iter::empty::<i32>().mean(), None);
+}
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+}
+
+#[test]
+fn stats() {
+    let stats = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().stats().unwrap();
+    assert_eq!(stats.count, 8);
+    assert_close(stats.mean, 5.0);
+    assert_close(stats.variance, 4.0);
+    assert_close(stats.sample_variance().unwrap(), 32.0 / 7.0);
+
+    let stats = [1.0, 2.0, 3.0, 4.0].into_iter().stats().unwrap();
+    assert_eq!(stats.count, 4);
+    assert_close(stats.mean, 2.5);
+    assert_close(stats.variance, 1.25);
+    assert_close(stats.sample_variance().unwrap(), 5.0 / 3.0);
+}
+
+#[test]
+fn stats_single() {
+    let stats = [3u8].into_iter().stats().unwrap();
+    assert_eq!(stats.count, 1);
+    assert_close(stats.mean, 3.0);
+    assert_close(stats.variance, 0.0);
+    assert_eq!(stats.sample_variance(), None);
+}
+
+#[test]
+fn stats_empty() {
+    assert_eq!(iter::empty::<f64>().stats(), None);
+}
+
+#[test]
+fn stats_large_offset() {
+    // Naively summing squares loses all precision here.
+    let stats = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]
+        .into_iter()
+        .stats()
+        .unwrap();
+    assert_close(stats.mean, 1e9 + 10.0);
+    assert_close(stats.variance, 22.5);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "step_by_offset")]
+
+use itermore::IterStepByOffset;
+
+#[test]
+fn step_by_offset() {
+    let v: Vec<_> = (0..10).step_by_offset(3, 1).collect();
+    assert_eq!(v, [1, 4, 7]);
+}
+
+#[test]
+fn step_by_offset_matches_skip_step_by() {
+    for len in 0..12 {
+        for step in 1..5 {
+            for offset in 0..6 {
+                let mut iter = (0..len).step_by_offset(step, offset);
+                let expected: Vec<_> = (0..len).skip(offset).step_by(step).collect();
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+                for n in (0..expected.len()).rev() {
+                    iter.next();
+                    assert_eq!(iter.size_hint(), (n, Some(n)));
+                }
+                assert_eq!(iter.next(), None);
+            }
+        }
+    }
+}
+
+#[test]
+fn step_by_offset_size_hint_unbounded() {
+    let iter = (0..).step_by_offset(2, 10);
+    assert_eq!(iter.size_hint(), (usize::MAX / 2 - 4, None));
+}
+
+#[test]
+#[should_panic(expected = "step must be non-zero")]
+fn step_by_offset_zero() {
+    let _ = (0..10).step_by_offset(0, 1);
+}
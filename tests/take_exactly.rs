@@ -0,0 +1,95 @@
+#![cfg(feature = "take_exactly")]
+
+use itermore::IterTakeExactly;
+
+#[test]
+fn take_exactly_exact() {
+    let v: Vec<_> = (0..3).take_exactly(3).collect();
+    assert_eq!(v, [0, 1, 2]);
+}
+
+#[test]
+fn take_exactly_longer() {
+    let mut iter = 0..10;
+    let v: Vec<_> = iter.by_ref().take_exactly(3).collect();
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn take_exactly_zero() {
+    let mut iter = (0..0).take_exactly(0);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "expected 3 elements but the iterator was exhausted after 2")]
+fn take_exactly_shorter() {
+    let _ = (0..2).take_exactly(3).count();
+}
+
+#[test]
+fn take_exactly_size_hint() {
+    let mut iter = (0..2).take_exactly(5);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    iter.next();
+    assert_eq!(iter.len(), 4);
+}
+
+#[test]
+fn try_take_exactly_exact() {
+    let v: Vec<_> = (0..3).try_take_exactly(3).collect();
+    assert_eq!(v, [Ok(0), Ok(1), Ok(2)]);
+}
+
+#[test]
+fn try_take_exactly_longer() {
+    let mut iter = 0..10;
+    let v: Result<Vec<_>, _> = iter.by_ref().try_take_exactly(3).collect();
+    assert_eq!(v.unwrap(), [0, 1, 2]);
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn try_take_exactly_shorter() {
+    let mut iter = (0..2).try_take_exactly(3);
+    assert_eq!(iter.next(), Some(Ok(0)));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_eq!(err.expected(), 3);
+    assert_eq!(err.actual(), 2);
+    assert_eq!(
+        err.to_string(),
+        "expected 3 elements but the iterator was exhausted after 2"
+    );
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn try_take_exactly_empty() {
+    let v: Vec<_> = (0..0).try_take_exactly(2).collect();
+    assert_eq!(v.len(), 1);
+    assert_eq!(v[0].unwrap_err().actual(), 0);
+}
+
+#[test]
+fn try_take_exactly_size_hint() {
+    let iter = (0..10).try_take_exactly(3);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+
+    let mut iter = (0..2).try_take_exactly(5);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    iter.next();
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn short_error_is_error() {
+    fn assert_error<E: std::error::Error>() {}
+    assert_error::<itermore::ShortError>();
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "tee")]
+
+use itermore::IterTee;
+
+#[test]
+fn tee_lockstep() {
+    let (a, b) = (0..5).tee();
+    let v: Vec<_> = a.zip(b).collect();
+    assert_eq!(v, [(0, 0), (1, 1), (2, 2), (3, 3), (4, 4)]);
+}
+
+#[test]
+fn tee_one_after_the_other() {
+    let (a, b) = "hello".chars().tee();
+    assert_eq!(a.collect::<String>(), "hello");
+    assert_eq!(b.collect::<String>(), "hello");
+}
+
+#[test]
+fn tee_different_rates() {
+    let (mut a, mut b) = (0..10).tee();
+    let mut va = Vec::new();
+    let mut vb = Vec::new();
+    loop {
+        // Advance `a` twice as fast as `b`.
+        let x = a.next();
+        let y = a.next();
+        let z = b.next();
+        va.extend(x);
+        va.extend(y);
+        vb.extend(z);
+        if x.is_none() && z.is_none() {
+            break;
+        }
+    }
+    assert_eq!(va, (0..10).collect::<Vec<_>>());
+    assert_eq!(vb, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn tee_swap_leader() {
+    let (mut a, mut b) = (0..6).tee();
+    assert_eq!(a.next(), Some(0));
+    assert_eq!(a.next(), Some(1));
+    assert_eq!(b.next(), Some(0));
+    assert_eq!(b.next(), Some(1));
+    assert_eq!(b.next(), Some(2));
+    assert_eq!(b.next(), Some(3));
+    assert_eq!(a.next(), Some(2));
+    assert_eq!(a.collect::<Vec<_>>(), [3, 4, 5]);
+    assert_eq!(b.collect::<Vec<_>>(), [4, 5]);
+}
+
+#[test]
+fn tee_size_hint() {
+    let (mut a, b) = (0..4).tee();
+    assert_eq!(a.len(), 4);
+    assert_eq!(b.len(), 4);
+    a.next();
+    a.next();
+    assert_eq!(a.len(), 2);
+    assert_eq!(b.len(), 4);
+    drop(a);
+    assert_eq!(b.count(), 4);
+}
+
+#[test]
+fn tee_debug() {
+    let (a, _) = (0..4).tee();
+    let _ = format!("{:?}", a);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "windows")]
+
+use core::iter;
+
+use itermore::prelude::*;
+
+#[test]
+fn windows_smoke() {
+    let v = Vec::from_iter((0..6).windows(3));
+    assert_eq!(v, [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+
+    let v = Vec::from_iter((0..3).windows(3));
+    assert_eq!(v, [[0, 1, 2]]);
+
+    let v = Vec::from_iter((0..2).windows(3));
+    assert!(v.is_empty());
+
+    let v = Vec::from_iter(iter::empty::<i32>().windows(3));
+    assert!(v.is_empty());
+}
+
+#[test]
+fn windows_size_hint() {
+    let mut iter = (0..6).windows(3);
+    for len in (0..=4).rev() {
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        assert_eq!(iter.len(), len);
+        iter.next();
+    }
+
+    let iter = (0..2).windows(3);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let iter = (1..).windows(3);
+    assert_eq!(iter.size_hint(), (usize::MAX - 2, None));
+}
+
+#[test]
+#[should_panic]
+fn windows_zero() {
+    let _ = (0..7).windows(0);
+}
+
+#[test]
+fn windows_clone() {
+    let mut iter = (0..6).windows(3);
+    let mut iter2 = iter.clone();
+    assert_eq!(iter.next(), Some(vec![0, 1, 2]));
+    assert_eq!(iter2.next(), Some(vec![0, 1, 2]));
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "windows_buffered")]
+
+use itermore::prelude::*;
+
+#[test]
+fn windows_buffered_smoke() {
+    let mut iter = (0..10).windows_buffered(3);
+    let mut v = Vec::new();
+    while let Some(w) = iter.next_ref() {
+        assert_eq!(w.len(), 3);
+        v.push(w.to_vec());
+    }
+    let data = Vec::from_iter(0..10);
+    assert!(v.iter().eq(data.windows(3)));
+    assert_eq!(v.len(), 8);
+}
+
+#[test]
+fn windows_buffered_short() {
+    let mut iter = (0..2).windows_buffered(3);
+    assert_eq!(iter.next_ref(), None);
+
+    let mut iter = (0..3).windows_buffered(3);
+    assert_eq!(iter.next_ref(), Some(&[0, 1, 2][..]));
+    assert_eq!(iter.next_ref(), None);
+}
+
+#[test]
+fn windows_buffered_non_clone() {
+    struct NoClone(usize);
+
+    let mut iter = (0..100).map(NoClone).windows_buffered(7);
+    let mut i = 0;
+    while let Some(w) = iter.next_ref() {
+        assert_eq!(w.len(), 7);
+        assert!(w.iter().map(|x| x.0).eq(i..i + 7));
+        i += 1;
+    }
+    assert_eq!(i, 94);
+}
+
+#[test]
+#[should_panic]
+fn windows_buffered_zero() {
+    let _ = (0..7).windows_buffered(0);
+}
+
+#[test]
+fn windows_buffered_debug() {
+    let iter = (0..6).windows_buffered(2);
+    let _ = format!("{:?}", iter);
+}
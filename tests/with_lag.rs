@@ -0,0 +1,45 @@
+#![cfg(feature = "with_lag")]
+
+use itermore::prelude::*;
+
+#[test]
+fn with_lag() {
+    let v = Vec::from_iter((0..6).with_lag::<3>());
+    assert_eq!(
+        v,
+        [
+            (0, None),
+            (1, None),
+            (2, None),
+            (3, Some([0, 1, 2])),
+            (4, Some([1, 2, 3])),
+            (5, Some([2, 3, 4])),
+        ]
+    );
+}
+
+#[test]
+fn with_lag_one() {
+    let v = Vec::from_iter("abc".chars().with_lag::<1>());
+    assert_eq!(v, [('a', None), ('b', Some(['a'])), ('c', Some(['b']))]);
+}
+
+#[test]
+fn with_lag_short() {
+    let v = Vec::from_iter((0..2).with_lag::<3>());
+    assert_eq!(v, [(0, None), (1, None)]);
+}
+
+#[test]
+fn with_lag_size_hint() {
+    let mut iter = (0..5).with_lag::<2>();
+    assert_eq!(iter.len(), 5);
+    iter.next();
+    assert_eq!(iter.len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn with_lag_zero() {
+    let _ = (0..5).with_lag::<0>();
+}
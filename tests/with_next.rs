@@ -0,0 +1,32 @@
+#![cfg(feature = "with_next")]
+
+use itermore::IterWithNext;
+
+#[test]
+fn with_next() {
+    let v: Vec<_> = [1, 2, 3].into_iter().with_next().collect();
+    assert_eq!(v, [(1, Some(2)), (2, Some(3)), (3, None)]);
+}
+
+#[test]
+fn with_next_single() {
+    let v: Vec<_> = ["a"].into_iter().with_next().collect();
+    assert_eq!(v, [("a", None)]);
+}
+
+#[test]
+fn with_next_empty() {
+    let mut iter = (0..0).with_next();
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn with_next_size_hint() {
+    let mut iter = (0..4).with_next();
+    for n in (0..=4).rev() {
+        assert_eq!(iter.len(), n);
+        iter.next();
+    }
+    assert_eq!(iter.next(), None);
+}
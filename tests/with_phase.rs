@@ -0,0 +1,36 @@
+#![cfg(feature = "with_phase")]
+
+use itermore::IterWithPhase;
+
+#[test]
+fn with_phase() {
+    let phases: Vec<_> = (0..5).with_phase(2).map(|(p, _)| p).collect();
+    assert_eq!(phases, [0, 1, 0, 1, 0]);
+
+    let v: Vec<_> = (10..13).with_phase(1).collect();
+    assert_eq!(v, [(0, 10), (0, 11), (0, 12)]);
+}
+
+#[test]
+fn with_phase_matches_cycle_zip() {
+    for period in 1..6 {
+        let v: Vec<_> = (0..17).with_phase(period).collect();
+        let expected: Vec<_> = (0..period).cycle().zip(0..17).collect();
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+fn with_phase_size_hint() {
+    let mut iter = (0..5).with_phase(3);
+    assert_eq!(iter.len(), 5);
+    iter.next();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.count(), 4);
+}
+
+#[test]
+#[should_panic(expected = "period must be non-zero")]
+fn with_phase_zero() {
+    let _ = (0..5).with_phase(0);
+}
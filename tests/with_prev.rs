@@ -0,0 +1,30 @@
+#![cfg(feature = "with_prev")]
+
+use itermore::IterWithPrev;
+
+#[test]
+fn with_prev() {
+    let v: Vec<_> = [1, 2, 3].into_iter().with_prev().collect();
+    assert_eq!(v, [(None, 1), (Some(1), 2), (Some(2), 3)]);
+}
+
+#[test]
+fn with_prev_single() {
+    let v: Vec<_> = ["a"].into_iter().with_prev().collect();
+    assert_eq!(v, [(None, "a")]);
+}
+
+#[test]
+fn with_prev_empty() {
+    let mut iter = (0..0).with_prev();
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn with_prev_size_hint() {
+    let mut iter = (0..4).with_prev();
+    assert_eq!(iter.len(), 4);
+    iter.next();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.count(), 3);
+}
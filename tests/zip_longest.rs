@@ -0,0 +1,68 @@
+#![cfg(feature = "zip_longest")]
+
+use std::iter;
+
+use itermore::{EitherOrBoth, IterZipLongest};
+
+#[test]
+fn zip_longest_left_longer() {
+    let v = Vec::from_iter([1, 2, 3].into_iter().zip_longest(['a']));
+    assert_eq!(
+        v,
+        [
+            EitherOrBoth::Both(1, 'a'),
+            EitherOrBoth::Left(2),
+            EitherOrBoth::Left(3),
+        ]
+    );
+}
+
+#[test]
+fn zip_longest_right_longer() {
+    let v = Vec::from_iter([1].into_iter().zip_longest(['a', 'b', 'c']));
+    assert_eq!(
+        v,
+        [
+            EitherOrBoth::Both(1, 'a'),
+            EitherOrBoth::Right('b'),
+            EitherOrBoth::Right('c'),
+        ]
+    );
+}
+
+#[test]
+fn zip_longest_equal_length() {
+    let v = Vec::from_iter([1, 2].into_iter().zip_longest(['a', 'b']));
+    assert_eq!(v, [EitherOrBoth::Both(1, 'a'), EitherOrBoth::Both(2, 'b')]);
+}
+
+#[test]
+fn zip_longest_empty() {
+    let mut iter = iter::empty::<i32>().zip_longest(iter::empty::<char>());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn zip_longest_size_hint() {
+    let mut iter = (0..5).zip_longest(0..2);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    iter.next();
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 2);
+
+    let iter = (0..5).zip_longest(0..);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+
+    let iter = (0..5).zip_longest((0..10).filter(|x| x % 2 == 0));
+    assert_eq!(iter.size_hint(), (5, Some(10)));
+}
+
+#[test]
+fn either_or_both_left_right() {
+    assert_eq!(EitherOrBoth::<i32, char>::Both(1, 'a').left(), Some(1));
+    assert_eq!(EitherOrBoth::<i32, char>::Both(1, 'a').right(), Some('a'));
+    assert_eq!(EitherOrBoth::<i32, char>::Left(1).right(), None);
+    assert_eq!(EitherOrBoth::<i32, char>::Right('a').left(), None);
+}